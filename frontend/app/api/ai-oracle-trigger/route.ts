@@ -186,7 +186,8 @@ export async function POST(request: NextRequest) {
                                  'ClientWins'; // Default to ClientWins for 'Inconclusive' or any other unexpected values
       const call = api.tx.arbitration.submitRuling(
         body.projectId,
-        rulingForBlockchain
+        rulingForBlockchain,
+        null // optional rationale
       );
 
       const extrinsic = api.tx.sudo.sudo(call);
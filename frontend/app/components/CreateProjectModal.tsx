@@ -17,6 +17,7 @@ export const CreateProjectModal = ({ isOpen, onClose, onProjectCreated }: Create
   const [description, setDescription] = useState("");
   const [budget, setBudget] = useState("");
   const [duration, setDuration] = useState("");
+  const [skills, setSkills] = useState("");
   const [isSubmitting, setIsSubmitting] = useState(false);
   const [error, setError] = useState("");
 
@@ -87,11 +88,13 @@ export const CreateProjectModal = ({ isOpen, onClose, onProjectCreated }: Create
       const budgetInPlanck = BigInt(parseFloat(budget) * 10**12);
       const durationInBlocks = parseInt(duration) * 24 * 60 * 60 / 12; // Convert days to blocks
       const uriHex = "0x" + Buffer.from(ipfsHash).toString('hex');
+      const skillTags = skills.split(",").map((s) => s.trim().toLowerCase()).filter(Boolean);
       
       const extrinsic = api.tx.projects.createProject(
         budgetInPlanck.toString(), 
         uriHex, 
-        durationInBlocks
+        durationInBlocks,
+        skillTags
       );
 
       await new Promise<void>((resolve, reject) => {
@@ -109,6 +112,7 @@ export const CreateProjectModal = ({ isOpen, onClose, onProjectCreated }: Create
             setDescription("");
             setBudget("");
             setDuration("");
+            setSkills("");
             resolve();
           }
         }).catch((error: any) => {
@@ -215,6 +219,19 @@ export const CreateProjectModal = ({ isOpen, onClose, onProjectCreated }: Create
               disabled={!selectedAccount || isSubmitting}
             />
           </div>
+
+          <div className="mb-6">
+            <label htmlFor="skills" className="block text-sm font-medium text-gray-700">Skills (comma separated, up to 5)</label>
+            <input
+              type="text"
+              id="skills"
+              value={skills}
+              onChange={(e) => setSkills(e.target.value)}
+              className="mt-1 block w-full px-3 py-2 bg-white border border-gray-300 rounded-md shadow-sm focus:outline-none focus:ring-indigo-500 focus:border-indigo-500 sm:text-sm text-gray-900"
+              placeholder="rust, substrate"
+              disabled={!selectedAccount || isSubmitting}
+            />
+          </div>
           
           {error && <p className="text-red-500 text-sm mb-4">{error}</p>}
           
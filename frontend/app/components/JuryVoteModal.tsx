@@ -29,7 +29,8 @@ export const JuryVoteModal = ({ project, dispute, isOpen, onClose, currentUser }
     try {
       const extrinsic = api.tx.arbitration.castVote(
         project.id,
-        selectedVote === 'ForClient' ? { ForClient: null } : { ForFreelancer: null }
+        selectedVote === 'ForClient' ? { ForClient: null } : { ForFreelancer: null },
+        null // optional rationale
       );
 
       await new Promise<void>((resolve, reject) => {
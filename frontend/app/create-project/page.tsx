@@ -13,6 +13,7 @@ export default function CreateProjectPage() {
   const [description, setDescription] = useState("");
   const [budget, setBudget] = useState("");
   const [duration, setDuration] = useState("");
+  const [skills, setSkills] = useState("");
   const [isSubmitting, setIsSubmitting] = useState(false);
   const [error, setError] = useState("");
   const [success, setSuccess] = useState(false);
@@ -99,11 +100,13 @@ export default function CreateProjectPage() {
       const budgetInPlanck = BigInt(parseFloat(budget) * 10**12);
       const durationInBlocks = parseInt(duration) * 24 * 60 * 60 / 12;
       const uriHex = "0x" + Buffer.from(ipfsHash).toString('hex');
+      const skillTags = skills.split(",").map((s) => s.trim().toLowerCase()).filter(Boolean);
       
       const extrinsic = api!.tx.projects.createProject(
         budgetInPlanck.toString(), 
         uriHex, 
-        durationInBlocks
+        durationInBlocks,
+        skillTags
       );
 
       await new Promise<void>((resolve, reject) => {
@@ -254,6 +257,18 @@ export default function CreateProjectPage() {
               min="1"
             />
           </div>
+
+          <div>
+            <label htmlFor="skills" className="block text-sm font-medium text-gray-700 mb-2">Skills (comma separated, up to 5)</label>
+            <input
+              type="text"
+              id="skills"
+              value={skills}
+              onChange={(e) => setSkills(e.target.value)}
+              className="w-full px-3 py-2 border border-gray-300 rounded-md shadow-sm focus:outline-none focus:ring-indigo-500 focus:border-indigo-500 text-gray-900"
+              placeholder="rust, substrate"
+            />
+          </div>
         </div>
 
         {error && <p className="text-red-500 text-sm mb-4">{error}</p>}
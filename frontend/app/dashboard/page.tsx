@@ -24,6 +24,10 @@ interface RawReputationData {
   disputes_lost?: any;
   juryParticipation?: any;
   jury_participation?: any;
+  projectsAccepted?: any;
+  freelancer?: any;
+  client?: any;
+  juror?: any;
   jury_accuracy?: any;
   registration_block?: any;
   last_activity_block?: any;
@@ -98,6 +102,18 @@ export default function DashboardPage() {
 
                 console.log("Dashboard - Parsed reputation data:", repData);
 
+                // Stats are split into freelancer, client and juror ledgers on-chain.
+                if (repData) {
+                  const { freelancer = {}, client = {}, juror = {} } = repData;
+                  repData = {
+                    ...repData,
+                    ...freelancer,
+                    ...juror,
+                    totalSpent: client.totalSpent ?? client.total_spent,
+                    projectsAccepted: client.projectsCompleted ?? client.projects_completed,
+                  };
+                }
+
                 // Process juror tier - handle the case where it might be a fallback value
                 let jurorTier = "Unstaked";
                 if (jurorTierCodec) {
@@ -148,7 +164,7 @@ export default function DashboardPage() {
 
                     const mappedReputation: ReputationData = {
                     projects_completed: repData.projectsCompleted || repData.projects_completed || 0,
-                    projects_accepted: repData.projectsCompleted || repData.projects_completed || 0, // Map projects_completed to projects_accepted
+                    projects_accepted: repData.projectsAccepted || 0,
                     total_earned: String(api.createType('Balance', repData.totalEarned || repData.total_earned || 0).toHuman()),
                     total_spent: String(api.createType('Balance', repData.totalSpent || repData.total_spent || 0).toHuman()),
                     positive_feedback: repData.totalRatingsReceived || repData.total_ratings_received || 0, // Map total_ratings to positive feedback
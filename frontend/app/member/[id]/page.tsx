@@ -22,6 +22,10 @@ interface RawReputationData {
   disputes_lost?: any;
   juryParticipation?: any;
   jury_participation?: any;
+  projectsAccepted?: any;
+  freelancer?: any;
+  client?: any;
+  juror?: any;
 }
 
 interface ReputationData {
@@ -88,6 +92,18 @@ export default function MemberProfilePage() {
         
         console.log("Parsed reputation data:", repData);
 
+        // Stats are split into freelancer, client and juror ledgers on-chain.
+        if (repData) {
+          const { freelancer = {}, client = {}, juror = {} } = repData;
+          repData = {
+            ...repData,
+            ...freelancer,
+            ...juror,
+            totalSpent: client.totalSpent ?? client.total_spent,
+            projectsAccepted: client.projectsCompleted ?? client.projects_completed,
+          };
+        }
+
         // Process juror tier - handle the case where it might be a fallback value
         let jurorTier = "Unstaked";
         if (jurorTierCodec) {
@@ -123,7 +139,7 @@ export default function MemberProfilePage() {
         if (repData) {
             const mappedReputation: ReputationData = {
             projects_completed: repData.projectsCompleted || repData.projects_completed || 0,
-            projects_accepted: repData.projectsCompleted || repData.projects_completed || 0, // Map projects_completed to projects_accepted
+            projects_accepted: repData.projectsAccepted || 0,
             total_earned: String(api.createType('Balance', repData.totalEarned || repData.total_earned || 0).toHuman()),
             total_spent: String(api.createType('Balance', repData.totalSpent || repData.total_spent || 0).toHuman()),
             positive_feedback: repData.totalRatingsReceived || repData.total_ratings_received || 0, // Map total_ratings to positive feedback
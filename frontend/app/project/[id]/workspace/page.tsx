@@ -317,14 +317,14 @@ const ContextualActionBox = ({ project, dispute, currentUser, onAppealClick }: {
                 <p className="text-gray-600 mb-6">You have been selected as a juror for this round. Please review the evidence and cast your vote.</p>
                 <div className="flex space-x-4">
                     <Button
-                        onClick={() => handleTx(api!.tx.arbitration.castVote(project.id, 'ForClient'), 'Voted for Client')}
+                        onClick={() => handleTx(api!.tx.arbitration.castVote(project.id, 'ForClient', null), 'Voted for Client')}
                         disabled={isSubmitting}
                         className="flex-1 bg-primary hover:bg-primary-hover text-white"
                     >
                         Vote Client
                     </Button>
                     <Button
-                        onClick={() => handleTx(api!.tx.arbitration.castVote(project.id, 'ForFreelancer'), 'Voted for Freelancer')}
+                        onClick={() => handleTx(api!.tx.arbitration.castVote(project.id, 'ForFreelancer', null), 'Voted for Freelancer')}
                         disabled={isSubmitting}
                         className="flex-1 bg-primary hover:bg-primary-hover text-white"
                     >
@@ -184,86 +184,136 @@ fn testnet_genesis(
     let alice_reputation = runtime::pallet_reputation::ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 0,
-        projects_failed: 0,
-        total_earned: 0u128,
-        projects_posted: 10, // Client activity
-        total_spent: 50000u128, // Significant spending
+        freelancer: runtime::pallet_reputation::FreelancerStats {
+            projects_completed: 0,
+            projects_failed: 0,
+            total_earned: 0u128,
+            avg_rating_received: 4500, // Good rating (0-5000 scale)
+            total_ratings_received: 8,
+        },
+        client: runtime::pallet_reputation::ClientStats {
+            projects_posted: 10, // Client activity
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 50000u128, // Significant spending
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: runtime::pallet_reputation::JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 0,
         disputes_lost: 0,
-        avg_rating_received: 4500, // Good rating (0-5000 scale)
-        total_ratings_received: 8,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Bob as Freelancer - has completed projects and earned money
     let bob_reputation = runtime::pallet_reputation::ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 15, // Experienced freelancer
-        projects_failed: 1,
-        total_earned: 25000u128, // Good earnings
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: runtime::pallet_reputation::FreelancerStats {
+            projects_completed: 15, // Experienced freelancer
+            projects_failed: 1,
+            total_earned: 25000u128, // Good earnings
+            avg_rating_received: 4200,
+            total_ratings_received: 12,
+        },
+        client: runtime::pallet_reputation::ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: runtime::pallet_reputation::JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 1,
         disputes_lost: 0,
-        avg_rating_received: 4200,
-        total_ratings_received: 12,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Charlie as Bronze Juror - meets minimum requirements
     let charlie_reputation = runtime::pallet_reputation::ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 5, // Minimum for Bronze
-        projects_failed: 0,
-        total_earned: 1500u128, // Above Bronze threshold
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: runtime::pallet_reputation::FreelancerStats {
+            projects_completed: 5, // Minimum for Bronze
+            projects_failed: 0,
+            total_earned: 1500u128, // Above Bronze threshold
+            avg_rating_received: 4000,
+            total_ratings_received: 5,
+        },
+        client: runtime::pallet_reputation::ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: runtime::pallet_reputation::JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 0,
         disputes_lost: 0, // No disputes lost
-        avg_rating_received: 4000,
-        total_ratings_received: 5,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Dave as Silver Juror - higher tier requirements
     let dave_reputation = runtime::pallet_reputation::ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 25, // Above Silver threshold
-        projects_failed: 1,
-        total_earned: 15000u128, // Above Silver threshold
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: runtime::pallet_reputation::FreelancerStats {
+            projects_completed: 25, // Above Silver threshold
+            projects_failed: 1,
+            total_earned: 15000u128, // Above Silver threshold
+            avg_rating_received: 4300,
+            total_ratings_received: 20,
+        },
+        client: runtime::pallet_reputation::ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: runtime::pallet_reputation::JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 2,
         disputes_lost: 0,
-        avg_rating_received: 4300,
-        total_ratings_received: 20,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Eve as Gold Juror - highest tier requirements
     let eve_reputation = runtime::pallet_reputation::ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 60, // Above Gold threshold
-        projects_failed: 2,
-        total_earned: 75000u128, // Above Gold threshold
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: runtime::pallet_reputation::FreelancerStats {
+            projects_completed: 60, // Above Gold threshold
+            projects_failed: 2,
+            total_earned: 75000u128, // Above Gold threshold
+            avg_rating_received: 4600,
+            total_ratings_received: 50,
+        },
+        client: runtime::pallet_reputation::ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: runtime::pallet_reputation::JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 5,
         disputes_lost: 0,
-        avg_rating_received: 4600,
-        total_ratings_received: 50,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     serde_json::json!({
         "balances": {
@@ -299,9 +349,15 @@ fn testnet_genesis(
                 (dave.clone(), dave_reputation),
                 (eve.clone(), eve_reputation),
             ],
-            "initialJurors": vec![charlie, dave, eve], // Charlie, Dave, and Eve as jurors
+            "initialJurors": vec![charlie.clone(), dave, eve], // Charlie, Dave, and Eve as jurors
             "jurorStake": 1000000000000u128, // 1 unit with 12 decimals
         },
+        "council": {
+            "members": vec![alice.clone(), bob, charlie],
+        },
+        "oracleMembership": {
+            "members": vec![alice], // Alice submits AI rulings
+        },
         "sudo": { "key": Some(root) }
     })
 }
\ No newline at end of file
@@ -8,6 +8,8 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -15,7 +17,7 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
     use frame_support::{BoundedVec,PalletId,
-        traits::{Currency, ReservableCurrency, ExistenceRequirement, Imbalance},
+        traits::{Currency, ReservableCurrency, ExistenceRequirement, Imbalance, SortedMembers},
         
         Blake2_128Concat,
     };
@@ -23,20 +25,25 @@ pub mod pallet {
     
 
     use sp_runtime::{
-		traits::{ Saturating}
+		traits::{ Saturating},
+        Percent,
     };
     use codec::{Encode, Decode, MaxEncodedLen};
     use scale_info::TypeInfo;
     use frame_support::dispatch::{DispatchResult};
     use frame_support::BoundedBTreeMap;
-    use sp_runtime::traits::Zero;
+    use sp_runtime::traits::{Zero, One};
 
-    use pallet_projects::{Arbitrable, EvidenceUri};
+    use scale_info::prelude::vec::Vec;
+    use pallet_projects::{Arbitrable, ContentCheck, EvidenceUri};
     use pallet_reputation::ReputationInterface;
-    use pallet_reputation::JurorTier;
+    use pallet_reputation::{JurorTier, ProjectRole};
     type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[derive(Clone, Encode, Decode, PartialEq, Debug,MaxEncodedLen, TypeInfo, Eq, Copy)]
@@ -63,6 +70,38 @@ pub mod pallet {
         ForFreelancer,
     }
 
+    /// Off-chain reasoning attached to a ruling or a vote: where to find it and the hash of its content.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, RuntimeDebug, DecodeWithMemTracking)]
+    pub struct Rationale {
+        pub uri: EvidenceUri,
+        pub content_hash: [u8; 32],
+    }
+
+    /// Shares of the project budget charged at each stage of a dispute. Per-round arrays start
+    /// with the AI round, followed by the first and the final appeal.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, RuntimeDebug, DecodeWithMemTracking)]
+    pub struct FeeSchedule {
+        /// Arbitration cost of each round.
+        pub arbitration_cost: [Percent; 3],
+        /// Bond for appealing the ruling of each round.
+        pub appeal_bond: [Percent; 3],
+        /// Base fee per juror in the first and the final appeal.
+        pub juror_base_fee: [Percent; 2],
+        /// Extra pay for voting with the majority, as a share of the base fee.
+        pub performance_bonus: Percent,
+    }
+
+    impl Default for FeeSchedule {
+        fn default() -> Self {
+            Self {
+                arbitration_cost: [Percent::from_percent(2), Percent::from_percent(5), Percent::from_percent(8)],
+                appeal_bond: [Percent::from_percent(5), Percent::from_percent(20), Percent::from_percent(50)],
+                juror_base_fee: [Percent::from_percent(1), Percent::from_percent(2)],
+                performance_bonus: Percent::from_percent(25),
+            }
+        }
+    }
+
     #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, Debug)]
     #[scale_info(skip_type_params(T))]
     pub struct DisputeInfo<T: Config> {
@@ -76,6 +115,31 @@ pub mod pallet {
         pub votes: BoundedBTreeMap<T::AccountId, Vote, T::MaxJurors>,
     }
 
+    /// Snapshot of a single arbitration round, kept after the dispute moves on to the next round.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RoundRecord<T: Config> {
+        pub appellant: T::AccountId,
+        pub bond: BalanceOf<T>,
+        pub arbitration_cost: BalanceOf<T>,
+        pub jurors: BoundedVec<(T::AccountId, bool), T::MaxJurors>,
+        pub votes: BoundedBTreeMap<T::AccountId, Vote, T::MaxJurors>,
+        pub ruling: Option<Ruling>,
+        pub started_at: BlockNumberFor<T>,
+        pub concluded_at: Option<BlockNumberFor<T>>,
+    }
+
+    /// Compact record left behind once a finalized dispute has been archived.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct DisputeSummary<T: Config> {
+        pub final_ruling: Ruling,
+        pub rounds: u32,
+        pub total_cost: BalanceOf<T>,
+        pub created_at: BlockNumberFor<T>,
+        pub finalized_at: BlockNumberFor<T>,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching runtime event type.
@@ -86,6 +150,12 @@ pub mod pallet {
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 
         type AiOracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Oracle accounts, granted access to private project briefs when a dispute is created.
+        type Oracles: SortedMembers<Self::AccountId>;
+        /// Origin allowed to change the fee schedule.
+        type FeeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to halt and resume deadline processing in `on_idle`.
+        type HaltOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         type PalletId: Get<PalletId>;
         /// The type used to identify projects
@@ -119,6 +189,24 @@ pub mod pallet {
         type VotingPeriod: Get<BlockNumberFor<Self>>;
         #[pallet::constant]
         type AppealPeriod: Get<BlockNumberFor<Self>>;
+        /// How long a finalized dispute keeps its full history before it can be archived.
+        #[pallet::constant]
+        type DisputeRetentionPeriod: Get<BlockNumberFor<Self>>;
+        /// Maximum number of dispute deadlines that can be scheduled for a single block.
+        #[pallet::constant]
+        type MaxDeadlinesPerBlock: Get<u32>;
+        /// Reward paid from `bounty_account` to whoever manually finalizes or enforces a dispute.
+        #[pallet::constant]
+        type EnforcementBounty: Get<BalanceOf<Self>>;
+        /// Number of peremptory juror challenges each party may make per round.
+        #[pallet::constant]
+        type MaxChallengesPerRound: Get<u32>;
+        /// Bond reserved for every peremptory challenge; forfeited if the challenger loses the round.
+        #[pallet::constant]
+        type ChallengeBond: Get<BalanceOf<Self>>;
+        /// Minimum voting time left to a replacement juror; the voting deadline is extended if needed.
+        #[pallet::constant]
+        type ReplacementVotingPeriod: Get<BlockNumberFor<Self>>;
 
         // --- Minimum Bond Amounts ---
         #[pallet::constant]
@@ -171,6 +259,94 @@ pub mod pallet {
         (BalanceOf<T>, BalanceOf<T>), // (base_fee, performance_bonus)
         ValueQuery
     >;
+    /// Full history of every round of a dispute.
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_rounds)]
+    pub type DisputeRounds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        RoundRecord<T>,
+    >;
+    /// Compact summaries of archived disputes.
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_summaries)]
+    pub type DisputeSummaries<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, DisputeSummary<T>>;
+    /// Blocks added to a round's voting period because jurors were replaced.
+    #[pallet::storage]
+    #[pallet::getter(fn voting_extensions)]
+    pub type VotingExtensions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        BlockNumberFor<T>,
+        ValueQuery
+    >;
+    /// Jurors removed from a round by recusal or challenge; they are never redrawn for it.
+    #[pallet::storage]
+    #[pallet::getter(fn struck_jurors)]
+    pub type StruckJurors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        BoundedVec<T::AccountId, T::MaxJurors>,
+        ValueQuery
+    >;
+    #[pallet::storage]
+    #[pallet::getter(fn juror_challenges)]
+    pub type JurorChallenges<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        Blake2_128Concat, T::AccountId, // challenging party
+        (u32, BalanceOf<T>), // (challenges_used, bond_reserved)
+        ValueQuery
+    >;
+    /// Disputes whose current period ends at a given block, processed in `on_idle`.
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_deadlines)]
+    pub type DisputeDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat, BlockNumberFor<T>,
+        BoundedVec<T::ProjectId, T::MaxDeadlinesPerBlock>,
+        ValueQuery
+    >;
+    /// Earliest block whose deadlines have not been fully processed yet.
+    #[pallet::storage]
+    #[pallet::getter(fn deadline_cursor)]
+    pub type DeadlineCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+    /// Rationale published by the AI oracle alongside its ruling for a round.
+    #[pallet::storage]
+    #[pallet::getter(fn ruling_rationale)]
+    pub type RulingRationales<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        Rationale,
+    >;
+    /// Rationale attached by each juror to their vote for a round.
+    #[pallet::storage]
+    #[pallet::getter(fn juror_rationale)]
+    pub type JurorRationales<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        Blake2_128Concat, T::AccountId, // juror
+        Rationale,
+    >;
+    /// Jurors of a round who reported that the parties never shared the private brief with them.
+    #[pallet::storage]
+    #[pallet::getter(fn withheld_brief)]
+    pub type WithheldBriefs<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, (T::ProjectId, u32), // (project_id, round)
+        Blake2_128Concat, T::AccountId, // juror
+        (),
+    >;
+
+    /// Current dispute fees and bonds, set by `FeeOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn fee_schedule)]
+    pub type Fees<T: Config> = StorageValue<_, FeeSchedule, ValueQuery>;
+
+    /// Whether `on_idle` leaves dispute deadlines alone, set by `HaltOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn deadlines_halted)]
+    pub type DeadlinesHalted<T: Config> = StorageValue<_, bool, ValueQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -191,6 +367,26 @@ pub mod pallet {
         JurorPerformanceBonusAwarded { project_id: T::ProjectId, juror: T::AccountId, amount: BalanceOf<T> },
         ArbitrationCostsPaid { project_id: T::ProjectId, payer: T::AccountId, amount: BalanceOf<T> },
         PayoutCompleted { project_id: T::ProjectId },
+        /// A rationale was attached to a round's ruling (`juror: None`) or to a juror's vote.
+        RationaleSubmitted { project_id: T::ProjectId, round: u32, juror: Option<T::AccountId>, content_hash: [u8; 32] },
+        DisputeArchived { project_id: T::ProjectId, final_ruling: Ruling },
+        /// A manual finalize/enforce call was rewarded.
+        EnforcementBountyPaid { project_id: T::ProjectId, caller: T::AccountId, amount: BalanceOf<T> },
+        /// The deadline queue could not finalize or enforce a dispute; the manual call remains available.
+        AutomaticProcessingFailed { project_id: T::ProjectId, error: DispatchError },
+        JurorRecused { project_id: T::ProjectId, juror: T::AccountId, replacement: T::AccountId },
+        JurorChallenged { project_id: T::ProjectId, challenger: T::AccountId, juror: T::AccountId, replacement: T::AccountId },
+        VotingPeriodExtended { project_id: T::ProjectId, round: u32, deadline: BlockNumberFor<T> },
+        ChallengeBondReturned { project_id: T::ProjectId, challenger: T::AccountId, amount: BalanceOf<T> },
+        ChallengeBondForfeited { project_id: T::ProjectId, challenger: T::AccountId, amount: BalanceOf<T> },
+        /// The fee schedule was replaced; it applies to rounds started from now on.
+        FeeScheduleUpdated { schedule: FeeSchedule },
+        /// The deadline block was full, so the dispute was queued at the first block with room.
+        DeadlineDeferred { project_id: T::ProjectId, requested: BlockNumberFor<T>, scheduled: BlockNumberFor<T> },
+        /// A juror was granted access to the private brief, but neither party shared a copy with them.
+        BriefWithheld { project_id: T::ProjectId, round: u32, juror: T::AccountId },
+        /// `on_idle` stopped or resumed processing dispute deadlines.
+        DeadlineProcessingSet { halted: bool },
     }
 
     #[pallet::error]
@@ -230,6 +426,33 @@ pub mod pallet {
         AlreadyVoted,
         VotingPeriodNotOver,
         PaymentFailed,
+        /// A rationale must point to a non-empty URI
+        InvalidRationale,
+        /// The dispute is still within its retention period
+        RetentionPeriodNotOver,
+        /// This party has used all of its challenges for the round
+        ChallengeLimitReached,
+        /// Too many jurors have been removed from this round
+        TooManyStruckJurors,
+        /// The submitted work does not match its content hash
+        SubmissionHashMismatch,
+        /// The project has no private brief, or a copy was shared with the caller
+        BriefNotWithheld,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let check = T::DbWeight::get().reads(1);
+            if !remaining_weight.all_gte(check) {
+                return Weight::zero();
+            }
+            // Due deadlines stay queued and are picked up once processing resumes
+            if Self::deadlines_halted() {
+                return check;
+            }
+            Self::process_deadlines(now, remaining_weight.saturating_sub(check)).saturating_add(check)
+        }
     }
 
     #[pallet::call]
@@ -243,8 +466,14 @@ pub mod pallet {
         ) -> DispatchResult {
             let freelancer = ensure_signed(origin)?;
             ensure!(!Disputes::<T>::contains_key(project_id), Error::<T>::DisputeAlreadyExists);
+            ensure!(!DisputeSummaries::<T>::contains_key(project_id), Error::<T>::DisputeAlreadyExists);
             let (_client, project_freelancer) = T::Arbitrable::get_project_parties(project_id)?;
             ensure!(freelancer == project_freelancer, Error::<T>::NotAuthorized);
+            // Work that doesn't match its own content hash can't be defended
+            ensure!(
+                T::Arbitrable::get_content_check(project_id) != Some(ContentCheck::Mismatch),
+                Error::<T>::SubmissionHashMismatch
+            );
             let bond = Self::calculate_bond(&project_id, 1)?;
             <T as pallet::Config>::Currency::reserve(&freelancer, bond)?;
             AppealBonds::<T>::insert(project_id, 1, (freelancer.clone(), bond));
@@ -253,18 +482,30 @@ pub mod pallet {
             ArbitrationCosts::<T>::insert(project_id, initial_arbitration_cost);
             
             let (requirements_uri, submission_uri) = T::Arbitrable::get_evidence_uris(project_id)?;
+            T::Arbitrable::grant_evidence_access(project_id, &T::Oracles::sorted_members())?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
 
             let new_dispute = DisputeInfo {
                 status: DisputeStatus::AiProcessing,
                 requirements_uri,
                 submission_uri,
-                start_block: <frame_system::Pallet<T>>::block_number(),
+                start_block: current_block,
                 ruling: None,
                 round: 1,
                 jurors: Default::default(),
                 votes: Default::default(),
             };
             Disputes::<T>::insert(project_id, new_dispute);
+            DisputeRounds::<T>::insert((project_id, 1), RoundRecord {
+                appellant: freelancer.clone(),
+                bond,
+                arbitration_cost: initial_arbitration_cost,
+                jurors: Default::default(),
+                votes: Default::default(),
+                ruling: None,
+                started_at: current_block,
+                concluded_at: None,
+            });
             T::Arbitrable::set_project_status_in_dispute(project_id)?;
             Self::deposit_event(Event::DisputeCreated { project_id, who: freelancer });
             Ok(())
@@ -272,7 +513,12 @@ pub mod pallet {
 
         #[pallet::call_index(1)]
         #[pallet::weight(Weight::default())]
-        pub fn submit_ruling(origin: OriginFor<T>, project_id: T::ProjectId, ruling: Ruling) -> DispatchResult {
+        pub fn submit_ruling(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            ruling: Ruling,
+            rationale: Option<Rationale>,
+        ) -> DispatchResult {
             T::AiOracleOrigin::ensure_origin(origin)?;
 
             Disputes::<T>::try_mutate(project_id, |maybe_dispute| -> DispatchResult {
@@ -281,10 +527,18 @@ pub mod pallet {
                 // 2. Verify the dispute is in the correct state for this action.
                 ensure!(dispute.status == DisputeStatus::AiProcessing, Error::<T>::InvalidStatus);
 
+                // Keep the oracle's explanation so parties and appeal jurors can review it.
+                if let Some(rationale) = rationale {
+                    Self::store_rationale(project_id, dispute.round, None, rationale)?;
+                }
+
                 // 3. Update the dispute state to reflect the AI's ruling.
+                let current_block = <frame_system::Pallet<T>>::block_number();
                 dispute.ruling = Some(ruling);
                 dispute.status = DisputeStatus::Appealable; // The ruling can now be appealed.
-                dispute.start_block = <frame_system::Pallet<T>>::block_number(); // Start the appeal timer.
+                dispute.start_block = current_block; // Start the appeal timer.
+                Self::conclude_round_record(project_id, dispute, current_block);
+                Self::schedule_deadline(project_id, current_block.saturating_add(T::AppealPeriod::get()));
 
                 // 4. Emit an event.
                 Self::deposit_event(Event::AiRulingSubmitted { project_id, ruling });
@@ -328,17 +582,20 @@ pub mod pallet {
                 *total_cost = total_cost.saturating_add(additional_arbitration_cost);
             });
             // Jury selection logic
-            let (required_tier, jury_size) = match next_round {
-                2 => (JurorTier::Bronze, T::MinJurors::get()),
-                3 => (JurorTier::Silver, T::MaxJurors::get()),
-                _ => return Err(Error::<T>::InvalidRound.into()),
-            };
-            let jurors_vec = <T as pallet::Config>::Reputation::get_eligible_jurors(required_tier, &[client, freelancer], jury_size);
+            let (required_tier, jury_size) = Self::jury_requirements(next_round)?;
+            let skills = T::Arbitrable::get_project_skills(project_id)?;
+            let jurors_vec = <T as pallet::Config>::Reputation::get_eligible_jurors(project_id, required_tier, &[client, freelancer], &[], jury_size, &skills);
             let mut jurors_with_vote_status = BoundedVec::<(T::AccountId, bool), T::MaxJurors>::new();
             for juror_account in jurors_vec {
                 jurors_with_vote_status.try_push((juror_account, false)).unwrap();
             }
             ensure!(jurors_with_vote_status.len() >= jury_size as usize, Error::<T>::NotEnoughJurors);
+            // The new jury needs the evidence, including a private brief
+            let jury: Vec<T::AccountId> = jurors_with_vote_status.iter().map(|(juror, _)| juror.clone()).collect();
+            let (requirements_uri, submission_uri) = T::Arbitrable::get_evidence_uris(project_id)?;
+            T::Arbitrable::grant_evidence_access(project_id, &jury)?;
+            dispute.requirements_uri = requirements_uri;
+            dispute.submission_uri = submission_uri;
             // Pre-calculate jury fees for this round - Fixed syntax
             let (base_fee, performance_bonus) = Self::calculate_jury_fees(&project_id, next_round)?;
             for (juror, _) in &jurors_with_vote_status {
@@ -351,8 +608,19 @@ pub mod pallet {
             dispute.votes.clear();
             dispute.ruling = None;
             dispute.start_block = current_block;
-            
+
+            DisputeRounds::<T>::insert((project_id, next_round), RoundRecord {
+                appellant: appellant.clone(),
+                bond: appeal_bond,
+                arbitration_cost: additional_arbitration_cost,
+                jurors: dispute.jurors.clone(),
+                votes: Default::default(),
+                ruling: None,
+                started_at: current_block,
+                concluded_at: None,
+            });
             Disputes::<T>::insert(project_id, dispute);
+            Self::schedule_deadline(project_id, current_block.saturating_add(T::VotingPeriod::get()));
             
             Self::deposit_event(Event::AppealStarted { project_id, appellant, bond: appeal_bond });
             Self::deposit_event(Event::ArbitrationCostReserved { project_id, amount: additional_arbitration_cost });
@@ -361,7 +629,12 @@ pub mod pallet {
 
         #[pallet::call_index(3)]
         #[pallet::weight(Weight::default())]
-        pub fn cast_vote(origin: OriginFor<T>, project_id: T::ProjectId, vote: Vote) -> DispatchResult {
+        pub fn cast_vote(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            vote: Vote,
+            rationale: Option<Rationale>,
+        ) -> DispatchResult {
             let juror = ensure_signed(origin)?;
 
             Disputes::<T>::try_mutate(project_id, |maybe_dispute| -> DispatchResult {
@@ -386,6 +659,10 @@ pub mod pallet {
                 
                 // 4. Mark the juror as having voted.
                 juror_entry.1 = true;
+
+                if let Some(rationale) = rationale {
+                    Self::store_rationale(project_id, dispute.round, Some(juror.clone()), rationale)?;
+                }
                 
                 // --- FINALIZATION ---
                 Self::deposit_event(Event::VoteCast { project_id, who: juror, vote });
@@ -397,9 +674,162 @@ pub mod pallet {
         #[pallet::call_index(4)]
         #[pallet::weight(Weight::default())]
         pub fn enforce_final_ruling(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
-            // Anyone can trigger this, so we just need a signed origin.
+            // Anyone can trigger this, so we just need a signed origin. The automatic
+            // deadline queue normally gets there first; a manual call earns a small bounty.
+            let caller = ensure_signed(origin)?;
+
+            Self::do_enforce_final_ruling(project_id)?;
+            Self::pay_enforcement_bounty(project_id, &caller);
+            Ok(())
+        }
+
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::default())]
+        pub fn finalize_round(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId
+        ) -> DispatchResult {
+            // Anyone can trigger this, so we just need a signed origin. The automatic
+            // deadline queue normally gets there first; a manual call earns a small bounty.
+            let caller = ensure_signed(origin)?;
+
+            Self::do_finalize_round(project_id)?;
+            Self::pay_enforcement_bounty(project_id, &caller);
+            Ok(())
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(Weight::default())]
+        pub fn recuse(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId
+        ) -> DispatchResult {
+            // A juror with a conflict of interest steps down; a replacement is drawn from the same tier.
+            let juror = ensure_signed(origin)?;
+
+            let replacement = Self::replace_juror(project_id, &juror)?;
+            Self::deposit_event(Event::JurorRecused { project_id, juror, replacement });
+            Ok(())
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(Weight::default())]
+        pub fn challenge_juror(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            juror: T::AccountId,
+        ) -> DispatchResult {
+            // Peremptory challenge: no reason is required, but each one is bonded and limited per round.
+            let challenger = ensure_signed(origin)?;
+
+            let (client, freelancer) = T::Arbitrable::get_project_parties(project_id)?;
+            ensure!(challenger == client || challenger == freelancer, Error::<T>::NotAuthorized);
+            let round = Disputes::<T>::get(project_id).ok_or(Error::<T>::DisputeNotFound)?.round;
+            let (used, _) = JurorChallenges::<T>::get((project_id, round), &challenger);
+            ensure!(used < T::MaxChallengesPerRound::get(), Error::<T>::ChallengeLimitReached);
+
+            let replacement = Self::replace_juror(project_id, &juror)?;
+            let bond = T::ChallengeBond::get();
+            <T as pallet::Config>::Currency::reserve(&challenger, bond)?;
+            JurorChallenges::<T>::mutate((project_id, round), &challenger, |(used, reserved)| {
+                *used = used.saturating_add(1);
+                *reserved = reserved.saturating_add(bond);
+            });
+
+            Self::deposit_event(Event::JurorChallenged { project_id, challenger, juror, replacement });
+            Ok(())
+        }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::default())]
+        pub fn archive_dispute(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId
+        ) -> DispatchResult {
+            // Anyone can trigger this once the retention period is over.
             ensure_signed(origin)?;
 
+            let dispute = Disputes::<T>::get(project_id).ok_or(Error::<T>::DisputeNotFound)?;
+            ensure!(dispute.status == DisputeStatus::Finalized, Error::<T>::InvalidStatus);
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                current_block >= dispute.start_block.saturating_add(T::DisputeRetentionPeriod::get()),
+                Error::<T>::RetentionPeriodNotOver
+            );
+            let final_ruling = dispute.ruling.ok_or(Error::<T>::InvalidStatus)?;
+
+            // Fold the round history into the summary, then drop it together with the rationales.
+            let mut total_cost = BalanceOf::<T>::zero();
+            let mut created_at = dispute.start_block;
+            for round in 1..=dispute.round {
+                if let Some(record) = DisputeRounds::<T>::take((project_id, round)) {
+                    total_cost = total_cost.saturating_add(record.arbitration_cost);
+                    if round == 1 {
+                        created_at = record.started_at;
+                    }
+                }
+                RulingRationales::<T>::remove((project_id, round));
+                VotingExtensions::<T>::remove((project_id, round));
+                StruckJurors::<T>::remove((project_id, round));
+                let _ = JurorChallenges::<T>::clear_prefix((project_id, round), u32::MAX, None);
+                let _ = JurorRationales::<T>::clear_prefix((project_id, round), u32::MAX, None);
+                let _ = WithheldBriefs::<T>::clear_prefix((project_id, round), u32::MAX, None);
+            }
+            Disputes::<T>::remove(project_id);
+            DisputeSummaries::<T>::insert(project_id, DisputeSummary {
+                final_ruling,
+                rounds: dispute.round,
+                total_cost,
+                created_at,
+                finalized_at: dispute.start_block,
+            });
+
+            Self::deposit_event(Event::DisputeArchived { project_id, final_ruling });
+            Ok(())
+        }
+
+        /// Replace the dispute fees and bonds. Costs and bonds already reserved are not
+        /// changed.
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::default())]
+        pub fn set_fee_schedule(origin: OriginFor<T>, schedule: FeeSchedule) -> DispatchResult {
+            T::FeeOrigin::ensure_origin(origin)?;
+            Fees::<T>::put(schedule.clone());
+            Self::deposit_event(Event::FeeScheduleUpdated { schedule });
+            Ok(())
+        }
+
+        /// A juror of the current round reports that the parties never shared the private brief
+        /// with them. The report stays on the round, so the rest of the jury can weigh it.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+        pub fn report_withheld_brief(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+            let juror = ensure_signed(origin)?;
+            let dispute = Disputes::<T>::get(project_id).ok_or(Error::<T>::DisputeNotFound)?;
+            ensure!(dispute.status == DisputeStatus::Voting, Error::<T>::InvalidStatus);
+            ensure!(dispute.jurors.iter().any(|(j, _)| *j == juror), Error::<T>::NotJuror);
+            ensure!(T::Arbitrable::brief_withheld(project_id, &juror), Error::<T>::BriefNotWithheld);
+
+            WithheldBriefs::<T>::insert((project_id, dispute.round), &juror, ());
+            Self::deposit_event(Event::BriefWithheld { project_id, round: dispute.round, juror });
+            Ok(())
+        }
+
+        /// Stop or resume processing dispute deadlines in `on_idle`, e.g. while the pallet's
+        /// calls are paused. `finalize_round` and `enforce_final_ruling` can still be called.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_deadlines_halted(origin: OriginFor<T>, halted: bool) -> DispatchResult {
+            T::HaltOrigin::ensure_origin(origin)?;
+            DeadlinesHalted::<T>::put(halted);
+            Self::deposit_event(Event::DeadlineProcessingSet { halted });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Enforce the ruling of a dispute whose appeal window has closed.
+        pub(crate) fn do_enforce_final_ruling(project_id: T::ProjectId) -> DispatchResult {
             let mut dispute = Disputes::<T>::get(project_id).ok_or(Error::<T>::DisputeNotFound)?;
 
             // --- PRE-CONDITION CHECKS ---
@@ -411,33 +841,28 @@ pub mod pallet {
             );
             let (client, freelancer) = T::Arbitrable::get_project_parties(project_id)?;
             let final_ruling = dispute.ruling.ok_or(Error::<T>::InvalidStatus)?;
-            let (winner, loser) = match final_ruling {
-                Ruling::ClientWins => (client.clone(), freelancer.clone()),
-                Ruling::FreelancerWins => (freelancer.clone(), client.clone()),
+            let (winner, loser, loser_role) = match final_ruling {
+                Ruling::ClientWins => (client.clone(), freelancer.clone(), ProjectRole::Freelancer),
+                Ruling::FreelancerWins => (freelancer.clone(), client.clone(), ProjectRole::Client),
             };
+            let project_budget = T::Arbitrable::get_project_budget(project_id)?;
             // 1. Execute the project payment via the Arbitrable trait
             T::Arbitrable::on_ruling(project_id, Self::convert_to_project_ruling(final_ruling))?;
             // 2. Complete all financial settlements
             Self::complete_arbitration_payouts(project_id, &winner, &loser)?;
-            // 3. Update reputation
-            <T as pallet::Config>::Reputation::on_dispute_outcome(&winner, &loser, project_id, BalanceOf::<T>::from(0u32))?;
-            // 4. Finalize dispute
+            // 3. Update reputation; this is the only place a dispute outcome is reported
+            let skills = T::Arbitrable::get_project_skills(project_id)?;
+            <T as pallet::Config>::Reputation::on_dispute_outcome(&winner, &loser, project_id, project_budget, &skills, loser_role)?;
+            // 4. Finalize dispute; `start_block` now marks the start of the retention period.
             dispute.status = DisputeStatus::Finalized;
+            dispute.start_block = current_block;
             Disputes::<T>::insert(project_id, dispute);
             Self::deposit_event(Event::DisputeResolved { project_id, winner });
             Self::deposit_event(Event::PayoutCompleted { project_id });
             Ok(())
         }
-
-        #[pallet::call_index(5)]
-        #[pallet::weight(Weight::default())]
-        pub fn finalize_round(
-            origin: OriginFor<T>,
-            project_id: T::ProjectId
-        ) -> DispatchResult {
-            // 1. Anyone can trigger this, so we just need a signed origin.
-            ensure_signed(origin)?;
-
+        /// Tally the votes of a round whose voting period has ended.
+        pub(crate) fn do_finalize_round(project_id: T::ProjectId) -> DispatchResult {
             Disputes::<T>::try_mutate(project_id, |maybe_dispute| -> DispatchResult {
                 let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::DisputeNotFound)?;
 
@@ -445,7 +870,7 @@ pub mod pallet {
                 ensure!(dispute.status == DisputeStatus::Voting, Error::<T>::InvalidStatus);
                 let current_block = <frame_system::Pallet<T>>::block_number();
                 ensure!(
-                    current_block >= dispute.start_block.saturating_add(T::VotingPeriod::get()),
+                    current_block >= Self::voting_deadline(project_id, dispute),
                     Error::<T>::VotingPeriodNotOver
                 );
                 let mut client_votes = 0;
@@ -463,6 +888,7 @@ pub mod pallet {
                 };
                 // Award jury fees based on voting behavior
                 Self::award_jury_fees_for_round(project_id, dispute.round, round_ruling, &dispute.votes)?;
+                Self::settle_challenge_bonds(project_id, dispute.round, round_ruling)?;
                 // Update juror reputation
                 for (juror, vote) in dispute.votes.iter() {
                     let voted_with_majority = match (round_ruling, vote) {
@@ -470,58 +896,152 @@ pub mod pallet {
                         (Ruling::ClientWins, Vote::ForClient) => true,
                         _ => false,
                     };
-                    let _ = <T as pallet::Config>::Reputation::on_jury_vote(juror, voted_with_majority);
+                    let _ = <T as pallet::Config>::Reputation::on_jury_vote(juror, voted_with_majority, project_id);
                 }
                 dispute.ruling = Some(round_ruling);
                 dispute.status = DisputeStatus::Appealable;
                 dispute.start_block = current_block;
+                Self::conclude_round_record(project_id, dispute, current_block);
+                Self::schedule_deadline(project_id, current_block.saturating_add(T::AppealPeriod::get()));
                 Self::deposit_event(Event::RoundFinalized { project_id, ruling: round_ruling });
                 Ok(())
             })
         }
-    }
+        /// Minimum juror tier and jury size for an appeal round.
+        pub(crate) fn jury_requirements(round: u32) -> Result<(JurorTier, u32), DispatchError> {
+            match round {
+                2 => Ok((JurorTier::Bronze, T::MinJurors::get())),
+                3 => Ok((JurorTier::Silver, T::MaxJurors::get())),
+                _ => Err(Error::<T>::InvalidRound.into()),
+            }
+        }
+        /// Block at which voting closes for the current round, including any extension.
+        pub fn voting_deadline(project_id: T::ProjectId, dispute: &DisputeInfo<T>) -> BlockNumberFor<T> {
+            dispute.start_block
+                .saturating_add(T::VotingPeriod::get())
+                .saturating_add(VotingExtensions::<T>::get((project_id, dispute.round)))
+        }
+        /// Swap a juror who has not voted yet for a fresh one from the same tier and
+        /// extend the voting deadline so the replacement has time to vote.
+        fn replace_juror(project_id: T::ProjectId, juror: &T::AccountId) -> Result<T::AccountId, DispatchError> {
+            let mut dispute = Disputes::<T>::get(project_id).ok_or(Error::<T>::DisputeNotFound)?;
+            ensure!(dispute.status == DisputeStatus::Voting, Error::<T>::InvalidStatus);
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let deadline = Self::voting_deadline(project_id, &dispute);
+            ensure!(current_block < deadline, Error::<T>::InvalidStatus);
+            let position = dispute.jurors.iter()
+                .position(|(j, _)| j == juror)
+                .ok_or(Error::<T>::NotJuror)?;
+            ensure!(!dispute.jurors[position].1, Error::<T>::AlreadyVoted);
 
-    impl<T: Config> Pallet<T> {
+            let round = dispute.round;
+            let mut struck = StruckJurors::<T>::get((project_id, round));
+            struck.try_push(juror.clone()).map_err(|_| Error::<T>::TooManyStruckJurors)?;
+
+            // Never redraw the parties, a sitting juror or anyone already struck from this round.
+            let (client, freelancer) = T::Arbitrable::get_project_parties(project_id)?;
+            let mut exclude: Vec<T::AccountId> = dispute.jurors.iter().map(|(j, _)| j.clone()).collect();
+            exclude.extend(struck.iter().cloned());
+            let (required_tier, _) = Self::jury_requirements(round)?;
+            let skills = T::Arbitrable::get_project_skills(project_id)?;
+            let replacement = <T as pallet::Config>::Reputation::get_eligible_jurors(project_id, required_tier, &[client, freelancer], &exclude, 1, &skills)
+                .into_iter()
+                .next()
+                .ok_or(Error::<T>::NotEnoughJurors)?;
+            T::Arbitrable::grant_evidence_access(project_id, core::slice::from_ref(&replacement))?;
+
+            dispute.jurors.remove(position);
+            dispute.jurors.try_push((replacement.clone(), false)).map_err(|_| Error::<T>::NotEnoughJurors)?;
+            let fees = JuryFeesOwed::<T>::take((project_id, round), juror);
+            JuryFeesOwed::<T>::insert((project_id, round), &replacement, fees);
+            StruckJurors::<T>::insert((project_id, round), struck);
+
+            let min_deadline = current_block.saturating_add(T::ReplacementVotingPeriod::get());
+            if min_deadline > deadline {
+                VotingExtensions::<T>::mutate((project_id, round), |extension| {
+                    *extension = extension.saturating_add(min_deadline.saturating_sub(deadline));
+                });
+                Self::schedule_deadline(project_id, min_deadline);
+                Self::deposit_event(Event::VotingPeriodExtended { project_id, round, deadline: min_deadline });
+            }
+            DisputeRounds::<T>::mutate((project_id, round), |maybe_record| {
+                if let Some(record) = maybe_record {
+                    record.jurors = dispute.jurors.clone();
+                }
+            });
+            Disputes::<T>::insert(project_id, dispute);
+            Ok(replacement)
+        }
+        /// Return challenge bonds to parties the round ruled for and forfeit the rest to the pallet account.
+        fn settle_challenge_bonds(project_id: T::ProjectId, round: u32, round_ruling: Ruling) -> DispatchResult {
+            let (client, freelancer) = T::Arbitrable::get_project_parties(project_id)?;
+            let winner = match round_ruling {
+                Ruling::ClientWins => client,
+                Ruling::FreelancerWins => freelancer,
+            };
+            let pallet_account = Self::account_id();
+            for (challenger, (used, bond)) in JurorChallenges::<T>::iter_prefix((project_id, round)).collect::<Vec<_>>() {
+                if bond.is_zero() {
+                    continue;
+                }
+                if challenger == winner {
+                    <T as pallet::Config>::Currency::unreserve(&challenger, bond);
+                    Self::deposit_event(Event::ChallengeBondReturned { project_id, challenger: challenger.clone(), amount: bond });
+                } else {
+                    // Resolving creates the pallet account if it was never funded
+                    let (imbalance, _) = <T as pallet::Config>::Currency::slash_reserved(&challenger, bond);
+                    let amount = imbalance.peek();
+                    <T as pallet::Config>::Currency::resolve_creating(&pallet_account, imbalance);
+                    Self::deposit_event(Event::ChallengeBondForfeited { project_id, challenger: challenger.clone(), amount });
+                }
+                // Keep the challenge count for the round history; the bond is settled.
+                JurorChallenges::<T>::insert((project_id, round), &challenger, (used, BalanceOf::<T>::zero()));
+            }
+            Ok(())
+        }
         /// Calculate arbitration costs for a given round (covers jury fees + platform fees)
         pub fn calculate_arbitration_cost(project_id: &T::ProjectId, round: u32) -> Result<BalanceOf<T>, DispatchError> {
             let project_budget = T::Arbitrable::get_project_budget(*project_id)?;
             
             let cost_percentage = match round {
-                1 => 2u32,  // 2% for AI processing
-                2 => 5u32,  // 5% for first appeal (Bronze jury)
-                3 => 8u32,  // 8% for final appeal (Silver jury)
+                1..=3 => Self::fee_schedule().arbitration_cost[round as usize - 1],
                 _ => return Err(Error::<T>::InvalidRound.into()),
             };
-            Ok(project_budget.saturating_mul(cost_percentage.into()) / (100u32.into()))
+            Ok(Self::percent_of(project_budget, cost_percentage))
         }
         /// Calculate appeal bond (separate from arbitration costs)
         pub fn calculate_appeal_bond(project_id: &T::ProjectId, round: u32) -> Result<BalanceOf<T>, DispatchError> {
             let project_budget = T::Arbitrable::get_project_budget(*project_id)?;
             
+            let bond_percentages = Self::fee_schedule().appeal_bond;
             let (bond_percentage, _minimum_bond) = match round {
-                1 => (5u32, T::MinimumAiBond::get()),
-                2 => (20u32, T::MinimumFirstAppealBond::get()),
-                3 => (50u32, T::MinimumFinalAppealBond::get()),
+                1 => (bond_percentages[0], T::MinimumAiBond::get()),
+                2 => (bond_percentages[1], T::MinimumFirstAppealBond::get()),
+                3 => (bond_percentages[2], T::MinimumFinalAppealBond::get()),
                 _ => return Err(Error::<T>::InvalidRound.into()),
             };
-            Ok(project_budget.saturating_mul(bond_percentage.into()) / (100u32.into()))
+            Ok(Self::percent_of(project_budget, bond_percentage))
         }
         /// Calculate individual jury fees (base fee + potential performance bonus)
         pub fn calculate_jury_fees(project_id: &T::ProjectId, round: u32) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
             let project_budget = T::Arbitrable::get_project_budget(*project_id)?;
             
+            let schedule = Self::fee_schedule();
             // Base fee per juror (guaranteed regardless of vote)
             let base_fee_percentage = match round {
-                2 => 1u32,  // 1% of project budget per juror for Bronze jury
-                3 => 2u32,  // 2% of project budget per juror for Silver jury
+                2 | 3 => schedule.juror_base_fee[round as usize - 2],
                 _ => return Err(Error::<T>::InvalidRound.into()),
             };
-            let base_fee = project_budget.saturating_mul(base_fee_percentage.into()) / (100u32.into());
+            let base_fee = Self::percent_of(project_budget, base_fee_percentage);
             
             // Performance bonus for voting with majority (additional incentive for careful consideration)
-            let performance_bonus = base_fee / 4u32.into(); // 25% of base fee as bonus
+            let performance_bonus = Self::percent_of(base_fee, schedule.performance_bonus);
             Ok((base_fee, performance_bonus))
         }
+        /// `percent` of `amount`, rounded down.
+        fn percent_of(amount: BalanceOf<T>, percent: Percent) -> BalanceOf<T> {
+            amount.saturating_mul(u32::from(percent.deconstruct()).into()) / (100u32.into())
+        }
         /// Award jury fees for a completed round
         pub fn award_jury_fees_for_round(
             project_id: T::ProjectId,
@@ -661,15 +1181,20 @@ pub mod pallet {
         fn pay_all_jury_rewards(project_id: T::ProjectId) -> DispatchResult {
             let pallet_account = Self::account_id();
             
-            // Pay out all accumulated jury rewards
+            // Pay out all accumulated jury rewards, shared with the juror's delegators
             for (juror, total_reward) in JurorRewards::<T>::iter_prefix(project_id) {
                 if !total_reward.is_zero() {
-                    <T as pallet::Config>::Currency::transfer(
-                        &pallet_account,
-                        &juror,
-                        total_reward,
-                        ExistenceRequirement::KeepAlive,
-                    ).map_err(|_| Error::<T>::PaymentFailed)?;
+                    for (account, share) in <T as pallet::Config>::Reputation::reward_shares(&juror, total_reward) {
+                        if share.is_zero() {
+                            continue;
+                        }
+                        <T as pallet::Config>::Currency::transfer(
+                            &pallet_account,
+                            &account,
+                            share,
+                            ExistenceRequirement::KeepAlive,
+                        ).map_err(|_| Error::<T>::PaymentFailed)?;
+                    }
                     Self::deposit_event(Event::JurorRewarded {
                         project_id,
                         juror,
@@ -707,6 +1232,11 @@ pub mod pallet {
         pub fn account_id() -> T::AccountId {
             T::PalletId::get().into_sub_account_truncating(())
         }
+        /// Pays `EnforcementBounty`, kept apart from the juror fees in `account_id`. Nothing pays
+        /// into it automatically; it is topped up by transfers, e.g. from the treasury.
+        pub fn bounty_account() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"bounty")
+        }
         fn convert_to_project_ruling(ruling: Ruling) -> pallet_projects::Ruling {
             match ruling {
                 Ruling::ClientWins => pallet_projects::Ruling::ClientWins,
@@ -725,5 +1255,145 @@ pub mod pallet {
         pub fn get_appeal_bond_info(project_id: T::ProjectId, round: u32) -> Option<(T::AccountId, BalanceOf<T>)> {
             AppealBonds::<T>::get(project_id, round)
         }
+        /// Get the recorded history of every round of a dispute, oldest first (useful for external queries)
+        pub fn get_dispute_history(project_id: T::ProjectId) -> Vec<(u32, RoundRecord<T>)> {
+            // Live disputes know their current round; archived ones keep the count in their summary
+            let rounds = Self::disputes(project_id)
+                .map(|dispute| dispute.round)
+                .or_else(|| Self::dispute_summaries(project_id).map(|summary| summary.rounds))
+                .unwrap_or_default();
+            (1..=rounds)
+                .filter_map(|round| DisputeRounds::<T>::get((project_id, round)).map(|record| (round, record)))
+                .collect()
+        }
+        /// Copy the outcome of the current round into its history record.
+        fn conclude_round_record(project_id: T::ProjectId, dispute: &DisputeInfo<T>, concluded_at: BlockNumberFor<T>) {
+            DisputeRounds::<T>::mutate((project_id, dispute.round), |maybe_record| {
+                if let Some(record) = maybe_record {
+                    record.jurors = dispute.jurors.clone();
+                    record.votes = dispute.votes.clone();
+                    record.ruling = dispute.ruling;
+                    record.concluded_at = Some(concluded_at);
+                }
+            });
+        }
+        /// Get the ruling rationale and all juror rationales recorded for a round (useful for external queries)
+        pub fn get_round_rationales(
+            project_id: T::ProjectId,
+            round: u32,
+        ) -> (Option<Rationale>, Vec<(T::AccountId, Rationale)>) {
+            let ruling_rationale = RulingRationales::<T>::get((project_id, round));
+            let juror_rationales = JurorRationales::<T>::iter_prefix((project_id, round)).collect();
+            (ruling_rationale, juror_rationales)
+        }
+        /// Validate and store a rationale, either for the round's ruling or for a juror's vote.
+        fn store_rationale(
+            project_id: T::ProjectId,
+            round: u32,
+            juror: Option<T::AccountId>,
+            rationale: Rationale,
+        ) -> DispatchResult {
+            ensure!(!rationale.uri.is_empty(), Error::<T>::InvalidRationale);
+            let content_hash = rationale.content_hash;
+            match &juror {
+                Some(who) => JurorRationales::<T>::insert((project_id, round), who, rationale),
+                None => RulingRationales::<T>::insert((project_id, round), rationale),
+            }
+            Self::deposit_event(Event::RationaleSubmitted { project_id, round, juror, content_hash });
+            Ok(())
+        }
+        /// Queue a dispute to be finalized or enforced automatically once block `at` is reached.
+        pub(crate) fn schedule_deadline(project_id: T::ProjectId, at: BlockNumberFor<T>) {
+            let mut block = at;
+            // Spill over into the following blocks if the target block is already full. Every
+            // queued entry belongs to a dispute, so a block with room is always found.
+            while !DisputeDeadlines::<T>::mutate(block, |queue| queue.try_push(project_id).is_ok()) {
+                block = block.saturating_add(One::one());
+            }
+            DeadlineCursor::<T>::mutate(|cursor| {
+                if cursor.is_zero() || *cursor > block {
+                    *cursor = block;
+                }
+            });
+            if block != at {
+                Self::deposit_event(Event::DeadlineDeferred { project_id, requested: at, scheduled: block });
+            }
+        }
+        /// Walk the deadline queue up to `now`, processing as many disputes as the weight allows.
+        pub(crate) fn process_deadlines(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut used = db_weight.reads(1);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+            let mut cursor = DeadlineCursor::<T>::get();
+            if cursor.is_zero() || cursor > now {
+                return used;
+            }
+            // Reading and clearing one block's queue.
+            let bucket_weight = db_weight.reads_writes(1, 1);
+            // Worst case for one dispute: payouts to every juror of the round plus the party settlements.
+            let item_weight = db_weight.reads_writes(12, 12)
+                .saturating_add(db_weight.reads_writes(3, 3).saturating_mul(T::MaxJurors::get().into()));
+            let mut processed = 0u32;
+
+            while cursor <= now {
+                if remaining_weight.any_lt(used.saturating_add(bucket_weight).saturating_add(item_weight)) {
+                    break;
+                }
+                used = used.saturating_add(bucket_weight);
+                let mut queue = DisputeDeadlines::<T>::take(cursor);
+                while !queue.is_empty() {
+                    if processed >= T::MaxDeadlinesPerBlock::get()
+                        || remaining_weight.any_lt(used.saturating_add(item_weight))
+                    {
+                        // Out of budget: put the rest back and resume from this block next time.
+                        DisputeDeadlines::<T>::insert(cursor, queue);
+                        DeadlineCursor::<T>::put(cursor);
+                        return used.saturating_add(db_weight.writes(2));
+                    }
+                    let project_id = queue.remove(0);
+                    Self::process_deadline(project_id, now);
+                    used = used.saturating_add(item_weight);
+                    processed += 1;
+                }
+                cursor = cursor.saturating_add(One::one());
+            }
+            DeadlineCursor::<T>::put(cursor);
+            used.saturating_add(db_weight.writes(1))
+        }
+        /// Finalize or enforce a single queued dispute if its period is over.
+        fn process_deadline(project_id: T::ProjectId, now: BlockNumberFor<T>) {
+            let Some(dispute) = Disputes::<T>::get(project_id) else { return };
+            let result = match dispute.status {
+                DisputeStatus::Voting if now >= Self::voting_deadline(project_id, &dispute) => {
+                    frame_support::storage::with_storage_layer(|| Self::do_finalize_round(project_id))
+                },
+                DisputeStatus::Appealable if now >= dispute.start_block.saturating_add(T::AppealPeriod::get()) => {
+                    frame_support::storage::with_storage_layer(|| Self::do_enforce_final_ruling(project_id))
+                },
+                // The dispute has moved on since this entry was queued (appealed or handled manually).
+                _ => return,
+            };
+            if let Err(error) = result {
+                Self::deposit_event(Event::AutomaticProcessingFailed { project_id, error });
+            }
+        }
+        /// Reward the caller of a manual finalize/enforce from the bounty account, if funds allow.
+        fn pay_enforcement_bounty(project_id: T::ProjectId, caller: &T::AccountId) {
+            let amount = T::EnforcementBounty::get();
+            if amount.is_zero() {
+                return;
+            }
+            // Best effort: the ruling has already been applied and must not be undone by an empty pot.
+            if <T as pallet::Config>::Currency::transfer(
+                &Self::bounty_account(),
+                caller,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            ).is_ok() {
+                Self::deposit_event(Event::EnforcementBountyPaid { project_id, caller: caller.clone(), amount });
+            }
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,126 @@
+//! Storage migrations for the arbitration pallet.
+//!
+//! Each `vN` module upgrades storage from version `N - 1` to `N` as a [`SteppedMigration`] for
+//! `pallet-migrations`, and is a no-op at any other version. Under `try-runtime` every
+//! migration records what it expects in `pre_upgrade` and checks it in `post_upgrade`.
+//!
+//! [`SteppedMigration`]: frame_support::migrations::SteppedMigration
+
+pub mod v1 {
+    use crate::pallet::{
+        Config, DeadlineCursor, DisputeDeadlines, DisputeInfo, DisputeStatus, Disputes, Pallet, VotingExtensions,
+    };
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+    use sp_runtime::traits::{One, Saturating, Zero};
+
+    /// Number of following blocks a full deadline queue spills over into.
+    const MAX_SPILL: u32 = 10;
+
+    /// Block at which the current period of `dispute` ends, if it has one.
+    fn deadline_of<T: Config>(project_id: T::ProjectId, dispute: &DisputeInfo<T>) -> Option<BlockNumberFor<T>> {
+        match dispute.status {
+            DisputeStatus::Voting => Some(
+                dispute.start_block
+                    .saturating_add(T::VotingPeriod::get())
+                    .saturating_add(VotingExtensions::<T>::get((project_id, dispute.round))),
+            ),
+            DisputeStatus::Appealable => Some(dispute.start_block.saturating_add(T::AppealPeriod::get())),
+            _ => None,
+        }
+    }
+
+    /// Queues every dispute that is voting or waiting for an appeal in `DisputeDeadlines`, so
+    /// disputes opened before deadlines were processed in `on_idle` are finalized and enforced
+    /// like new ones. A dispute that is already queued is queued again; whichever entry comes
+    /// second finds the dispute has moved on and skips it. Each step queues as many disputes as
+    /// the weight meter allows, resuming after the last project id.
+    pub struct LazyMigrationV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV1<T> {
+        type Cursor = T::ProjectId;
+        type Identifier = MigrationId<18>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-arbitration", version_from: 0, version_to: 1 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return Ok(None);
+            }
+
+            // Worst case for one dispute: read it and its extension, then try every spill-over block
+            let required = T::DbWeight::get().reads_writes(3 + u64::from(MAX_SPILL), 2 + u64::from(MAX_SPILL));
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            while meter.try_consume(required).is_ok() {
+                let mut remaining = match cursor {
+                    Some(last) => Disputes::<T>::iter_from(Disputes::<T>::hashed_key_for(last)),
+                    None => Disputes::<T>::iter(),
+                };
+                let Some((project_id, dispute)) = remaining.next() else {
+                    StorageVersion::new(1).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+                if let Some(mut block) = deadline_of::<T>(project_id, &dispute) {
+                    for _ in 0..MAX_SPILL {
+                        if DisputeDeadlines::<T>::mutate(block, |queue| queue.try_push(project_id).is_ok()) {
+                            DeadlineCursor::<T>::mutate(|cursor| {
+                                if cursor.is_zero() || *cursor > block {
+                                    *cursor = block;
+                                }
+                            });
+                            break;
+                        }
+                        block = block.saturating_add(One::one());
+                    }
+                }
+                cursor = Some(project_id);
+            }
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            use codec::Encode;
+
+            // Queue entries expected afterwards: those already there plus one per open period
+            let expected = (Pallet::<T>::on_chain_storage_version() == 0).then(|| {
+                let queued = DisputeDeadlines::<T>::iter_values().map(|queue| queue.len() as u32).sum::<u32>();
+                let open = Disputes::<T>::iter()
+                    .filter(|(project_id, dispute)| deadline_of::<T>(*project_id, dispute).is_some())
+                    .count() as u32;
+                queued.saturating_add(open)
+            });
+            Ok(expected.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            use codec::Decode;
+
+            let Some(expected) = Option::<u32>::decode(&mut &state[..])
+                .map_err(|_| "v1: cannot decode pre-upgrade state")?
+            else {
+                return Ok(());
+            };
+            ensure!(Pallet::<T>::on_chain_storage_version() == 1, "v1: storage version not bumped");
+            let queued = DisputeDeadlines::<T>::iter_values().map(|queue| queue.len() as u32).sum::<u32>();
+            ensure!(queued == expected, "v1: open disputes missing from the deadline queue");
+            Ok(())
+        }
+    }
+}
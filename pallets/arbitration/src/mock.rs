@@ -3,7 +3,7 @@ use std::cell::{RefCell};
 use crate as pallet_arbitration;
 use frame_support::{
     parameter_types,
-    traits::{ConstU32, ConstU64},
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
     weights::constants::RocksDbWeight,
     PalletId,
 };
@@ -12,12 +12,17 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage, AccountId32,
 };
-use pallet_reputation::{ReputationInterface, JurorTier};
+use pallet_reputation::{ReputationInterface, JurorTier, ProjectRole, Skill};
 use frame_support::dispatch::{DispatchResult};
 use sp_runtime::DispatchError;
 use frame_support::BoundedVec;
-use pallet_projects::{Arbitrable, ProjectStatus, Ruling};
+use pallet_projects::{Arbitrable, ContentCheck, ProjectStatus, Ruling};
 use sp_runtime::Permill;
+use pallet_nfts::{
+    CollectionConfig, CollectionConfigFor, CollectionSetting, CollectionSettings, ItemConfig,
+    ItemSetting, ItemSettings, MintSettings, PalletFeatures,
+};
+use sp_runtime::{MultiSignature, MultiSigner};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 pub type Balance = u64;
@@ -30,6 +35,7 @@ frame_support::construct_runtime!(
         System: frame_system,
         Balances: pallet_balances,
         Projects: pallet_projects,
+        Nfts: pallet_nfts,
         Reputation: pallet_reputation,
         Arbitration: pallet_arbitration,
     }
@@ -79,6 +85,7 @@ parameter_types! {
     pub const ArbitrationPalletId: PalletId = PalletId(*b"tsk/arbt");
     pub const MaxApplicantsLength: u32 = 100;
     pub const ReviewPeriod: BlockNumber = 10000;
+    pub static EnforcementBounty: Balance = 0;
 }
 
 impl pallet_balances::Config for Test {
@@ -113,18 +120,30 @@ impl MockReputation {
 }
 
 impl ReputationInterface<AccountId32, Balance, u32, BlockNumber, MaxApplicantsLength> for MockReputation {
-    fn on_project_completed(_freelancer: &AccountId32, _project_value: Balance, _client_rating: u32, _project_id: u32) -> DispatchResult { Ok(()) }
-    fn on_dispute_outcome(_winner: &AccountId32, _loser: &AccountId32, _project_id: u32, _project_value: Balance) -> DispatchResult { Ok(()) }
+    fn on_project_completed(_freelancer: &AccountId32, _project_value: Balance, _client_rating: u32, _project_id: u32, _skills: &[Skill]) -> DispatchResult { Ok(()) }
+    fn on_dispute_outcome(_winner: &AccountId32, _loser: &AccountId32, _project_id: u32, _project_value: Balance, _skills: &[Skill], _loser_role: ProjectRole) -> DispatchResult { Ok(()) }
     fn on_project_created(_client: &AccountId32, _budget: Balance) -> DispatchResult { Ok(()) }
     fn on_project_cancelled(_client: &AccountId32) -> DispatchResult { Ok(()) }
     fn on_work_accepted(_client: &AccountId32, _project_id: u32) -> DispatchResult { Ok(()) }
-    fn get_eligible_jurors(_min_tier: JurorTier, _exclude: &[AccountId32], _count: u32) -> BoundedVec<AccountId32, MaxApplicantsLength> {
-        JURORS.with(|j| j.borrow().clone())
+    fn on_client_rated(_client: &AccountId32, _project_value: Balance, _freelancer_rating: u32, _project_id: u32) -> DispatchResult { Ok(()) }
+    fn on_work_started(_client: &AccountId32, _freelancer: &AccountId32, _project_id: u32) -> DispatchResult { Ok(()) }
+    fn get_eligible_jurors(_project_id: u32, _min_tier: JurorTier, parties: &[AccountId32], exclude: &[AccountId32], count: u32, _skills: &[Skill]) -> BoundedVec<AccountId32, MaxApplicantsLength> {
+        JURORS.with(|j| {
+            let eligible: Vec<AccountId32> = j.borrow().iter()
+                .filter(|juror| !parties.contains(juror) && !exclude.contains(juror))
+                .take(count as usize)
+                .cloned()
+                .collect();
+            BoundedVec::truncate_from(eligible)
+        })
     }
-    fn on_jury_vote(_juror: &AccountId32, _voted_with_majority: bool) -> DispatchResult { Ok(()) }
+    fn skill_score(_account: &AccountId32, _skills: &[Skill]) -> u32 { 0 }
+    fn has_conflict_of_interest(_juror: &AccountId32, _parties: &[AccountId32]) -> bool { false }
+    fn on_jury_vote(_juror: &AccountId32, _voted_with_majority: bool, _project_id: u32) -> DispatchResult { Ok(()) }
     fn slash_juror(_juror: &AccountId32) -> DispatchResult {
         Ok(())
     }
+    fn reward_shares(juror: &AccountId32, reward: Balance) -> Vec<(AccountId32, Balance)> { vec![(juror.clone(), reward)] }
 }
 
 pub struct MockArbitrable;
@@ -143,6 +162,9 @@ thread_local! {
     pub static PROJECT_PARTIES: RefCell<HashMap<ProjectId, (AccountId32, AccountId32)>> = RefCell::new(HashMap::new());
     pub static PROJECT_BUDGETS: RefCell<HashMap<ProjectId, Balance>> = RefCell::new(HashMap::new());
     static LAST_RULING: RefCell<Option<(ProjectId, Ruling)>> = RefCell::new(None);
+    static EVIDENCE_READERS: RefCell<Vec<AccountId32>> = const { RefCell::new(Vec::new()) };
+    static WITHHELD_BRIEFS: RefCell<Vec<AccountId32>> = const { RefCell::new(Vec::new()) };
+    static CONTENT_CHECKS: RefCell<HashMap<ProjectId, ContentCheck>> = RefCell::new(HashMap::new());
 }
 
 impl MockArbitrable {
@@ -161,6 +183,22 @@ impl MockArbitrable {
     pub fn last_ruling() -> Option<(ProjectId, Ruling)> {
         LAST_RULING.with(|l| l.borrow().clone())
     }
+
+    pub fn set_content_check(project_id: ProjectId, check: ContentCheck) {
+        CONTENT_CHECKS.with(|c| {
+            c.borrow_mut().insert(project_id, check);
+        });
+    }
+
+    /// Everyone the evidence has been requested for, in order.
+    pub fn evidence_readers() -> Vec<AccountId32> {
+        EVIDENCE_READERS.with(|r| r.borrow().clone())
+    }
+
+    /// `reader` was granted access to the brief, but no copy was shared with them.
+    pub fn withhold_brief(reader: AccountId32) {
+        WITHHELD_BRIEFS.with(|w| w.borrow_mut().push(reader));
+    }
 }
 
 impl Arbitrable<ProjectId, Balance, AccountId32, BlockNumber> for MockArbitrable {
@@ -185,6 +223,68 @@ impl Arbitrable<ProjectId, Balance, AccountId32, BlockNumber> for MockArbitrable
 		let uri2: BoundedVec<u8, ConstU32<256>> = "sub_uri".as_bytes().to_vec().try_into().unwrap();
 		Ok((uri1, uri2))
 	}
+
+	fn grant_evidence_access(_project_id: u32, readers: &[AccountId32]) -> DispatchResult {
+		EVIDENCE_READERS.with(|r| r.borrow_mut().extend_from_slice(readers));
+		Ok(())
+	}
+
+	fn brief_withheld(_project_id: u32, reader: &AccountId32) -> bool {
+		WITHHELD_BRIEFS.with(|w| w.borrow().contains(reader))
+	}
+
+	fn get_project_skills(_project_id: u32) -> Result<Vec<Skill>, sp_runtime::DispatchError> {
+		Ok(Vec::new())
+	}
+
+	fn get_content_check(project_id: u32) -> Option<ContentCheck> {
+		CONTENT_CHECKS.with(|c| c.borrow().get(&project_id).copied())
+	}
+}
+
+parameter_types! {
+    pub const ReputationPalletId: PalletId = PalletId(*b"tsk/repu");
+    pub NftFeatures: PalletFeatures = PalletFeatures::all_enabled();
+    pub CredentialCollectionConfig: CollectionConfigFor<Test> = CollectionConfig {
+        settings: CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into()),
+        max_supply: None,
+        mint_settings: MintSettings::default(),
+    };
+    pub CredentialItemConfig: ItemConfig = ItemConfig {
+        settings: ItemSettings::from_disabled(ItemSetting::Transferable.into()),
+    };
+}
+
+impl pallet_nfts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId32>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId32>;
+    type Locker = ();
+    type CollectionDeposit = ConstU64<0>;
+    type ItemDeposit = ConstU64<0>;
+    type MetadataDepositBase = ConstU64<0>;
+    type AttributeDepositBase = ConstU64<0>;
+    type DepositPerByte = ConstU64<0>;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<10>;
+    type ItemAttributesApprovalsLimit = ConstU32<2>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU64<10000>;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftFeatures;
+    type OffchainSignature = MultiSignature;
+    type OffchainPublic = MultiSigner;
+    type WeightInfo = ();
+    type BlockNumberProvider = System;
+}
+
+frame_support::ord_parameter_types! {
+    pub const OracleAccount: AccountId32 = AccountId32::new([0xAA; 32]);
 }
 
 parameter_types! {
@@ -195,6 +295,7 @@ impl pallet_reputation::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId32>;
+    type JurorEmergencyOrigin = frame_system::EnsureRoot<AccountId32>;
     type Currency = Balances;
     type ProjectId = u32;
     type MaxMetadata = ConstU32<1024>;
@@ -203,6 +304,61 @@ impl pallet_reputation::Config for Test {
     type MaxSilverJurors = ConstU32<200>;
     type MaxBronzeJurors = ConstU32<200>;
     type SlashRatio = SlashRatio;
+    type ConflictWindow = ConstU64<1000>;
+    type MaxRecentProjects = ConstU32<4>;
+    type Randomness = pallet_reputation::ParentHashRandomness<Test>;
+    type DecayPeriod = ConstU64<100>;
+    type MaxRescoresPerBlock = ConstU32<10>;
+    type PalletId = ReputationPalletId;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NftCollectionConfig = CollectionConfigFor<Test>;
+    type NftItemConfig = ItemConfig;
+    type Nfts = Nfts;
+    type CredentialCollectionConfig = CredentialCollectionConfig;
+    type CredentialItemConfig = CredentialItemConfig;
+    type PartnerChainOrigin = frame_system::EnsureRootWithSuccess<AccountId32, ConstU32<2000>>;
+    type ReputationSender = ();
+    type MaxPartnerCalls = ConstU32<100>;
+    type PartnerQuotaPeriod = ConstU64<10>;
+    type UnbondingPeriod = ConstU64<100>;
+    type MaxUnlockingChunks = ConstU32<4>;
+    type MaxDelegators = ConstU32<8>;
+    type BalanceUnit = ConstU64<1>;
+    type PublishFee = ConstU64<10>;
+}
+
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = MultiSigner;
+    type Signature = MultiSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: MultiSigner,
+        _account: AccountId32,
+        nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, nonce, (), ()))
+    }
+}
+
+parameter_types! {
+    pub const IpfsGateway: &'static str = "http://127.0.0.1:8080";
 }
 
 impl pallet_projects::Config for Test {
@@ -212,8 +368,17 @@ impl pallet_projects::Config for Test {
     type PalletId = ProjectsPalletId;
     type MaxApplicants = MaxApplicantsLength;
     type ReviewPeriod = ReviewPeriod;
+    type RatingPeriod = ConstU64<100>;
+    type OfferPeriod = ConstU64<50>;
+    type MaxSkills = ConstU32<5>;
+    type ApplicationDeposit = ConstU64<0>;
     type WeightInfo = ();
     type Reputation = MockReputation;
+    type AuthorityId = pallet_projects::crypto::VerifierAuthId;
+    type VerifierOrigin = frame_system::EnsureSigned<AccountId32>;
+    type IpfsGateway = IpfsGateway;
+    type MaxContentChecksPerBlock = ConstU32<5>;
+    type MaxContentSize = ConstU32<1024>;
 }
 
 impl pallet_arbitration::Config for Test {
@@ -222,6 +387,9 @@ impl pallet_arbitration::Config for Test {
     type ProjectId = u32;
     type WeightInfo = ();
     type AiOracleOrigin = frame_system::EnsureRoot<AccountId32>;
+    type Oracles = OracleAccount;
+    type FeeOrigin = frame_system::EnsureRoot<AccountId32>;
+    type HaltOrigin = frame_system::EnsureRoot<AccountId32>;
     type Arbitrable = MockArbitrable;
     type Reputation = MockReputation;
     type MaxJurors = MaxApplicantsLength;
@@ -230,6 +398,12 @@ impl pallet_arbitration::Config for Test {
     type AiProcessingPeriod = ConstU64<100>;
     type VotingPeriod = ConstU64<200>;
     type AppealPeriod = ConstU64<100>;
+    type DisputeRetentionPeriod = ConstU64<1000>;
+    type MaxDeadlinesPerBlock = ConstU32<50>;
+    type EnforcementBounty = EnforcementBounty;
+    type MaxChallengesPerRound = ConstU32<1>;
+    type ChallengeBond = ConstU64<UNIT>;
+    type ReplacementVotingPeriod = ConstU64<50>;
     type MinimumAiBond = ConstU64<{UNIT / 2}>;
     type MinimumFirstAppealBond = ConstU64<{2 * UNIT}>;
     type MinimumFinalAppealBond = ConstU64<{5 * UNIT}>;
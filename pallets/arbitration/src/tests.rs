@@ -1,7 +1,8 @@
 
-use crate::{mock::*, DisputeStatus, Event, Ruling, Vote};
-use frame_support::{assert_ok, BoundedVec, traits::{Currency, ConstU32, Get}};
-use sp_runtime::AccountId32;
+use crate::{mock::*, DisputeStatus, Error, Event, FeeSchedule, Rationale, Ruling, Vote};
+use frame_support::{assert_noop, assert_ok, BoundedVec, traits::{Currency, ConstU32, Get, Hooks}, weights::Weight};
+use pallet_projects::{ContentCheck, HiringMode};
+use sp_runtime::{AccountId32, Percent};
 
 // Helper function to convert a string to a bounded vec
 fn str_to_bounded(s: &str) -> BoundedVec<u8, ConstU32<256>> {
@@ -36,16 +37,22 @@ fn initiate_ai_dispute_works() {
             RuntimeOrigin::signed(client.clone()),
             budget,
             str_to_bounded("Test Project"),
-            1000
+            1000,
+            Default::default(),
+            HiringMode::Open
         ));
         assert_ok!(Projects::apply_for_project(
             RuntimeOrigin::signed(freelancer.clone()),
-            project_id
+            project_id,
+            budget,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
         assert_ok!(Projects::start_work(
             RuntimeOrigin::signed(client.clone()),
             project_id,
-            freelancer.clone()
+            freelancer.clone(),
+            None
         ));
         System::set_block_number(1);
         // Check initial balances
@@ -123,7 +130,9 @@ fn submit_ai_ruling_works() {
             RuntimeOrigin::signed(client.clone()),
             budget,
             str_to_bounded("Test Project"),
-            1000 // duration
+            1000, // duration
+            Default::default(),
+            HiringMode::Open
         ));
 
         // Fund the freelancer so they can apply for the project
@@ -132,14 +141,18 @@ fn submit_ai_ruling_works() {
         // Freelancer applies for the project
         assert_ok!(Projects::apply_for_project(
             RuntimeOrigin::signed(freelancer.clone()),
-            project_id
+            project_id,
+            budget,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
 
         // Client accepts the freelancer's application
         assert_ok!(Projects::start_work(
             RuntimeOrigin::signed(client.clone()),
             project_id,
-            freelancer.clone()
+            freelancer.clone(),
+            None
         ));
 
         System::set_block_number(1);
@@ -156,7 +169,8 @@ fn submit_ai_ruling_works() {
         assert_ok!(Arbitration::submit_ruling(
             RuntimeOrigin::root(),
             project_id,
-            ruling
+            ruling,
+            None
         ));
 
         // --- ASSERT ---
@@ -191,9 +205,9 @@ fn enforce_ruling_after_single_ai_round_works() {
 
         // --- SETUP THE DISPUTE (similar to your helper) ---
         // 1. Create the project
-        assert_ok!(Projects::create_project(RuntimeOrigin::signed(client.clone()), budget, str_to_bounded("AI Win Test"), 1000));
-        assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id));
-        assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone()));
+        assert_ok!(Projects::create_project(RuntimeOrigin::signed(client.clone()), budget, str_to_bounded("AI Win Test"), 1000, Default::default(), HiringMode::Open));
+        assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id, budget, 1000, str_to_bounded("ipfs://proposal")));
+        assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone(), None));
         
         // 2. Freelancer initiates dispute (Round 1)
         System::set_block_number(1);
@@ -211,7 +225,7 @@ fn enforce_ruling_after_single_ai_round_works() {
 
         // 3. AI rules in favor of the freelancer
         System::set_block_number(2);
-        assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::FreelancerWins));
+        assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::FreelancerWins, None));
 
         // --- ACT ---
         // The client concedes by not appealing. We advance time past the appeal period.
@@ -272,9 +286,9 @@ fn enforce_ruling_freelancer_loses_ai_round() {
 
         // --- SETUP THE DISPUTE ---
         // 1. Create project and get to a disputable state
-        assert_ok!(Projects::create_project(RuntimeOrigin::signed(client.clone()), budget, str_to_bounded("AI Lose Test"), 1000));
-        assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id));
-        assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone()));
+        assert_ok!(Projects::create_project(RuntimeOrigin::signed(client.clone()), budget, str_to_bounded("AI Lose Test"), 1000, Default::default(), HiringMode::Open));
+        assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id, budget, 1000, str_to_bounded("ipfs://proposal")));
+        assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone(), None));
         
         // 2. Freelancer initiates dispute (Round 1)
         System::set_block_number(1);
@@ -291,7 +305,7 @@ fn enforce_ruling_freelancer_loses_ai_round() {
 
         // 3. AI rules AGAINST the freelancer (Client Wins)
         System::set_block_number(2);
-        assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::ClientWins));
+        assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::ClientWins, None));
 
         // --- ACT ---
         // The freelancer concedes by not appealing. Fast-forward time.
@@ -350,20 +364,26 @@ fn appeal_ruling_works() {
             RuntimeOrigin::signed(client.clone()),
             budget,
             str_to_bounded("Test Project"),
-            1000 // duration
+            1000, // duration
+            Default::default(),
+            HiringMode::Open
         ));
         // Fund the freelancer so they can apply for the project
         let _ = Balances::deposit_creating(&freelancer, 3 * UNIT);
         // Freelancer applies for the project
         assert_ok!(Projects::apply_for_project(
             RuntimeOrigin::signed(freelancer.clone()),
-            project_id
+            project_id,
+            budget,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
         // Client accepts the freelancer's application
         assert_ok!(Projects::start_work(
             RuntimeOrigin::signed(client.clone()),
             project_id,
-            freelancer.clone()
+            freelancer.clone(),
+            None
         ));
         System::set_block_number(1);
         assert_ok!(Arbitration::create_dispute(
@@ -375,7 +395,8 @@ fn appeal_ruling_works() {
         assert_ok!(Arbitration::submit_ruling(
             RuntimeOrigin::root(),
             project_id,
-            ruling
+            ruling,
+            None
         ));
         let jurors = vec![account("juror1"), account("juror2"), account("juror3")];
         MockReputation::set_jurors(jurors.clone());
@@ -447,7 +468,9 @@ fn cast_vote_and_finalize_round_works() {
             RuntimeOrigin::signed(client.clone()),
             budget,
             str_to_bounded("Test Project"),
-            1000 // duration
+            1000, // duration
+            Default::default(),
+            HiringMode::Open
         ));
 
         // Fund the freelancer so they can apply for the project
@@ -456,14 +479,18 @@ fn cast_vote_and_finalize_round_works() {
         // Freelancer applies for the project
         assert_ok!(Projects::apply_for_project(
             RuntimeOrigin::signed(freelancer.clone()),
-            project_id
+            project_id,
+            budget,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
 
         // Client accepts the freelancer's application
         assert_ok!(Projects::start_work(
             RuntimeOrigin::signed(client.clone()),
             project_id,
-            freelancer.clone()
+            freelancer.clone(),
+            None
         ));
 
         System::set_block_number(1);
@@ -479,7 +506,8 @@ fn cast_vote_and_finalize_round_works() {
         assert_ok!(Arbitration::submit_ruling(
             RuntimeOrigin::root(),
             project_id,
-            ruling
+            ruling,
+            None
         ));
 
         let juror1 = account("juror1");
@@ -494,9 +522,9 @@ fn cast_vote_and_finalize_round_works() {
         ));
 
         // --- ACT ---
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient, None));
 
         System::set_block_number(System::block_number() + 200 + 1);
 
@@ -530,9 +558,9 @@ fn finalize_round_records_juror_rewards() {
         ));
 
         // majority = freelancer wins - 2 jurors vote with majority, 1 against
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient, None));
 
         System::set_block_number(System::block_number() + 200 + 1);
         assert_ok!(Arbitration::finalize_round(RuntimeOrigin::signed(account("any")), project_id));
@@ -575,9 +603,9 @@ fn enforce_final_ruling_completes_dispute_and_makes_payments() {
         ));
 
         // freelancer wins => client is loser
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForFreelancer));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForFreelancer, None));
 
         let voting_period: u64 = <Test as crate::Config>::VotingPeriod::get();
         System::set_block_number(System::block_number() + voting_period + 1);
@@ -612,9 +640,9 @@ fn finalize_round_distributes_rewards_to_jurors_who_voted_with_majority() {
             project_id
         ));
         // Majority vote: 2 for freelancer, 1 for client
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient, None));
         System::set_block_number(System::block_number() + 200 + 1);
         assert_ok!(Arbitration::finalize_round(RuntimeOrigin::signed(account("any")), project_id));
         // Check that rewards are assigned to jurors who voted with the majority
@@ -657,9 +685,9 @@ fn enforce_final_ruling_pays_out_juror_rewards_and_handles_bonds() {
         ));
 
         // Cast votes (freelancer wins)
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient, None));
 
         let voting_period: u64 = <Test as crate::Config>::VotingPeriod::get();
         System::set_block_number(System::block_number() + voting_period + 1);
@@ -696,9 +724,9 @@ fn dispute_flow_full_slash_path() {
         ));
 
         // both jurors vote same way -> no slash
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer));
-        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForFreelancer));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForFreelancer, None));
 
         System::set_block_number(System::block_number() + 200 + 1);
         assert_ok!(Arbitration::finalize_round(RuntimeOrigin::signed(account("any")), project_id));
@@ -765,6 +793,432 @@ fn appeal_bonds_are_handled_correctly() {
     });
 }
 
+#[test]
+fn submit_ruling_stores_ai_rationale() {
+    new_test_ext().execute_with(|| {
+        let project_id = 0u32;
+        let client = account("alice");
+        let freelancer = account("bob");
+        let budget = 100_000;
+        MockArbitrable::set_project_parties(project_id, client.clone(), freelancer.clone());
+        MockArbitrable::set_project_budget(project_id, budget);
+        let _ = Balances::deposit_creating(&client, budget + 10 * UNIT);
+        let _ = Balances::deposit_creating(&freelancer, 10 * UNIT);
+        assert_ok!(Projects::create_project(RuntimeOrigin::signed(client.clone()), budget, str_to_bounded("Test Project"), 1000, Default::default(), HiringMode::Open));
+        assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id, budget, 1000, str_to_bounded("ipfs://proposal")));
+        assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone(), None));
+        System::set_block_number(1);
+        assert_ok!(Arbitration::create_dispute(RuntimeOrigin::signed(freelancer.clone()), project_id));
+
+        let rationale = Rationale { uri: str_to_bounded("ipfs://ai_reasoning"), content_hash: [7u8; 32] };
+        assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::ClientWins, Some(rationale.clone())));
+
+        assert_eq!(Arbitration::ruling_rationale((project_id, 1)), Some(rationale));
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::RationaleSubmitted {
+            project_id,
+            round: 1,
+            juror: None,
+            content_hash: [7u8; 32],
+        }));
+    });
+}
+
+#[test]
+fn cast_vote_stores_juror_rationale() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, freelancer) = create_project_and_dispute_to_round2();
+        let juror1 = account("juror1");
+        let juror2 = account("juror2");
+        let juror3 = account("juror3");
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone()]);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer.clone()), project_id));
+
+        let rationale = Rationale { uri: str_to_bounded("ipfs://juror1_notes"), content_hash: [1u8; 32] };
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, Some(rationale.clone())));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForClient, None));
+
+        // An empty URI is rejected and the vote is not recorded
+        let empty = Rationale { uri: str_to_bounded(""), content_hash: [0u8; 32] };
+        assert_noop!(
+            Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient, Some(empty)),
+            Error::<Test>::InvalidRationale
+        );
+
+        // The AI rationale of round 1 is still available next to the juror rationales of round 2
+        let (ruling_rationale, juror_rationales) = Arbitration::get_round_rationales(project_id, 2);
+        assert!(ruling_rationale.is_none());
+        assert_eq!(juror_rationales, vec![(juror1, rationale)]);
+    });
+}
+
+#[test]
+fn appeal_keeps_previous_round_history() {
+    new_test_ext().execute_with(|| {
+        let (project_id, client, freelancer) = create_project_and_dispute_to_round2();
+        let juror1 = account("juror1");
+        let juror2 = account("juror2");
+        let juror3 = account("juror3");
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone()]);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer.clone()), project_id));
+
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3.clone()), project_id, Vote::ForClient, None));
+        System::set_block_number(System::block_number() + 200 + 1);
+        assert_ok!(Arbitration::finalize_round(RuntimeOrigin::signed(account("any")), project_id));
+
+        // Client appeals to round 3, which resets the live dispute state
+        let _ = Balances::deposit_creating(&client, 100 * UNIT);
+        let max_jurors: u32 = <Test as crate::Config>::MaxJurors::get();
+        MockReputation::set_jurors((0..max_jurors).map(|i| account(&format!("r3juror{}", i))).collect());
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(client.clone()), project_id));
+        let dispute = Arbitration::disputes(project_id).unwrap();
+        assert!(dispute.votes.is_empty());
+
+        // ...but rounds 1 and 2 are still on record
+        let round1 = Arbitration::dispute_rounds((project_id, 1)).unwrap();
+        assert_eq!(round1.appellant, freelancer);
+        assert_eq!(round1.ruling, Some(Ruling::ClientWins));
+        assert_eq!(round1.concluded_at, Some(2));
+
+        let round2 = Arbitration::dispute_rounds((project_id, 2)).unwrap();
+        assert_eq!(round2.ruling, Some(Ruling::FreelancerWins));
+        assert_eq!(round2.votes.len(), 3);
+        assert_eq!(round2.votes.get(&juror3), Some(&Vote::ForClient));
+
+        let round3 = Arbitration::dispute_rounds((project_id, 3)).unwrap();
+        assert_eq!(round3.appellant, client);
+        assert!(round3.ruling.is_none());
+        assert_eq!(Arbitration::get_dispute_history(project_id).len(), 3);
+    });
+}
+
+#[test]
+fn archive_dispute_replaces_history_with_summary() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, _freelancer) = create_project_and_dispute_to_round2();
+        let appeal_period: u64 = <Test as crate::Config>::AppealPeriod::get();
+        System::set_block_number(System::block_number() + appeal_period + 1);
+        assert_ok!(Arbitration::enforce_final_ruling(RuntimeOrigin::signed(account("any")), project_id));
+        let finalized_at = System::block_number();
+
+        // Too early
+        assert_noop!(
+            Arbitration::archive_dispute(RuntimeOrigin::signed(account("any")), project_id),
+            Error::<Test>::RetentionPeriodNotOver
+        );
+
+        let retention: u64 = <Test as crate::Config>::DisputeRetentionPeriod::get();
+        System::set_block_number(finalized_at + retention);
+        assert_ok!(Arbitration::archive_dispute(RuntimeOrigin::signed(account("any")), project_id));
+
+        assert!(Arbitration::disputes(project_id).is_none());
+        assert!(Arbitration::dispute_rounds((project_id, 1)).is_none());
+        let summary = Arbitration::dispute_summaries(project_id).unwrap();
+        assert_eq!(summary.final_ruling, Ruling::ClientWins);
+        assert_eq!(summary.rounds, 1);
+        assert_eq!(summary.total_cost, 2000);
+        assert_eq!(summary.created_at, 1);
+        assert_eq!(summary.finalized_at, finalized_at);
+        System::assert_last_event(RuntimeEvent::Arbitration(Event::DisputeArchived {
+            project_id,
+            final_ruling: Ruling::ClientWins,
+        }));
+    });
+}
+
+#[test]
+fn on_idle_enforces_unappealed_ai_ruling() {
+    new_test_ext().execute_with(|| {
+        let (project_id, client, _freelancer) = create_project_and_dispute_to_round2();
+        // AI ruled at block 2, so the appeal window closes at block 102.
+        assert_eq!(Arbitration::dispute_deadlines(102u64).to_vec(), vec![project_id]);
+
+        // Nothing happens before the deadline.
+        System::set_block_number(101);
+        Arbitration::on_idle(101, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Appealable);
+
+        System::set_block_number(102);
+        Arbitration::on_idle(102, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Finalized);
+        assert!(Arbitration::dispute_deadlines(102u64).is_empty());
+        assert_eq!(Arbitration::deadline_cursor(), 103);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::DisputeResolved { project_id, winner: client }));
+
+        // The manual call is now a no-op fallback.
+        assert_noop!(
+            Arbitration::enforce_final_ruling(RuntimeOrigin::signed(account("any")), project_id),
+            Error::<Test>::InvalidStatus
+        );
+    });
+}
+
+#[test]
+fn on_idle_finalizes_voting_round_then_enforces() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, freelancer) = create_project_and_dispute_to_round2();
+        let juror1 = account("juror1");
+        let juror2 = account("juror2");
+        let juror3 = account("juror3");
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone()]);
+
+        System::set_block_number(10);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer.clone()), project_id));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror1), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2), project_id, Vote::ForFreelancer, None));
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror3), project_id, Vote::ForClient, None));
+
+        // The stale AI deadline at block 102 is skipped because the dispute was appealed.
+        System::set_block_number(102);
+        Arbitration::on_idle(102, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Voting);
+
+        // Voting closes at block 210.
+        System::set_block_number(210);
+        Arbitration::on_idle(210, Weight::MAX);
+        let dispute = Arbitration::disputes(project_id).unwrap();
+        assert_eq!(dispute.status, DisputeStatus::Appealable);
+        assert_eq!(dispute.ruling, Some(Ruling::FreelancerWins));
+
+        // Appeal window of the jury ruling closes at block 310.
+        System::set_block_number(310);
+        Arbitration::on_idle(310, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Finalized);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::DisputeResolved { project_id, winner: freelancer }));
+    });
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, _freelancer) = create_project_and_dispute_to_round2();
+
+        System::set_block_number(102);
+        Arbitration::on_idle(102, Weight::zero());
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Appealable);
+        assert_eq!(Arbitration::dispute_deadlines(102u64).to_vec(), vec![project_id]);
+
+        // The fallback extrinsic still works while the queue is starved.
+        assert_ok!(Arbitration::enforce_final_ruling(RuntimeOrigin::signed(account("any")), project_id));
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Finalized);
+    });
+}
+
+#[test]
+fn manual_enforcement_pays_bounty() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, _freelancer) = create_project_and_dispute_to_round2();
+        EnforcementBounty::set(100);
+        let _ = Balances::deposit_creating(&Arbitration::bounty_account(), 1_000);
+        let caller = account("keeper");
+
+        System::set_block_number(102);
+        assert_ok!(Arbitration::enforce_final_ruling(RuntimeOrigin::signed(caller.clone()), project_id));
+
+        assert_eq!(Balances::free_balance(&caller), 100);
+        // Paid from its own account, not from the fees held for jurors
+        assert_eq!(Balances::free_balance(&Arbitration::bounty_account()), 900);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::EnforcementBountyPaid {
+            project_id,
+            caller,
+            amount: 100,
+        }));
+    });
+}
+
+#[test]
+fn recused_juror_is_replaced_from_the_same_pool() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, freelancer) = create_project_and_dispute_to_round2();
+        let (juror1, juror2, juror3, juror4) = (account("juror1"), account("juror2"), account("juror3"), account("juror4"));
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone(), juror4.clone()]);
+        System::set_block_number(10);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer.clone()), project_id));
+        let fees = Arbitration::jury_fees_owed((project_id, 2), &juror1);
+
+        assert_ok!(Arbitration::recuse(RuntimeOrigin::signed(juror1.clone()), project_id));
+
+        let dispute = Arbitration::disputes(project_id).unwrap();
+        let jurors: Vec<AccountId32> = dispute.jurors.iter().map(|(j, _)| j.clone()).collect();
+        assert_eq!(jurors, vec![juror2.clone(), juror3.clone(), juror4.clone()]);
+        assert_eq!(Arbitration::jury_fees_owed((project_id, 2), &juror4), fees);
+        assert_eq!(Arbitration::struck_jurors((project_id, 2)).to_vec(), vec![juror1.clone()]);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::JurorRecused {
+            project_id,
+            juror: juror1.clone(),
+            replacement: juror4,
+        }));
+
+        // A juror who already voted cannot step down, and the pool is now exhausted.
+        assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror2.clone()), project_id, Vote::ForClient, None));
+        assert_noop!(Arbitration::recuse(RuntimeOrigin::signed(juror2), project_id), Error::<Test>::AlreadyVoted);
+        assert_noop!(Arbitration::recuse(RuntimeOrigin::signed(juror3), project_id), Error::<Test>::NotEnoughJurors);
+        assert_noop!(Arbitration::recuse(RuntimeOrigin::signed(juror1), project_id), Error::<Test>::NotJuror);
+    });
+}
+
+#[test]
+fn challenge_extends_voting_and_settles_bond_with_the_round() {
+    new_test_ext().execute_with(|| {
+        let (project_id, client, freelancer) = create_project_and_dispute_to_round2();
+        let (juror1, juror2, juror3, juror4, juror5) =
+            (account("juror1"), account("juror2"), account("juror3"), account("juror4"), account("juror5"));
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone(), juror4.clone(), juror5.clone()]);
+        System::set_block_number(10);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer.clone()), project_id));
+
+        // Voting would close at 210; a challenge at 190 leaves the replacement 50 blocks.
+        System::set_block_number(190);
+        assert_noop!(
+            Arbitration::challenge_juror(RuntimeOrigin::signed(account("outsider")), project_id, juror1.clone()),
+            Error::<Test>::NotAuthorized
+        );
+        let reserved_before = Balances::reserved_balance(&client);
+        assert_ok!(Arbitration::challenge_juror(RuntimeOrigin::signed(client.clone()), project_id, juror1.clone()));
+        assert_eq!(Balances::reserved_balance(&client) - reserved_before, UNIT);
+        assert_eq!(Arbitration::juror_challenges((project_id, 2), &client), (1, UNIT));
+        let dispute = Arbitration::disputes(project_id).unwrap();
+        assert_eq!(Arbitration::voting_deadline(project_id, &dispute), 240);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::VotingPeriodExtended { project_id, round: 2, deadline: 240 }));
+
+        // One challenge per round in the mock.
+        assert_noop!(
+            Arbitration::challenge_juror(RuntimeOrigin::signed(client.clone()), project_id, juror2.clone()),
+            Error::<Test>::ChallengeLimitReached
+        );
+        assert_ok!(Arbitration::challenge_juror(RuntimeOrigin::signed(freelancer.clone()), project_id, juror2.clone()));
+
+        for juror in [juror3, juror4, juror5] {
+            assert_ok!(Arbitration::cast_vote(RuntimeOrigin::signed(juror), project_id, Vote::ForFreelancer, None));
+        }
+        System::set_block_number(220);
+        assert_noop!(
+            Arbitration::finalize_round(RuntimeOrigin::signed(account("any")), project_id),
+            Error::<Test>::VotingPeriodNotOver
+        );
+
+        // The freelancer wins the round: their bond comes back, the client's is forfeited.
+        System::set_block_number(240);
+        let client_reserved = Balances::reserved_balance(&client);
+        let freelancer_reserved = Balances::reserved_balance(&freelancer);
+        let pallet_balance = Balances::free_balance(Arbitration::account_id());
+        Arbitration::on_idle(240, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Appealable);
+        assert_eq!(client_reserved - Balances::reserved_balance(&client), UNIT);
+        assert_eq!(freelancer_reserved - Balances::reserved_balance(&freelancer), UNIT);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::ChallengeBondForfeited {
+            project_id,
+            challenger: client.clone(),
+            amount: UNIT,
+        }));
+        assert_eq!(Arbitration::juror_challenges((project_id, 2), &client), (1, 0));
+        assert!(Balances::free_balance(Arbitration::account_id()) >= pallet_balance + UNIT);
+    });
+}
+
+#[test]
+fn fee_schedule_is_set_by_governance() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, _freelancer) = create_project_and_dispute_to_round2();
+        assert_eq!(Arbitration::calculate_arbitration_cost(&project_id, 2), Ok(5_000));
+
+        let schedule = FeeSchedule {
+            arbitration_cost: [Percent::from_percent(1), Percent::from_percent(3), Percent::from_percent(6)],
+            performance_bonus: Percent::from_percent(50),
+            ..FeeSchedule::default()
+        };
+        assert_noop!(
+            Arbitration::set_fee_schedule(RuntimeOrigin::signed(account("alice")), schedule.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Arbitration::set_fee_schedule(RuntimeOrigin::root(), schedule.clone()));
+        System::assert_last_event(Event::FeeScheduleUpdated { schedule }.into());
+
+        assert_eq!(Arbitration::calculate_arbitration_cost(&project_id, 2), Ok(3_000));
+        assert_eq!(Arbitration::calculate_appeal_bond(&project_id, 2), Ok(20_000));
+        assert_eq!(Arbitration::calculate_jury_fees(&project_id, 3), Ok((2_000, 1_000)));
+    });
+}
+
+#[test]
+fn oracles_and_jurors_are_given_the_evidence() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, freelancer) = create_project_and_dispute_to_round2();
+        assert_eq!(MockArbitrable::evidence_readers(), vec![OracleAccount::get()]);
+
+        let (juror1, juror2, juror3, juror4) = (account("juror1"), account("juror2"), account("juror3"), account("juror4"));
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone(), juror4.clone()]);
+        System::set_block_number(10);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer), project_id));
+        assert_eq!(
+            MockArbitrable::evidence_readers(),
+            vec![OracleAccount::get(), juror1.clone(), juror2.clone(), juror3.clone()]
+        );
+
+        // A replacement juror gets the evidence too
+        assert_ok!(Arbitration::recuse(RuntimeOrigin::signed(juror1), project_id));
+        assert_eq!(MockArbitrable::evidence_readers().last(), Some(&juror4));
+    });
+}
+
+#[test]
+fn work_failing_its_content_check_cannot_be_disputed() {
+    new_test_ext().execute_with(|| {
+        let project_id = 0u32;
+        let (client, freelancer) = (account("alice"), account("bob"));
+        MockArbitrable::set_project_parties(project_id, client, freelancer.clone());
+        MockArbitrable::set_project_budget(project_id, 100_000);
+        let _ = Balances::deposit_creating(&freelancer, 10 * UNIT);
+
+        MockArbitrable::set_content_check(project_id, ContentCheck::Mismatch);
+        assert_noop!(
+            Arbitration::create_dispute(RuntimeOrigin::signed(freelancer.clone()), project_id),
+            Error::<Test>::SubmissionHashMismatch
+        );
+
+        MockArbitrable::set_content_check(project_id, ContentCheck::Verified);
+        assert_ok!(Arbitration::create_dispute(RuntimeOrigin::signed(freelancer), project_id));
+    });
+}
+
+#[test]
+fn lazy_migration_to_v1_queues_open_disputes() {
+    use crate::migrations::v1::LazyMigrationV1;
+    use frame_support::{
+        migrations::SteppedMigration,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, _freelancer) = create_project_and_dispute_to_round2();
+        // Disputes opened before version 1 were never queued
+        crate::DisputeDeadlines::<Test>::remove(102u64);
+        crate::DeadlineCursor::<Test>::kill();
+        StorageVersion::new(0).put::<Arbitration>();
+
+        let mut cursor = None;
+        loop {
+            cursor = LazyMigrationV1::<Test>::step(cursor, &mut WeightMeter::new()).unwrap();
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(Arbitration::on_chain_storage_version(), 1);
+        assert_eq!(Arbitration::dispute_deadlines(102u64).to_vec(), vec![project_id]);
+        assert_eq!(Arbitration::deadline_cursor(), 102);
+
+        // The unappealed ruling is now enforced on time
+        System::set_block_number(102);
+        Arbitration::on_idle(102, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Finalized);
+
+        // Once at version 1 there is nothing left to do
+        assert!(matches!(LazyMigrationV1::<Test>::step(None, &mut WeightMeter::new()), Ok(None)));
+    });
+}
+
 // ---------- helper ----------
 fn create_project_and_dispute_to_round2() -> (u32, AccountId32, AccountId32) {
     let project_id = 0u32;
@@ -783,16 +1237,88 @@ fn create_project_and_dispute_to_round2() -> (u32, AccountId32, AccountId32) {
         RuntimeOrigin::signed(client.clone()),
         budget,
         str_to_bounded("Test Project"),
-        1000
+        1000,
+        Default::default(),
+        HiringMode::Open
     ));
 
-    assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id));
-    assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone()));
+    assert_ok!(Projects::apply_for_project(RuntimeOrigin::signed(freelancer.clone()), project_id, budget, 1000, str_to_bounded("ipfs://proposal")));
+    assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), project_id, freelancer.clone(), None));
 
     System::set_block_number(1);
     assert_ok!(Arbitration::create_dispute(RuntimeOrigin::signed(freelancer.clone()), project_id));
     System::set_block_number(2);
-    assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::ClientWins));
+    assert_ok!(Arbitration::submit_ruling(RuntimeOrigin::root(), project_id, Ruling::ClientWins, None));
 
     (project_id, client, freelancer)
-}
\ No newline at end of file
+}
+#[test]
+fn full_deadline_blocks_defer_to_the_next_block_with_room() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let capacity: u32 = <Test as crate::Config>::MaxDeadlinesPerBlock::get();
+        // Fill more blocks than the queue used to search
+        for block in 100..112u64 {
+            for project_id in 0..capacity {
+                Arbitration::schedule_deadline(1_000 + project_id, block);
+            }
+        }
+        System::reset_events();
+
+        Arbitration::schedule_deadline(7, 100);
+        assert_eq!(Arbitration::dispute_deadlines(112).to_vec(), vec![7]);
+        System::assert_last_event(RuntimeEvent::Arbitration(Event::DeadlineDeferred {
+            project_id: 7,
+            requested: 100,
+            scheduled: 112,
+        }));
+    });
+}
+
+#[test]
+fn jurors_report_a_withheld_brief_for_the_rest_of_the_jury() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _client, freelancer) = create_project_and_dispute_to_round2();
+        let (juror1, juror2, juror3) = (account("juror1"), account("juror2"), account("juror3"));
+        MockReputation::set_jurors(vec![juror1.clone(), juror2.clone(), juror3.clone()]);
+        System::set_block_number(10);
+        assert_ok!(Arbitration::appeal_ruling(RuntimeOrigin::signed(freelancer.clone()), project_id));
+        MockArbitrable::withhold_brief(juror1.clone());
+
+        assert_noop!(
+            Arbitration::report_withheld_brief(RuntimeOrigin::signed(freelancer), project_id),
+            Error::<Test>::NotJuror
+        );
+        assert_noop!(
+            Arbitration::report_withheld_brief(RuntimeOrigin::signed(juror2), project_id),
+            Error::<Test>::BriefNotWithheld
+        );
+        assert_ok!(Arbitration::report_withheld_brief(RuntimeOrigin::signed(juror1.clone()), project_id));
+        assert!(Arbitration::withheld_brief((project_id, 2), &juror1).is_some());
+        System::assert_last_event(RuntimeEvent::Arbitration(Event::BriefWithheld { project_id, round: 2, juror: juror1 }));
+    });
+}
+
+#[test]
+fn halted_deadline_processing_leaves_due_disputes_queued() {
+    new_test_ext().execute_with(|| {
+        let (project_id, client, _freelancer) = create_project_and_dispute_to_round2();
+        assert_noop!(
+            Arbitration::set_deadlines_halted(RuntimeOrigin::signed(client.clone()), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Arbitration::set_deadlines_halted(RuntimeOrigin::root(), true));
+        System::assert_last_event(RuntimeEvent::Arbitration(Event::DeadlineProcessingSet { halted: true }));
+
+        System::set_block_number(102);
+        Arbitration::on_idle(102, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Appealable);
+        assert_eq!(Arbitration::dispute_deadlines(102u64).to_vec(), vec![project_id]);
+
+        assert_ok!(Arbitration::set_deadlines_halted(RuntimeOrigin::root(), false));
+        System::set_block_number(103);
+        Arbitration::on_idle(103, Weight::MAX);
+        assert_eq!(Arbitration::disputes(project_id).unwrap().status, DisputeStatus::Finalized);
+        System::assert_has_event(RuntimeEvent::Arbitration(Event::DisputeResolved { project_id, winner: client }));
+    });
+}
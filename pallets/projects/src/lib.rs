@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -8,15 +10,21 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
 use frame_support::{ BoundedVec,
     dispatch::DispatchResult,
     traits::{Get},
 };
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, KeyTypeId};
 use sp_runtime::traits::ConstU32;
+use scale_info::prelude::vec::Vec;
+use pallet_reputation::Skill;
 
 // Define the Arbitrable trait that the arbitration pallet will use to interact with projects
 pub type EvidenceUri = BoundedVec<u8, ConstU32<256>>;
+/// An x25519 public key that private briefs are encrypted to.
+pub type EncryptionKey = [u8; 32];
 pub trait Arbitrable<ProjectId, Balance, AccountId, BlockNumber> {
     fn on_ruling(project_id: ProjectId, ruling: Ruling) -> DispatchResult; // Using local Ruling enum
     fn get_project_budget(project_id: ProjectId) -> Result<Balance, DispatchError>;
@@ -26,6 +34,42 @@ pub trait Arbitrable<ProjectId, Balance, AccountId, BlockNumber> {
 	/// Fetches the core evidence for a dispute: the client's requirements URI
     /// and the freelancer's submission URI.
     fn get_evidence_uris(project_id: ProjectId) -> Result<(EvidenceUri, EvidenceUri), DispatchError>;
+    /// If the project has a private brief, grant access to it to every reader with a
+    /// registered encryption key, so the parties can share a copy with them.
+    fn grant_evidence_access(project_id: ProjectId, readers: &[AccountId]) -> DispatchResult;
+    /// Whether `reader` was granted access to the private brief but no copy was shared yet.
+    fn brief_withheld(project_id: ProjectId, reader: &AccountId) -> bool;
+    /// The skill tags the client attached to the project, used to draw jurors who know the domain.
+    fn get_project_skills(project_id: ProjectId) -> Result<Vec<Skill>, DispatchError>;
+    /// Whether the current submission matched its content hash, once it has been checked.
+    fn get_content_check(project_id: ProjectId) -> Option<ContentCheck>;
+}
+
+/// Key type of the accounts the offchain worker signs content checks with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"pcnt");
+
+/// Offchain local storage key under which a node can set its own IPFS gateway URL.
+pub const GATEWAY_KEY: &[u8] = b"projects::ipfs-gateway";
+
+/// Keys for signing content checks. A verifier inserts one into its node's keystore under
+/// `KEY_TYPE`, for an account that passes `VerifierOrigin`.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct VerifierAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for VerifierAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = Sr25519Signature;
+        type GenericPublic = Sr25519Public;
+    }
 }
 
 #[frame_support::pallet]
@@ -34,19 +78,29 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
     use frame_support::{BoundedVec,PalletId, 
-        traits::{Currency, LockableCurrency, ExistenceRequirement, WithdrawReasons}
+        traits::{Currency, LockableCurrency, ReservableCurrency, ExistenceRequirement, WithdrawReasons}
+    };
+    use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+    use alloc::{format, string::String};
+    use sp_runtime::offchain::{
+        http,
+        storage::{StorageRetrievalError, StorageValueRef},
+        Duration, StorageKind,
     };
     use pallet_reputation::ReputationInterface;
     use scale_info::TypeInfo;
     use codec::{MaxEncodedLen};
     use scale_info::prelude::ops::Add;
     use sp_runtime::{
-		traits::{ One, AccountIdConversion, Saturating}
+		traits::{ One, AccountIdConversion, Saturating, Zero}
 };
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
 
@@ -84,13 +138,59 @@ pub mod pallet {
     pub struct Project<T: Config> {
         pub client: T::AccountId,
         pub freelancer: Option<T::AccountId>,
-        pub uri: BoundedVec<u8, ConstU32<256>>,  // URI describing the project details
+        pub uri: BoundedVec<u8, ConstU32<256>>,  // Public URI describing the project, a teaser if the brief is private
         pub budget: BalanceOf<T>,
         pub status: ProjectStatus,
         pub duration: BlockNumberFor<T>,
         pub submission_block: Option<BlockNumberFor<T>>, // Deadline for submission
         pub work_submission: Option<WorkSubmission<T>>,     // The actual work submission
         pub dispute_info: Option<DisputeInfo<T>>,          // Information about rejections and disputes
+        pub skills: BoundedVec<Skill, T::MaxSkills>,       // Skill tags chosen by the client
+    }
+
+    /// A freelancer's offer to do a project.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Proposal<T: Config> {
+        /// Price asked for the work, at most the project budget.
+        pub bid: BalanceOf<T>,
+        /// Blocks the freelancer needs to deliver.
+        pub duration: BlockNumberFor<T>,
+        pub cover_letter_uri: EvidenceUri,
+        /// Reserved from the freelancer until the client hires someone or the proposal is withdrawn.
+        pub deposit: BalanceOf<T>,
+    }
+
+    /// Who can take on a new project.
+    #[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, EqNoBound, DebugNoBound, TypeInfo, DecodeWithMemTracking)]
+    #[scale_info(skip_type_params(T))]
+    pub enum HiringMode<T: Config> {
+        /// Any freelancer can submit a proposal.
+        Open,
+        /// Only the listed freelancers can submit proposals.
+        InviteOnly(BoundedVec<T::AccountId, T::MaxApplicants>),
+        /// The named freelancer is offered the project and starts work by accepting it.
+        DirectHire(T::AccountId),
+    }
+
+    /// Outcome of checking a submission's content against its `content_hash`.
+    #[derive(Clone, Encode, Decode, PartialEq, Debug, MaxEncodedLen, TypeInfo, Eq, Copy, DecodeWithMemTracking)]
+    pub enum ContentCheck {
+        /// The SHA-256 hash of the content at `uri` equals `content_hash`.
+        Verified,
+        /// The content at `uri` has a different hash.
+        Mismatch,
+        /// The content at `uri` is larger than `MaxContentSize`, so it was never hashed.
+        Unverifiable,
+    }
+
+    /// A copy of a private project brief, encrypted off-chain to one reader's key.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, Debug)]
+    pub struct SealedBrief {
+        /// The reader's key when access was granted; the copy must be encrypted to it.
+        pub key: EncryptionKey,
+        /// Where the encrypted copy is stored, once a party has shared it.
+        pub uri: Option<EvidenceUri>,
     }
 
     #[derive(Clone, Encode, Decode, PartialEq, Debug,MaxEncodedLen, TypeInfo, Eq, Copy)]
@@ -101,14 +201,27 @@ pub mod pallet {
     }
 
 
+    /// Blocks before the offchain worker retries a submission it could not fetch.
+    const CHECK_RETRY_BLOCKS: u32 = 10;
+    /// How long the offchain worker waits for a gateway to return a submission.
+    const FETCH_TIMEOUT_MS: u64 = 10_000;
+
+    /// Why the offchain worker could not hash a submission.
+    enum FetchError {
+        /// The gateway failed or timed out; the check is retried.
+        Unavailable,
+        /// The submission is larger than `MaxContentSize`.
+        TooLarge,
+    }
+
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
         /// The overarching runtime event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo;
         /// The currency type that will be used to place deposits and pay freelancers
-        type Currency: Currency<Self::AccountId> + LockableCurrency<Self::AccountId>;
+        type Currency: Currency<Self::AccountId> + LockableCurrency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
         /// The pallet id, used for deriving its sovereign account ID.
         type PalletId: Get<PalletId>;
         /// The type used to identify projects
@@ -126,6 +239,40 @@ pub mod pallet {
         #[pallet::constant]
         /// The maximum number of applicants that can apply for a project
         type MaxApplicants: Get<u32>;
+
+        #[pallet::constant]
+        /// How long after completion the parties can rate and review each other
+        type RatingPeriod: Get<BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// The maximum number of skill tags on a project
+        type MaxSkills: Get<u32>;
+
+        #[pallet::constant]
+        /// How long a freelancer has to accept a direct-hire offer
+        type OfferPeriod: Get<BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// Refundable deposit reserved with every proposal; zero for none
+        type ApplicationDeposit: Get<BalanceOf<Self>>;
+
+        /// Keys the offchain worker signs content checks with
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Origin allowed to record content checks
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// IPFS gateway the offchain worker fetches `ipfs://` submissions from, unless the node
+        /// sets its own under `GATEWAY_KEY`
+        type IpfsGateway: Get<&'static str>;
+
+        #[pallet::constant]
+        /// The maximum number of submissions the offchain worker checks per block
+        type MaxContentChecksPerBlock: Get<u32>;
+
+        #[pallet::constant]
+        /// The largest submission, in bytes, the offchain worker downloads to check
+        type MaxContentSize: Get<u32>;
     }
 
     #[pallet::storage]
@@ -146,6 +293,72 @@ pub mod pallet {
         ValueQuery
     >;
 
+    /// Proposals of the applicants of each open project.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal)]
+    pub type Proposals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::ProjectId,
+        Blake2_128Concat, T::AccountId,
+        Proposal<T>,
+    >;
+
+    /// Freelancers invited to an invite-only project; other accounts can't apply.
+    #[pallet::storage]
+    #[pallet::getter(fn invitations)]
+    pub type Invitations<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, BoundedVec<T::AccountId, T::MaxApplicants>>;
+
+    /// Last block at which the freelancer of a direct-hire project can accept the offer. The
+    /// freelancer is the project's only entry in `ProjectApplicants`.
+    #[pallet::storage]
+    #[pallet::getter(fn offer_deadline)]
+    pub type OfferDeadlines<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, BlockNumberFor<T>>;
+
+    /// Last block at which the parties of a completed project can rate and review each other.
+    #[pallet::storage]
+    #[pallet::getter(fn rating_deadline)]
+    pub type RatingDeadlines<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, BlockNumberFor<T>>;
+
+    /// The freelancer's rating of the client (1-5), once given.
+    #[pallet::storage]
+    #[pallet::getter(fn client_rating)]
+    pub type ClientRatings<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, u32>;
+
+    /// Written reviews left by either party after completion.
+    #[pallet::storage]
+    #[pallet::getter(fn reviews)]
+    pub type Reviews<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::ProjectId,
+        Blake2_128Concat, T::AccountId,
+        EvidenceUri,
+    >;
+
+    /// Encryption key each account receives private briefs under.
+    #[pallet::storage]
+    #[pallet::getter(fn encryption_key)]
+    pub type EncryptionKeys<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, EncryptionKey>;
+
+    /// Readers of a private brief: the hired freelancer, and the oracles and jurors of a dispute.
+    #[pallet::storage]
+    #[pallet::getter(fn brief)]
+    pub type Briefs<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::ProjectId,
+        Blake2_128Concat, T::AccountId,
+        SealedBrief,
+    >;
+
+    /// Result of checking each project's current submission against its content hash.
+    #[pallet::storage]
+    #[pallet::getter(fn content_check)]
+    pub type ContentChecks<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, ContentCheck>;
+
+    /// Submissions waiting for the offchain worker to check them.
+    #[pallet::storage]
+    #[pallet::getter(fn content_check_pending)]
+    pub type PendingContentChecks<T: Config> = StorageMap<_, Blake2_128Concat, T::ProjectId, ()>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -153,9 +366,26 @@ pub mod pallet {
         WorkSubmitted { project_id: T::ProjectId, freelancer: T::AccountId },
         WorkAccepted { project_id: T::ProjectId, freelancer: T::AccountId, payment: BalanceOf<T> },
         WorkRejected { project_id: T::ProjectId, freelancer: T::AccountId, reason_uri: BoundedVec<u8, ConstU32<256>> },
-        ApplicationSubmitted { project_id: T::ProjectId, applicant: T::AccountId },
+        ApplicationSubmitted { project_id: T::ProjectId, applicant: T::AccountId, bid: BalanceOf<T> },
+        ApplicationWithdrawn { project_id: T::ProjectId, applicant: T::AccountId },
+        /// The client hired a freelancer at their bid; the rest of the budget is no longer in escrow.
+        ProposalAccepted { project_id: T::ProjectId, freelancer: T::AccountId, bid: BalanceOf<T>, released: BalanceOf<T> },
         WorkStarted { project_id: T::ProjectId, freelancer: T::AccountId },
         ProjectCancelled { project_id: T::ProjectId, client: T::AccountId },
+        ClientRated { project_id: T::ProjectId, client: T::AccountId, rating: u32 },
+        ReviewSubmitted { project_id: T::ProjectId, author: T::AccountId, review_uri: EvidenceUri },
+        EncryptionKeyRegistered { who: T::AccountId, key: EncryptionKey },
+        /// A reader may see the private brief once a party shares a copy encrypted to `key`.
+        BriefAccessGranted { project_id: T::ProjectId, reader: T::AccountId, key: EncryptionKey },
+        BriefShared { project_id: T::ProjectId, reader: T::AccountId, uri: EvidenceUri },
+        ContentChecked { project_id: T::ProjectId, check: ContentCheck },
+        FreelancerInvited { project_id: T::ProjectId, freelancer: T::AccountId },
+        OfferMade { project_id: T::ProjectId, freelancer: T::AccountId, expires_at: BlockNumberFor<T> },
+        OfferAccepted { project_id: T::ProjectId, freelancer: T::AccountId },
+        /// The freelancer turned the offer down; the project is cancelled and the escrow released.
+        OfferDeclined { project_id: T::ProjectId, freelancer: T::AccountId },
+        /// Nobody accepted the offer in time; the project is cancelled and the escrow released.
+        OfferExpired { project_id: T::ProjectId, freelancer: T::AccountId },
     }
 
     // --- Errors ---
@@ -177,15 +407,60 @@ pub mod pallet {
         NoWorkSubmitted,
         PaymentFailed,
         UnlockFailed,
+        /// Ratings are 1 to 5 stars.
+        InvalidRating,
+        /// The project is not open for ratings or the rating period is over.
+        RatingPeriodClosed,
+        AlreadyRated,
+        AlreadyReviewed,
+        /// Skill tags can't be empty.
+        InvalidSkill,
+        /// The account has not registered an encryption key.
+        NoEncryptionKey,
+        /// Only the client and the hired freelancer can share the brief.
+        NotProjectParty,
+        /// The account was never granted access to the project's brief.
+        BriefAccessNotGranted,
+        /// The submission is not waiting for a content check.
+        ContentAlreadyChecked,
+        /// Bids must be above zero and within the project budget.
+        InvalidBid,
+        /// Proposals must give a duration above zero.
+        InvalidDuration,
+        /// Invite lists can't be empty, and clients can't invite or hire themselves.
+        InvalidInvitation,
+        /// The project only takes proposals from invited freelancers.
+        NotInvited,
+        /// The project has no offer waiting for this account.
+        NoOffer,
+        /// The offer period is over.
+        OfferExpired,
+        /// The offer can still be accepted.
+        OfferNotExpired,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            Self::check_pending_content(now);
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
         #[pallet::weight(Weight::default())]
-        pub fn create_project(origin: OriginFor<T>, budget: BalanceOf<T>, uri: BoundedVec<u8, ConstU32<256>>, duration: BlockNumberFor<T>) -> DispatchResult {
+        pub fn create_project(
+            origin: OriginFor<T>,
+            budget: BalanceOf<T>,
+            uri: BoundedVec<u8, ConstU32<256>>,
+            duration: BlockNumberFor<T>,
+            skills: BoundedVec<Skill, T::MaxSkills>,
+            hiring: HiringMode<T>,
+        ) -> DispatchResult {
             let client = ensure_signed(origin)?;
             let project_id = Self::next_project_id();
+            ensure!(skills.iter().all(|skill| !skill.is_empty()), Error::<T>::InvalidSkill);
 
             // Create a unique lock ID for this project
             let lock_id = Self::generate_lock_id(project_id);
@@ -208,6 +483,7 @@ pub mod pallet {
                 submission_block: None,
                 work_submission: None,
                 dispute_info: None,
+                skills,
             };
 
             Projects::<T>::insert(project_id, new_project);
@@ -215,18 +491,32 @@ pub mod pallet {
 
             T::Reputation::on_project_created(&client, budget)?;
 
-            Self::deposit_event(Event::ProjectCreated { project_id, client, budget });
-            Ok(())
+            Self::deposit_event(Event::ProjectCreated { project_id, client: client.clone(), budget });
+            Self::set_hiring_mode(project_id, &client, hiring)
         }
 
         #[pallet::call_index(1)]
         #[pallet::weight(Weight::default())]
-        pub fn apply_for_project(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+        pub fn apply_for_project(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            bid: BalanceOf<T>,
+            duration: BlockNumberFor<T>,
+            cover_letter_uri: EvidenceUri,
+        ) -> DispatchResult {
             let applicant = ensure_signed(origin)?;
             
             // Ensure project exists and is in the right status
             let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
             ensure!(project.status == ProjectStatus::Created, Error::<T>::InvalidStatus);
+            // Direct-hire projects are only open to the freelancer they were offered to
+            ensure!(!OfferDeadlines::<T>::contains_key(project_id), Error::<T>::NotInvited);
+            if let Some(invited) = Self::invitations(project_id) {
+                ensure!(invited.contains(&applicant), Error::<T>::NotInvited);
+            }
+            ensure!(!bid.is_zero() && bid <= project.budget, Error::<T>::InvalidBid);
+            ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
+            ensure!(!cover_letter_uri.is_empty(), Error::<T>::InvalidUri);
             
             // Get current applicants and ensure the user hasn't already applied
             ProjectApplicants::<T>::try_mutate(project_id, |applicants| -> DispatchResult {
@@ -235,9 +525,14 @@ pub mod pallet {
                 Ok(())
             })?;
 
+            let deposit = T::ApplicationDeposit::get();
+            T::Currency::reserve(&applicant, deposit)?;
+            Proposals::<T>::insert(project_id, &applicant, Proposal { bid, duration, cover_letter_uri, deposit });
+
             Self::deposit_event(Event::ApplicationSubmitted { 
                 project_id,
-                applicant: applicant.clone()
+                applicant: applicant.clone(),
+                bid,
             });
             
             Ok(())
@@ -248,7 +543,8 @@ pub mod pallet {
         pub fn start_work(
             origin: OriginFor<T>, 
             project_id: T::ProjectId, 
-            selected_freelancer: T::AccountId
+            selected_freelancer: T::AccountId,
+            brief_uri: Option<EvidenceUri>,
         ) -> DispatchResult {
             let client = ensure_signed(origin)?;
             
@@ -258,8 +554,13 @@ pub mod pallet {
                 ensure!(project.status == ProjectStatus::Created, Error::<T>::InvalidStatus);
                 
                 // Ensure the selected freelancer is an applicant
-                let applicants = ProjectApplicants::<T>::get(project_id);
-                ensure!(applicants.contains(&selected_freelancer), Error::<T>::ApplicantNotFound);
+                let proposal = Self::proposal(project_id, &selected_freelancer).ok_or(Error::<T>::ApplicantNotFound)?;
+
+                // The accepted bid becomes the budget; the client gets the rest of the escrow back
+                let released = project.budget.saturating_sub(proposal.bid);
+                T::Currency::set_lock(Self::generate_lock_id(project_id), &client, proposal.bid, WithdrawReasons::all());
+                project.budget = proposal.bid;
+                project.duration = proposal.duration;
                 
                 project.freelancer = Some(selected_freelancer.clone());
                 project.status = ProjectStatus::InProgress;
@@ -268,8 +569,23 @@ pub mod pallet {
                 let current_block = <frame_system::Pallet<T>>::block_number();
                 project.submission_block = Some(current_block.saturating_add(project.duration));
                 
+                // A private brief is encrypted to the freelancer's key before it is posted
+                if let Some(uri) = brief_uri {
+                    let key = Self::encryption_key(&selected_freelancer).ok_or(Error::<T>::NoEncryptionKey)?;
+                    Self::store_brief(project_id, &selected_freelancer, key, uri)?;
+                }
+
                 // Clean up the applicants storage to save space
                 ProjectApplicants::<T>::remove(project_id);                
+                Invitations::<T>::remove(project_id);
+                Self::release_proposals(project_id);
+                T::Reputation::on_work_started(&client, &selected_freelancer, project_id)?;
+                Self::deposit_event(Event::ProposalAccepted {
+                    project_id,
+                    freelancer: selected_freelancer.clone(),
+                    bid: proposal.bid,
+                    released,
+                });
                 Self::deposit_event(Event::WorkStarted { 
                     project_id,
                     freelancer: selected_freelancer
@@ -308,6 +624,16 @@ pub mod pallet {
 
                 // Validate submission data
                 ensure!(!uri.is_empty(), Error::<T>::InvalidUri);
+                // A raw CID names the hash of the content itself, so it has to match
+                if let Some(digest) = Self::cid_digest(&uri) {
+                    ensure!(digest == content_hash, Error::<T>::InvalidHash);
+                }
+
+                // Queue the new submission for the offchain worker
+                ContentChecks::<T>::remove(project_id);
+                if Self::is_fetchable(&uri) {
+                    PendingContentChecks::<T>::insert(project_id, ());
+                }
                 
                 // Create and store the work submission
                 let submission = WorkSubmission {
@@ -369,10 +695,12 @@ pub mod pallet {
                 ).map_err(|_| Error::<T>::PaymentFailed)?;
 
                 // Update freelancer and client reputation
-                T::Reputation::on_project_completed(freelancer, payment, scaled_rating, project_id)?;
+                T::Reputation::on_project_completed(freelancer, payment, scaled_rating, project_id, &project.skills)?;
                 T::Reputation::on_work_accepted(&client, project_id)?;
                 
                 project.status = ProjectStatus::Completed;
+                PendingContentChecks::<T>::remove(project_id);
+                Self::open_rating_window(project_id);
                 
                 Self::deposit_event(Event::WorkAccepted {
                     project_id,
@@ -454,6 +782,11 @@ pub mod pallet {
                 );
 
                 project.status = ProjectStatus::Cancelled;
+                PendingContentChecks::<T>::remove(project_id);
+                ProjectApplicants::<T>::remove(project_id);
+                Invitations::<T>::remove(project_id);
+                OfferDeadlines::<T>::remove(project_id);
+                Self::release_proposals(project_id);
 
                 T::Reputation::on_project_cancelled(&client)?;
 
@@ -462,13 +795,430 @@ pub mod pallet {
             })?;
 
             Ok(())
-        }    }
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(Weight::default())]
+        pub fn rate_client(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            rating: u32,
+            review_uri: Option<EvidenceUri>,
+        ) -> DispatchResult {
+            let freelancer = ensure_signed(origin)?;
+            ensure!(rating > 0 && rating <= 5, Error::<T>::InvalidRating);
+
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            ensure!(project.freelancer.as_ref() == Some(&freelancer), Error::<T>::NotFreelancer);
+            Self::ensure_rating_window_open(project_id)?;
+            ensure!(!ClientRatings::<T>::contains_key(project_id), Error::<T>::AlreadyRated);
+
+            if let Some(review_uri) = review_uri {
+                Self::store_review(project_id, &freelancer, review_uri)?;
+            }
+
+            ClientRatings::<T>::insert(project_id, rating);
+            T::Reputation::on_client_rated(&project.client, project.budget, rating.saturating_mul(1000), project_id)?;
+
+            Self::deposit_event(Event::ClientRated { project_id, client: project.client, rating });
+            Ok(())
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(Weight::default())]
+        pub fn submit_review(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            review_uri: EvidenceUri,
+        ) -> DispatchResult {
+            let client = ensure_signed(origin)?;
+
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            ensure!(project.client == client, Error::<T>::NotProjectOwner);
+            Self::ensure_rating_window_open(project_id)?;
+
+            Self::store_review(project_id, &client, review_uri)
+        }
+
+        /// Register the x25519 public key that private briefs are encrypted to.
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::default())]
+        pub fn register_encryption_key(origin: OriginFor<T>, key: EncryptionKey) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            EncryptionKeys::<T>::insert(&who, key);
+            Self::deposit_event(Event::EncryptionKeyRegistered { who, key });
+            Ok(())
+        }
+
+        /// Post a copy of the private brief, encrypted to the key `reader` was granted access
+        /// under. Either party can share it, as both of them hold the plaintext.
+        #[pallet::call_index(10)]
+        #[pallet::weight(Weight::default())]
+        pub fn share_brief(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            reader: T::AccountId,
+            uri: EvidenceUri,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            ensure!(
+                project.client == who || project.freelancer.as_ref() == Some(&who),
+                Error::<T>::NotProjectParty
+            );
+            let granted = Self::brief(project_id, &reader).ok_or(Error::<T>::BriefAccessNotGranted)?;
+
+            Self::store_brief(project_id, &reader, granted.key, uri)
+        }
+
+        /// Withdraw a proposal from a project that hasn't started, returning its deposit.
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::default())]
+        pub fn withdraw_application(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+            let applicant = ensure_signed(origin)?;
+
+            let proposal = Proposals::<T>::take(project_id, &applicant).ok_or(Error::<T>::ApplicantNotFound)?;
+            ProjectApplicants::<T>::mutate(project_id, |applicants| applicants.retain(|who| who != &applicant));
+            T::Currency::unreserve(&applicant, proposal.deposit);
+
+            Self::deposit_event(Event::ApplicationWithdrawn { project_id, applicant });
+            Ok(())
+        }
+
+        /// Record whether the current submission matches its content hash. Submitted by the
+        /// offchain worker of a verifier; `content_hash` guards against results for an older
+        /// submission.
+        #[pallet::call_index(11)]
+        #[pallet::weight(Weight::default())]
+        pub fn record_content_check(
+            origin: OriginFor<T>,
+            project_id: T::ProjectId,
+            content_hash: [u8; 32],
+            check: ContentCheck,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            let submission = project.work_submission.ok_or(Error::<T>::NoWorkSubmitted)?;
+            ensure!(submission.content_hash == content_hash, Error::<T>::InvalidHash);
+            ensure!(PendingContentChecks::<T>::contains_key(project_id), Error::<T>::ContentAlreadyChecked);
+
+            PendingContentChecks::<T>::remove(project_id);
+            ContentChecks::<T>::insert(project_id, check);
+            Self::deposit_event(Event::ContentChecked { project_id, check });
+            Ok(())
+        }
+
+        /// Accept a direct-hire offer and start work on the project.
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::default())]
+        pub fn accept_offer(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+            let freelancer = ensure_signed(origin)?;
+
+            Projects::<T>::try_mutate(project_id, |maybe_project| -> DispatchResult {
+                let project = maybe_project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+                let deadline = Self::ensure_offered(project_id, project, &freelancer)?;
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                ensure!(current_block <= deadline, Error::<T>::OfferExpired);
+
+                project.freelancer = Some(freelancer.clone());
+                project.status = ProjectStatus::InProgress;
+                project.submission_block = Some(current_block.saturating_add(project.duration));
+
+                OfferDeadlines::<T>::remove(project_id);
+                ProjectApplicants::<T>::remove(project_id);
+                T::Reputation::on_work_started(&project.client, &freelancer, project_id)?;
+                Self::deposit_event(Event::OfferAccepted { project_id, freelancer: freelancer.clone() });
+                Self::deposit_event(Event::WorkStarted { project_id, freelancer });
+                Ok(())
+            })
+        }
+
+        /// Turn down a direct-hire offer. The project is cancelled and the client's escrow released.
+        #[pallet::call_index(14)]
+        #[pallet::weight(Weight::default())]
+        pub fn decline_offer(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+            let freelancer = ensure_signed(origin)?;
+
+            Projects::<T>::try_mutate(project_id, |maybe_project| -> DispatchResult {
+                let project = maybe_project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+                Self::ensure_offered(project_id, project, &freelancer)?;
+
+                Self::close_offer(project_id, project);
+                Self::deposit_event(Event::OfferDeclined { project_id, freelancer });
+                Ok(())
+            })
+        }
+
+        /// Cancel a direct-hire project whose offer was not accepted in time, releasing the
+        /// client's escrow. Anyone can call this once the offer period is over.
+        #[pallet::call_index(15)]
+        #[pallet::weight(Weight::default())]
+        pub fn expire_offer(origin: OriginFor<T>, project_id: T::ProjectId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Projects::<T>::try_mutate(project_id, |maybe_project| -> DispatchResult {
+                let project = maybe_project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+                ensure!(project.status == ProjectStatus::Created, Error::<T>::InvalidStatus);
+                let deadline = Self::offer_deadline(project_id).ok_or(Error::<T>::NoOffer)?;
+                ensure!(<frame_system::Pallet<T>>::block_number() > deadline, Error::<T>::OfferNotExpired);
+
+                let freelancer = Self::project_applicants(project_id).first().cloned().ok_or(Error::<T>::NoOffer)?;
+                Self::close_offer(project_id, project);
+                Self::deposit_event(Event::OfferExpired { project_id, freelancer });
+                Ok(())
+            })
+        }
+    }
 
     impl<T:Config> Pallet<T> {
         pub fn account_id() -> T::AccountId {
 			<T as pallet::Config>::PalletId::get().into_account_truncating()
 		}
 
+        /// Applicants of a project ordered by their reputation in the project's skills, best first.
+        pub fn ranked_applicants(project_id: T::ProjectId) -> Vec<(T::AccountId, u32)> {
+            let Some(project) = Self::projects(project_id) else { return Vec::new() };
+            let mut ranked: Vec<(T::AccountId, u32)> = Self::project_applicants(project_id)
+                .into_iter()
+                .map(|applicant| {
+                    let score = T::Reputation::skill_score(&applicant, &project.skills);
+                    (applicant, score)
+                })
+                .collect();
+            ranked.sort_by(|a, b| b.1.cmp(&a.1));
+            ranked
+        }
+
+        /// Let both parties rate and review each other for `RatingPeriod` blocks.
+        fn open_rating_window(project_id: T::ProjectId) {
+            let deadline = <frame_system::Pallet<T>>::block_number().saturating_add(T::RatingPeriod::get());
+            RatingDeadlines::<T>::insert(project_id, deadline);
+        }
+
+        fn ensure_rating_window_open(project_id: T::ProjectId) -> DispatchResult {
+            let deadline = Self::rating_deadline(project_id).ok_or(Error::<T>::RatingPeriodClosed)?;
+            ensure!(<frame_system::Pallet<T>>::block_number() <= deadline, Error::<T>::RatingPeriodClosed);
+            Ok(())
+        }
+
+        fn store_review(project_id: T::ProjectId, author: &T::AccountId, review_uri: EvidenceUri) -> DispatchResult {
+            ensure!(!review_uri.is_empty(), Error::<T>::InvalidUri);
+            ensure!(!Reviews::<T>::contains_key(project_id, author), Error::<T>::AlreadyReviewed);
+
+            Reviews::<T>::insert(project_id, author, review_uri.clone());
+            Self::deposit_event(Event::ReviewSubmitted { project_id, author: author.clone(), review_uri });
+            Ok(())
+        }
+
+        /// Restrict who can take on a new project: record the invite list, or offer the project
+        /// to the freelancer the client wants to hire.
+        fn set_hiring_mode(project_id: T::ProjectId, client: &T::AccountId, hiring: HiringMode<T>) -> DispatchResult {
+            match hiring {
+                HiringMode::Open => {},
+                HiringMode::InviteOnly(invited) => {
+                    ensure!(!invited.is_empty() && !invited.contains(client), Error::<T>::InvalidInvitation);
+                    for (i, freelancer) in invited.iter().enumerate() {
+                        ensure!(!invited[..i].contains(freelancer), Error::<T>::InvalidInvitation);
+                    }
+                    for freelancer in invited.iter().cloned() {
+                        Self::deposit_event(Event::FreelancerInvited { project_id, freelancer });
+                    }
+                    Invitations::<T>::insert(project_id, invited);
+                },
+                HiringMode::DirectHire(freelancer) => {
+                    ensure!(&freelancer != client, Error::<T>::InvalidInvitation);
+                    let offered = BoundedVec::try_from(alloc::vec![freelancer.clone()])
+                        .map_err(|_| Error::<T>::TooManyApplicants)?;
+                    ProjectApplicants::<T>::insert(project_id, offered);
+
+                    let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::OfferPeriod::get());
+                    OfferDeadlines::<T>::insert(project_id, expires_at);
+                    Self::deposit_event(Event::OfferMade { project_id, freelancer, expires_at });
+                },
+            }
+            Ok(())
+        }
+
+        /// Check that `freelancer` holds the open offer on a project, returning its deadline.
+        fn ensure_offered(project_id: T::ProjectId, project: &Project<T>, freelancer: &T::AccountId) -> Result<BlockNumberFor<T>, DispatchError> {
+            ensure!(project.status == ProjectStatus::Created, Error::<T>::InvalidStatus);
+            let deadline = Self::offer_deadline(project_id).ok_or(Error::<T>::NoOffer)?;
+            ensure!(Self::project_applicants(project_id).contains(freelancer), Error::<T>::NoOffer);
+            Ok(deadline)
+        }
+
+        /// Cancel a direct-hire project nobody took on. The client isn't charged a cancellation.
+        fn close_offer(project_id: T::ProjectId, project: &mut Project<T>) {
+            T::Currency::remove_lock(Self::generate_lock_id(project_id), &project.client);
+            project.status = ProjectStatus::Cancelled;
+            ProjectApplicants::<T>::remove(project_id);
+            OfferDeadlines::<T>::remove(project_id);
+        }
+
+        /// Return the deposits of every proposal on a project that is no longer open.
+        fn release_proposals(project_id: T::ProjectId) {
+            for (applicant, proposal) in Proposals::<T>::drain_prefix(project_id) {
+                T::Currency::unreserve(&applicant, proposal.deposit);
+            }
+        }
+
+        fn store_brief(project_id: T::ProjectId, reader: &T::AccountId, key: EncryptionKey, uri: EvidenceUri) -> DispatchResult {
+            ensure!(!uri.is_empty(), Error::<T>::InvalidUri);
+
+            Briefs::<T>::insert(project_id, reader, SealedBrief { key, uri: Some(uri.clone()) });
+            Self::deposit_event(Event::BriefShared { project_id, reader: reader.clone(), uri });
+            Ok(())
+        }
+
+        /// Let `readers` request the private brief of a project. Readers without a registered
+        /// key are skipped, as nobody could encrypt a copy to them.
+        fn grant_brief_access(project_id: T::ProjectId, freelancer: &T::AccountId, readers: &[T::AccountId]) {
+            if !Briefs::<T>::contains_key(project_id, freelancer) {
+                return;
+            }
+            for reader in readers {
+                if Briefs::<T>::contains_key(project_id, reader) {
+                    continue;
+                }
+                if let Some(key) = Self::encryption_key(reader) {
+                    Briefs::<T>::insert(project_id, reader, SealedBrief { key, uri: None });
+                    Self::deposit_event(Event::BriefAccessGranted { project_id, reader: reader.clone(), key });
+                }
+            }
+        }
+
+        /// Fetch pending submissions and submit whether they match their content hash. Only
+        /// nodes holding a `KEY_TYPE` key take part.
+        fn check_pending_content(now: BlockNumberFor<T>) {
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            if !signer.can_sign() {
+                return;
+            }
+
+            let limit = T::MaxContentChecksPerBlock::get() as usize;
+            // Claimed entries are skipped before the limit applies, so submissions that keep
+            // failing to fetch can't hold up the ones behind them
+            let claimed = PendingContentChecks::<T>::iter_keys()
+                .filter_map(|project_id| {
+                    let submission = Self::projects(project_id)?.work_submission?;
+                    Self::claim_content_check(project_id, now).then_some((project_id, submission))
+                })
+                .take(limit);
+            for (project_id, submission) in claimed {
+                let check = match Self::fetch_content(&submission.uri) {
+                    Ok(content) if sp_io::hashing::sha2_256(&content) == submission.content_hash => {
+                        ContentCheck::Verified
+                    },
+                    Ok(_) => ContentCheck::Mismatch,
+                    Err(FetchError::TooLarge) => ContentCheck::Unverifiable,
+                    // Fetch errors leave the check pending until the next attempt
+                    Err(FetchError::Unavailable) => continue,
+                };
+                let _ = signer.send_signed_transaction(|_| Call::record_content_check {
+                    project_id,
+                    content_hash: submission.content_hash,
+                    check,
+                });
+            }
+        }
+
+        /// Claim a project's check for `CHECK_RETRY_BLOCKS`, so later blocks don't fetch the
+        /// same submission while the result is on its way.
+        fn claim_content_check(project_id: T::ProjectId, now: BlockNumberFor<T>) -> bool {
+            let key = [b"projects::content-check::".as_slice(), &project_id.encode()].concat();
+            StorageValueRef::persistent(&key)
+                .mutate(|last: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| match last {
+                    Ok(Some(at)) if now < at.saturating_add(CHECK_RETRY_BLOCKS.into()) => Err(()),
+                    _ => Ok(now),
+                })
+                .is_ok()
+        }
+
+        /// Download a submission, reading at most `MaxContentSize` bytes of it.
+        fn fetch_content(uri: &[u8]) -> Result<Vec<u8>, FetchError> {
+            let url = Self::content_url(uri).ok_or(FetchError::Unavailable)?;
+            let max_size = T::MaxContentSize::get() as usize;
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+            let pending = http::Request::get(&url).deadline(deadline).send().map_err(|_| FetchError::Unavailable)?;
+            let mut response = pending
+                .try_wait(deadline)
+                .map_err(|_| FetchError::Unavailable)?
+                .map_err(|_| FetchError::Unavailable)?;
+            if response.code != 200 {
+                return Err(FetchError::Unavailable);
+            }
+            let declared_size = response.headers().find("content-length").and_then(|len| len.parse::<usize>().ok());
+            if declared_size.is_some_and(|len| len > max_size) {
+                return Err(FetchError::TooLarge);
+            }
+
+            // The header is only a claim, so stop reading as soon as the limit is passed
+            let mut body = response.body();
+            body.deadline(deadline);
+            let mut content = Vec::new();
+            for byte in body.by_ref() {
+                if content.len() == max_size {
+                    return Err(FetchError::TooLarge);
+                }
+                content.push(byte);
+            }
+            if body.error().is_some() {
+                return Err(FetchError::Unavailable);
+            }
+            Ok(content)
+        }
+
+        /// Only `ipfs://` submissions can be fetched and checked, and only through the gateway.
+        fn is_fetchable(uri: &[u8]) -> bool {
+            uri.starts_with(b"ipfs://")
+        }
+
+        /// Where the offchain worker fetches an `ipfs://` submission from: the node's own gateway
+        /// if it set one, `IpfsGateway` otherwise.
+        fn content_url(uri: &[u8]) -> Option<String> {
+            let path = core::str::from_utf8(uri).ok()?.strip_prefix("ipfs://")?;
+            let gateway = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, GATEWAY_KEY)
+                .and_then(|gateway| String::from_utf8(gateway).ok())
+                .unwrap_or_else(|| T::IpfsGateway::get().into());
+            Some(format!("{}/ipfs/{}", gateway.trim_end_matches('/'), path))
+        }
+
+        /// The SHA-256 digest in a raw-codec CIDv1 (`ipfs://bafkrei...`). Unlike other CIDs,
+        /// these hash the file bytes directly.
+        pub(crate) fn cid_digest(uri: &[u8]) -> Option<[u8; 32]> {
+            let cid = uri.strip_prefix(b"ipfs://")?.split(|byte| *byte == b'/').next()?;
+            // Multibase prefix `b` is lower-case base32 without padding
+            let bytes = Self::decode_base32(cid.strip_prefix(b"b")?)?;
+            match bytes.as_slice() {
+                // CIDv1, raw codec, sha2-256 multihash of 32 bytes
+                [0x01, 0x55, 0x12, 0x20, digest @ ..] => digest.try_into().ok(),
+                _ => None,
+            }
+        }
+
+        fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+            let mut output = Vec::with_capacity(input.len() * 5 / 8);
+            let (mut buffer, mut bits) = (0u32, 0u32);
+            for symbol in input {
+                let value = match symbol {
+                    b'a'..=b'z' => symbol - b'a',
+                    b'2'..=b'7' => symbol - b'2' + 26,
+                    _ => return None,
+                };
+                buffer = (buffer << 5) | u32::from(value);
+                bits += 5;
+                if bits >= 8 {
+                    bits -= 8;
+                    output.push((buffer >> bits) as u8);
+                    buffer &= (1 << bits) - 1;
+                }
+            }
+            Some(output)
+        }
+
         /// Generate a unique lock ID for a project
         fn generate_lock_id(project_id: T::ProjectId) -> [u8; 8] {
             let mut lock_id = *b"tsk/proj";  // Start with a prefix
@@ -512,16 +1262,17 @@ pub mod pallet {
                         )?;
                         
                         // Update freelancer and client reputation
-                        T::Reputation::on_project_completed(freelancer, project.budget, 3000, project_id)?; // Default 3-star rating
+                        T::Reputation::on_project_completed(freelancer, project.budget, 3000, project_id, &project.skills)?; // Default 3-star rating
                     },
                     Ruling::ClientWins => {
-                        // For client wins, we just update reputation for dispute resolution
-                        // Funds remain with client (already in escrow)
-                        T::Reputation::on_dispute_outcome(client, freelancer, project_id, project.budget)?;
+                        // Funds remain with client (already in escrow). The dispute outcome is
+                        // reported to reputation by the arbitration pallet when it enforces the ruling.
                     },
                 }
                 
                 project.status = ProjectStatus::Completed; // Dispute resolved
+                PendingContentChecks::<T>::remove(project_id);
+                Self::open_rating_window(project_id);
                 
                 Ok(())
             })
@@ -562,7 +1313,6 @@ pub mod pallet {
 
 		fn get_evidence_uris(project_id: T::ProjectId) -> Result<(EvidenceUri, EvidenceUri), DispatchError> {
 			let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
-			
 			let requirements_uri = project.uri;
 			let submission_uri = project.work_submission
 				.ok_or(Error::<T>::NoWorkSubmitted)?
@@ -570,5 +1320,26 @@ pub mod pallet {
 				
 			Ok((requirements_uri, submission_uri))
 		}
+
+        fn grant_evidence_access(project_id: T::ProjectId, readers: &[T::AccountId]) -> DispatchResult {
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            if let Some(freelancer) = project.freelancer.as_ref() {
+                Self::grant_brief_access(project_id, freelancer, readers);
+            }
+            Ok(())
+        }
+
+        fn brief_withheld(project_id: T::ProjectId, reader: &T::AccountId) -> bool {
+            Self::brief(project_id, reader).is_some_and(|brief| brief.uri.is_none())
+        }
+
+        fn get_project_skills(project_id: T::ProjectId) -> Result<Vec<Skill>, DispatchError> {
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            Ok(project.skills.into_inner())
+        }
+
+        fn get_content_check(project_id: T::ProjectId) -> Option<ContentCheck> {
+            Self::content_check(project_id)
+        }
     }
 }
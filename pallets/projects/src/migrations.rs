@@ -0,0 +1,261 @@
+//! Storage migrations for the projects pallet.
+//!
+//! Each `vN` module upgrades storage from version `N - 1` to `N`. Migrations whose work grows
+//! with the number of entries also come as a [`SteppedMigration`] for `pallet-migrations`, so
+//! they can spread over several blocks on a live chain.
+//!
+//! [`SteppedMigration`]: frame_support::migrations::SteppedMigration
+
+pub mod v1 {
+    use crate::pallet::{
+        BalanceOf, Config, DisputeInfo, Pallet, Project, ProjectStatus, Projects, WorkSubmission,
+    };
+    use codec::{Decode, Encode};
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+        weights::{Weight, WeightMeter},
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+
+    /// A project as stored before version 1, without skill tags.
+    #[derive(Encode, Decode)]
+    pub struct OldProject<T: Config> {
+        pub client: T::AccountId,
+        pub freelancer: Option<T::AccountId>,
+        pub uri: BoundedVec<u8, ConstU32<256>>,
+        pub budget: BalanceOf<T>,
+        pub status: ProjectStatus,
+        pub duration: BlockNumberFor<T>,
+        pub submission_block: Option<BlockNumberFor<T>>,
+        pub work_submission: Option<WorkSubmission<T>>,
+        pub dispute_info: Option<DisputeInfo<T>>,
+    }
+
+    impl<T: Config> OldProject<T> {
+        pub fn migrate(self) -> Project<T> {
+            Project {
+                client: self.client,
+                freelancer: self.freelancer,
+                uri: self.uri,
+                budget: self.budget,
+                status: self.status,
+                duration: self.duration,
+                submission_block: self.submission_block,
+                work_submission: self.work_submission,
+                dispute_info: self.dispute_info,
+                skills: BoundedVec::default(),
+            }
+        }
+    }
+
+    pub mod old {
+        use super::*;
+
+        /// `Projects` holding version 0 entries.
+        #[frame_support::storage_alias]
+        pub type Projects<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::ProjectId, OldProject<T>>;
+    }
+
+    /// Number of projects before the upgrade, or `None` if it does not apply.
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade_state<T: Config>() -> Vec<u8> {
+        let count = (Pallet::<T>::on_chain_storage_version() == 0)
+            .then(|| old::Projects::<T>::iter_keys().count() as u32);
+        count.encode()
+    }
+
+    /// Every project decodes in the new layout and the version is bumped.
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade_check<T: Config>(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let Some(count) = Option::<u32>::decode(&mut &state[..])
+            .map_err(|_| "v1: cannot decode pre-upgrade state")?
+        else {
+            return Ok(());
+        };
+        ensure!(Pallet::<T>::on_chain_storage_version() == 1, "v1: storage version not bumped");
+        ensure!(Projects::<T>::iter_values().count() as u32 == count, "v1: projects lost in translation");
+        Ok(())
+    }
+
+    /// Adds an empty skill list to every existing project.
+    pub struct MigrateToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut count = 0u64;
+            Projects::<T>::translate::<OldProject<T>, _>(|_, old| {
+                count = count.saturating_add(1);
+                Some(old.migrate())
+            });
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok(pre_upgrade_state::<T>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            post_upgrade_check::<T>(state)
+        }
+    }
+
+    /// [`MigrateToV1`] as a multi-block migration. Each step translates as many projects as
+    /// the weight meter allows, resuming after the last translated project id.
+    pub struct LazyMigrationV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV1<T> {
+        type Cursor = T::ProjectId;
+        type Identifier = MigrationId<15>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-projects", version_from: 0, version_to: 1 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return Ok(None);
+            }
+
+            let required = T::DbWeight::get().reads_writes(1, 1);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            while meter.try_consume(required).is_ok() {
+                let mut remaining = match cursor {
+                    Some(last) => old::Projects::<T>::iter_from(old::Projects::<T>::hashed_key_for(last)),
+                    None => old::Projects::<T>::iter(),
+                };
+                match remaining.next() {
+                    Some((project_id, project)) => {
+                        Projects::<T>::insert(project_id, project.migrate());
+                        cursor = Some(project_id);
+                    },
+                    None => {
+                        StorageVersion::new(1).put::<Pallet<T>>();
+                        return Ok(None);
+                    },
+                }
+            }
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok(pre_upgrade_state::<T>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            post_upgrade_check::<T>(state)
+        }
+    }
+}
+
+pub mod v2 {
+    use crate::pallet::{Config, Pallet, ProjectApplicants, ProjectStatus, Projects, Proposal, Proposals};
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+    use sp_runtime::traits::Zero;
+
+    /// Gives everyone who applied to an open project before proposals existed a proposal that
+    /// bids the full budget over the project's duration, without cover letter or deposit.
+    pub struct LazyMigrationV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV2<T> {
+        type Cursor = T::ProjectId;
+        type Identifier = MigrationId<15>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-projects", version_from: 1, version_to: 2 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return Ok(None);
+            }
+
+            // Worst case for one project: read it and its applicants, then write every proposal
+            let required = T::DbWeight::get().reads_writes(2, u64::from(T::MaxApplicants::get()));
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            while meter.try_consume(required).is_ok() {
+                let mut remaining = match cursor {
+                    Some(last) => ProjectApplicants::<T>::iter_from(ProjectApplicants::<T>::hashed_key_for(last)),
+                    None => ProjectApplicants::<T>::iter(),
+                };
+                let Some((project_id, applicants)) = remaining.next() else {
+                    StorageVersion::new(2).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+                // Cancelled projects used to keep their applicants; they don't need proposals
+                if let Some(project) = Projects::<T>::get(project_id).filter(|p| p.status == ProjectStatus::Created) {
+                    for applicant in applicants {
+                        let proposal = Proposal {
+                            bid: project.budget,
+                            duration: project.duration,
+                            cover_letter_uri: BoundedVec::default(),
+                            deposit: Zero::zero(),
+                        };
+                        Proposals::<T>::insert(project_id, applicant, proposal);
+                    }
+                }
+                cursor = Some(project_id);
+            }
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = (Pallet::<T>::on_chain_storage_version() == 1).then(|| {
+                ProjectApplicants::<T>::iter()
+                    .filter(|(project_id, _)| {
+                        Projects::<T>::get(project_id).is_some_and(|p| p.status == ProjectStatus::Created)
+                    })
+                    .map(|(_, applicants)| applicants.len() as u32)
+                    .sum::<u32>()
+            });
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let Some(count) = Option::<u32>::decode(&mut &state[..])
+                .map_err(|_| "v2: cannot decode pre-upgrade state")?
+            else {
+                return Ok(());
+            };
+            ensure!(Pallet::<T>::on_chain_storage_version() == 2, "v2: storage version not bumped");
+            ensure!(Proposals::<T>::iter_keys().count() as u32 == count, "v2: applicants without a proposal");
+            Ok(())
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use frame_support::{
     parameter_types,
-    traits::{ConstU32},
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
     weights::constants::RocksDbWeight,
     PalletId,
 };
@@ -10,10 +10,15 @@ use sp_runtime::{
     BuildStorage, AccountId32
 };
 use crate as pallet_projects;
-use pallet_reputation::{ReputationInterface, JurorTier};
+use pallet_reputation::{ReputationInterface, JurorTier, ProjectRole, Skill};
 use frame_support::dispatch::DispatchResult;
 use frame_support::BoundedVec;
 use sp_runtime::Permill;
+use pallet_nfts::{
+    CollectionConfig, CollectionConfigFor, CollectionSetting, CollectionSettings, ItemConfig,
+    ItemSetting, ItemSettings, MintSettings, PalletFeatures,
+};
+use sp_runtime::{MultiSignature, MultiSigner};
 
 // Configure a mock runtime to test the pallet.
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -25,6 +30,7 @@ frame_support::construct_runtime!(
         System: frame_system,
         Balances: pallet_balances,
         Projects: pallet_projects,
+        Nfts: pallet_nfts,
         Reputation: pallet_reputation,
     }
 );
@@ -94,11 +100,11 @@ impl pallet_balances::Config for Test {
 pub struct MockReputation;
 
 impl ReputationInterface<AccountId32, u64, u32, u64, MaxApplicantsLength> for MockReputation {
-    fn on_project_completed(_freelancer: &AccountId32, _project_value: u64, _client_rating: u32, _project_id: u32) -> DispatchResult {
+    fn on_project_completed(_freelancer: &AccountId32, _project_value: u64, _client_rating: u32, _project_id: u32, _skills: &[Skill]) -> DispatchResult {
         Ok(())
     }
 
-    fn on_dispute_outcome(_winner: &AccountId32, _loser: &AccountId32, _project_id: u32, _project_value: u64) -> DispatchResult {
+    fn on_dispute_outcome(_winner: &AccountId32, _loser: &AccountId32, _project_id: u32, _project_value: u64, _skills: &[Skill], _loser_role: ProjectRole) -> DispatchResult {
         Ok(())
     }
 
@@ -114,17 +120,79 @@ impl ReputationInterface<AccountId32, u64, u32, u64, MaxApplicantsLength> for Mo
         Ok(())
     }
 
-    fn get_eligible_jurors(_min_tier: JurorTier, _exclude: &[AccountId32], _count: u32) -> BoundedVec<AccountId32, MaxApplicantsLength> {
+    fn on_client_rated(_client: &AccountId32, _project_value: u64, _freelancer_rating: u32, _project_id: u32) -> DispatchResult {
+        Ok(())
+    }
+
+    fn on_work_started(_client: &AccountId32, _freelancer: &AccountId32, _project_id: u32) -> DispatchResult {
+        Ok(())
+    }
+
+    fn get_eligible_jurors(_project_id: u32, _min_tier: JurorTier, _parties: &[AccountId32], _exclude: &[AccountId32], _count: u32, _skills: &[Skill]) -> BoundedVec<AccountId32, MaxApplicantsLength> {
         BoundedVec::new()
     }
 
-    fn on_jury_vote(_juror: &AccountId32, _voted_with_majority: bool) -> DispatchResult {
+    fn skill_score(account: &AccountId32, skills: &[Skill]) -> u32 {
+        // Ranks applicants by the first byte of their account, so tests can choose the order
+        if skills.is_empty() { 0 } else { AsRef::<[u8]>::as_ref(account)[0] as u32 }
+    }
+
+    fn has_conflict_of_interest(_juror: &AccountId32, _parties: &[AccountId32]) -> bool {
+        false
+    }
+
+    fn on_jury_vote(_juror: &AccountId32, _voted_with_majority: bool, _project_id: u32) -> DispatchResult {
         Ok(())
     }
 
     fn slash_juror(_juror: &AccountId32) -> DispatchResult {
         Ok(())
     }
+
+    fn reward_shares(juror: &AccountId32, reward: u64) -> Vec<(AccountId32, u64)> {
+        vec![(juror.clone(), reward)]
+    }
+}
+
+parameter_types! {
+    pub const ReputationPalletId: PalletId = PalletId(*b"tsk/repu");
+    pub NftFeatures: PalletFeatures = PalletFeatures::all_enabled();
+    pub CredentialCollectionConfig: CollectionConfigFor<Test> = CollectionConfig {
+        settings: CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into()),
+        max_supply: None,
+        mint_settings: MintSettings::default(),
+    };
+    pub CredentialItemConfig: ItemConfig = ItemConfig {
+        settings: ItemSettings::from_disabled(ItemSetting::Transferable.into()),
+    };
+}
+
+impl pallet_nfts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId32>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId32>;
+    type Locker = ();
+    type CollectionDeposit = ConstU64<0>;
+    type ItemDeposit = ConstU64<0>;
+    type MetadataDepositBase = ConstU64<0>;
+    type AttributeDepositBase = ConstU64<0>;
+    type DepositPerByte = ConstU64<0>;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<10>;
+    type ItemAttributesApprovalsLimit = ConstU32<2>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU64<10000>;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftFeatures;
+    type OffchainSignature = MultiSignature;
+    type OffchainPublic = MultiSigner;
+    type WeightInfo = ();
+    type BlockNumberProvider = System;
 }
 
 parameter_types! {
@@ -135,6 +203,7 @@ impl pallet_reputation::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId32>;
+    type JurorEmergencyOrigin = frame_system::EnsureRoot<AccountId32>;
     type Currency = Balances;
     type ProjectId = u32;
     type MaxMetadata = ConstU32<1024>;
@@ -143,6 +212,64 @@ impl pallet_reputation::Config for Test {
     type MaxSilverJurors = ConstU32<200>;
     type MaxBronzeJurors = ConstU32<200>;
     type SlashRatio = SlashRatio;
+    type ConflictWindow = ConstU64<1000>;
+    type MaxRecentProjects = ConstU32<4>;
+    type Randomness = pallet_reputation::ParentHashRandomness<Test>;
+    type DecayPeriod = ConstU64<100>;
+    type MaxRescoresPerBlock = ConstU32<10>;
+    type PalletId = ReputationPalletId;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NftCollectionConfig = CollectionConfigFor<Test>;
+    type NftItemConfig = ItemConfig;
+    type Nfts = Nfts;
+    type CredentialCollectionConfig = CredentialCollectionConfig;
+    type CredentialItemConfig = CredentialItemConfig;
+    type PartnerChainOrigin = frame_system::EnsureRootWithSuccess<AccountId32, ConstU32<2000>>;
+    type ReputationSender = ();
+    type MaxPartnerCalls = ConstU32<100>;
+    type PartnerQuotaPeriod = ConstU64<10>;
+    type UnbondingPeriod = ConstU64<100>;
+    type MaxUnlockingChunks = ConstU32<4>;
+    type MaxDelegators = ConstU32<8>;
+    type BalanceUnit = ConstU64<1>;
+    type PublishFee = ConstU64<10>;
+}
+
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = MultiSigner;
+    type Signature = MultiSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: MultiSigner,
+        _account: AccountId32,
+        nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, nonce, (), ()))
+    }
+}
+
+parameter_types! {
+    pub const IpfsGateway: &'static str = "http://127.0.0.1:8080";
+    pub static ApplicationDeposit: u64 = 0;
+    pub static MaxContentChecksPerBlock: u32 = 5;
+    pub static MaxContentSize: u32 = 1024;
 }
 
 impl pallet_projects::Config for Test {
@@ -152,8 +279,17 @@ impl pallet_projects::Config for Test {
     type PalletId = ProjectsPalletId;
     type MaxApplicants = MaxApplicantsLength;
     type ReviewPeriod = ReviewPeriod;
+    type RatingPeriod = ConstU64<100>;
+    type OfferPeriod = ConstU64<50>;
+    type MaxSkills = ConstU32<5>;
+    type ApplicationDeposit = ApplicationDeposit;
     type WeightInfo = ();
     type Reputation = MockReputation;
+    type AuthorityId = pallet_projects::crypto::VerifierAuthId;
+    type VerifierOrigin = frame_system::EnsureSigned<AccountId32>;
+    type IpfsGateway = IpfsGateway;
+    type MaxContentChecksPerBlock = MaxContentChecksPerBlock;
+    type MaxContentSize = MaxContentSize;
 }
 
 // Build genesis storage according to the mock runtime.
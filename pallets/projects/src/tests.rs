@@ -1,7 +1,8 @@
-use crate::{mock::*, Error, Event, ProjectStatus};
+use crate::{mock::*, Arbitrable, ContentCheck, Error, Event, HiringMode, ProjectStatus};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
 use frame_support::traits::{fungible::Mutate, ConstU32};
 use frame_system::RawOrigin;
+use pallet_reputation::Skill;
 use sp_runtime::AccountId32;
 
 // Helper function to convert a string to a bounded vec
@@ -32,7 +33,9 @@ fn create_project_works() {
             RawOrigin::Signed(client.clone()).into(),
             budget,
             uri.clone(),
-            duration
+            duration,
+            Default::default(),
+            HiringMode::Open
         ));
 
         // Check event was emitted
@@ -69,19 +72,25 @@ fn apply_for_project_works() {
             RawOrigin::Signed(client.clone()).into(),
             budget,
             uri,
-            1000
+            1000,
+            Default::default(),
+            HiringMode::Open
         ));
 
         // Apply for project
         assert_ok!(Projects::apply_for_project(
             RawOrigin::Signed(freelancer.clone()).into(),
-            0
+            0,
+            1000,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
 
         // Check event was emitted
         System::assert_last_event(Event::ApplicationSubmitted { 
             project_id: 0, 
-            applicant: freelancer.clone() 
+            applicant: freelancer.clone(),
+            bid: 1000,
         }.into());
 
         // Check applicant was added
@@ -108,20 +117,26 @@ fn start_work_works() {
             RawOrigin::Signed(client.clone()).into(),
             budget,
             uri,
-            1000
+            1000,
+            Default::default(),
+            HiringMode::Open
         ));
 
         // Apply for project
         assert_ok!(Projects::apply_for_project(
             RawOrigin::Signed(freelancer.clone()).into(),
-            0
+            0,
+            1000,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
 
         // Start work
         assert_ok!(Projects::start_work(
             RawOrigin::Signed(client.clone()).into(),
             0,
-            freelancer.clone()
+            freelancer.clone(),
+            None
         ));
 
         // Check event was emitted
@@ -152,7 +167,7 @@ fn test_error_cases() {
 
         // Try to apply for non-existent project
         assert_noop!(
-            Projects::apply_for_project(RawOrigin::Signed(freelancer.clone()).into(), 0),
+            Projects::apply_for_project(RawOrigin::Signed(freelancer.clone()).into(), 0, 1000, 1000, str_to_bounded("ipfs://proposal")),
             Error::<Test>::ProjectNotFound
         );
 
@@ -161,25 +176,760 @@ fn test_error_cases() {
             RawOrigin::Signed(client.clone()).into(),
             budget,
             uri,
-            1000
+            1000,
+            Default::default(),
+            HiringMode::Open
         ));
 
         // Try to start work without applying
         assert_noop!(
-            Projects::start_work(RawOrigin::Signed(client.clone()).into(), 0, freelancer.clone()),
+            Projects::start_work(RawOrigin::Signed(client.clone()).into(), 0, freelancer.clone(), None),
             Error::<Test>::ApplicantNotFound
         );
 
         // Apply for project
         assert_ok!(Projects::apply_for_project(
             RawOrigin::Signed(freelancer.clone()).into(),
-            0
+            0,
+            1000,
+            1000,
+            str_to_bounded("ipfs://proposal")
         ));
 
         // Try to start work with wrong user
         assert_noop!(
-            Projects::start_work(RawOrigin::Signed(wrong_user.clone()).into(), 0, freelancer.clone()),
+            Projects::start_work(RawOrigin::Signed(wrong_user.clone()).into(), 0, freelancer.clone(), None),
             Error::<Test>::NotProjectOwner
         );
     });
-}
\ No newline at end of file
+}
+// Helper that takes project 0 from creation to accepted work
+fn complete_project(client: &AccountId32, freelancer: &AccountId32) {
+    let _ = <Balances as Mutate<_>>::set_balance(client, 2000);
+    assert_ok!(Projects::create_project(
+        RawOrigin::Signed(client.clone()).into(),
+        1000,
+        str_to_bounded("ipfs://project_details"),
+        1000,
+        Default::default(),
+        HiringMode::Open
+    ));
+    assert_ok!(Projects::apply_for_project(RawOrigin::Signed(freelancer.clone()).into(), 0, 1000, 1000, str_to_bounded("ipfs://proposal")));
+    assert_ok!(Projects::start_work(RawOrigin::Signed(client.clone()).into(), 0, freelancer.clone(), None));
+    assert_ok!(Projects::submit_work(
+        RawOrigin::Signed(freelancer.clone()).into(),
+        0,
+        [1u8; 32],
+        str_to_bounded("ipfs://work"),
+        BoundedVec::default()
+    ));
+    assert_ok!(Projects::accept_work(RawOrigin::Signed(client.clone()).into(), 0, 5));
+}
+
+#[test]
+fn parties_rate_and_review_after_completion() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let client = account("alice");
+        let freelancer = account("bob");
+        complete_project(&client, &freelancer);
+        assert_eq!(Projects::rating_deadline(0), Some(101));
+
+        // The freelancer rates the client and leaves a review
+        assert_ok!(Projects::rate_client(
+            RawOrigin::Signed(freelancer.clone()).into(),
+            0,
+            4,
+            Some(str_to_bounded("ipfs://freelancer_review"))
+        ));
+        System::assert_last_event(Event::ClientRated { project_id: 0, client: client.clone(), rating: 4 }.into());
+        assert_eq!(Projects::client_rating(0), Some(4));
+        assert_eq!(Projects::reviews(0, &freelancer), Some(str_to_bounded("ipfs://freelancer_review")));
+
+        // Only one rating per project
+        assert_noop!(
+            Projects::rate_client(RawOrigin::Signed(freelancer.clone()).into(), 0, 5, None),
+            Error::<Test>::AlreadyRated
+        );
+
+        // The client leaves a written review
+        assert_ok!(Projects::submit_review(
+            RawOrigin::Signed(client.clone()).into(),
+            0,
+            str_to_bounded("ipfs://client_review")
+        ));
+        System::assert_last_event(Event::ReviewSubmitted {
+            project_id: 0,
+            author: client.clone(),
+            review_uri: str_to_bounded("ipfs://client_review"),
+        }.into());
+        assert_noop!(
+            Projects::submit_review(RawOrigin::Signed(client).into(), 0, str_to_bounded("ipfs://again")),
+            Error::<Test>::AlreadyReviewed
+        );
+    });
+}
+
+#[test]
+fn rate_client_error_cases() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let client = account("alice");
+        let freelancer = account("bob");
+        complete_project(&client, &freelancer);
+
+        assert_noop!(
+            Projects::rate_client(RawOrigin::Signed(freelancer.clone()).into(), 0, 6, None),
+            Error::<Test>::InvalidRating
+        );
+        assert_noop!(
+            Projects::rate_client(RawOrigin::Signed(client).into(), 0, 3, None),
+            Error::<Test>::NotFreelancer
+        );
+
+        // The rating window closes after RatingPeriod
+        System::set_block_number(102);
+        assert_noop!(
+            Projects::rate_client(RawOrigin::Signed(freelancer).into(), 0, 3, None),
+            Error::<Test>::RatingPeriodClosed
+        );
+    });
+}
+
+#[test]
+fn projects_carry_skills_and_rank_applicants() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let client = account("alice");
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        let skill = |s: &str| -> Skill { s.as_bytes().to_vec().try_into().unwrap() };
+
+        // Skill tags can't be empty
+        assert_noop!(
+            Projects::create_project(RawOrigin::Signed(client.clone()).into(), 1000, str_to_bounded("ipfs://project_details"), 1000, vec![skill("")].try_into().unwrap(), HiringMode::Open),
+            Error::<Test>::InvalidSkill
+        );
+
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            1000,
+            vec![skill("rust"), skill("substrate")].try_into().unwrap(),
+            HiringMode::Open
+        ));
+        assert_eq!(Projects::get_project_skills(0).unwrap(), vec![skill("rust"), skill("substrate")]);
+
+        // The mock scores applicants by the first byte of their account
+        for who in ["bob", "dave", "carol"] {
+            assert_ok!(Projects::apply_for_project(RawOrigin::Signed(account(who)).into(), 0, 1000, 1000, str_to_bounded("ipfs://proposal")));
+        }
+        let ranked: Vec<AccountId32> = Projects::ranked_applicants(0).into_iter().map(|(who, _)| who).collect();
+        assert_eq!(ranked, vec![account("dave"), account("carol"), account("bob")]);
+    });
+}
+
+#[test]
+fn lazy_migration_to_v1_translates_projects_in_steps() {
+    use crate::migrations::v1::{LazyMigrationV1, OldProject};
+    use codec::Encode;
+    use frame_support::{
+        migrations::SteppedMigration,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+
+    new_test_ext().execute_with(|| {
+        for project_id in 0u32..5 {
+            let old = OldProject::<Test> {
+                client: account("alice"),
+                freelancer: None,
+                uri: str_to_bounded("ipfs://project"),
+                budget: 1_000,
+                status: ProjectStatus::Created,
+                duration: 100,
+                submission_block: None,
+                work_submission: None,
+                dispute_info: None,
+            };
+            frame_support::storage::unhashed::put_raw(
+                &crate::Projects::<Test>::hashed_key_for(project_id),
+                &old.encode(),
+            );
+        }
+        StorageVersion::new(0).put::<Projects>();
+
+        // Room for two projects per step
+        let step_weight = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(step_weight);
+            cursor = LazyMigrationV1::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(steps, 3);
+        assert_eq!(Projects::on_chain_storage_version(), 1);
+        for project_id in 0u32..5 {
+            let project = Projects::projects(project_id).unwrap();
+            assert_eq!(project.budget, 1_000);
+            assert!(project.skills.is_empty());
+        }
+
+        // Once at version 1 there is nothing left to do
+        assert!(matches!(LazyMigrationV1::<Test>::step(None, &mut WeightMeter::new()), Ok(None)));
+    });
+}
+
+#[test]
+fn private_brief_is_shared_with_the_freelancer_and_the_jury() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, freelancer, juror, stranger) = (account("alice"), account("bob"), account("juror"), account("eve"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://teaser"),
+            1000,
+            Default::default(),
+            HiringMode::Open
+        ));
+        assert_ok!(Projects::apply_for_project(RawOrigin::Signed(freelancer.clone()).into(), 0, 1000, 1000, str_to_bounded("ipfs://proposal")));
+
+        // The brief can only be encrypted to a freelancer with a registered key
+        assert_noop!(
+            Projects::start_work(RawOrigin::Signed(client.clone()).into(), 0, freelancer.clone(), Some(str_to_bounded("ipfs://brief_bob"))),
+            Error::<Test>::NoEncryptionKey
+        );
+        assert_ok!(Projects::register_encryption_key(RawOrigin::Signed(freelancer.clone()).into(), [1u8; 32]));
+        assert_ok!(Projects::start_work(
+            RawOrigin::Signed(client.clone()).into(),
+            0,
+            freelancer.clone(),
+            Some(str_to_bounded("ipfs://brief_bob"))
+        ));
+        assert_eq!(Projects::brief(0, &freelancer).unwrap().uri, Some(str_to_bounded("ipfs://brief_bob")));
+        assert_ok!(Projects::submit_work(
+            RawOrigin::Signed(freelancer.clone()).into(),
+            0,
+            [1u8; 32],
+            str_to_bounded("ipfs://work"),
+            BoundedVec::default()
+        ));
+
+        // Arbitration grants access to readers with a key; the rest are skipped
+        assert_ok!(Projects::register_encryption_key(RawOrigin::Signed(juror.clone()).into(), [2u8; 32]));
+        let (requirements_uri, _) = <Projects as Arbitrable<_, _, _, _>>::get_evidence_uris(0).unwrap();
+        assert_eq!(requirements_uri, str_to_bounded("ipfs://teaser"));
+        assert!(Projects::brief(0, &juror).is_none());
+        assert_ok!(<Projects as Arbitrable<_, _, _, _>>::grant_evidence_access(0, &[juror.clone(), stranger.clone()]));
+        System::assert_last_event(Event::BriefAccessGranted { project_id: 0, reader: juror.clone(), key: [2u8; 32] }.into());
+        assert_eq!(Projects::brief(0, &juror).unwrap().uri, None);
+        assert!(<Projects as Arbitrable<_, _, _, _>>::brief_withheld(0, &juror));
+        assert!(Projects::brief(0, &stranger).is_none());
+        assert!(!<Projects as Arbitrable<_, _, _, _>>::brief_withheld(0, &stranger));
+
+        // Either party re-encrypts the brief to the juror's key
+        assert_noop!(
+            Projects::share_brief(RawOrigin::Signed(stranger.clone()).into(), 0, juror.clone(), str_to_bounded("ipfs://brief_juror")),
+            Error::<Test>::NotProjectParty
+        );
+        assert_noop!(
+            Projects::share_brief(RawOrigin::Signed(freelancer.clone()).into(), 0, stranger.clone(), str_to_bounded("ipfs://brief_eve")),
+            Error::<Test>::BriefAccessNotGranted
+        );
+        assert_ok!(Projects::share_brief(
+            RawOrigin::Signed(freelancer.clone()).into(),
+            0,
+            juror.clone(),
+            str_to_bounded("ipfs://brief_juror")
+        ));
+        System::assert_last_event(Event::BriefShared { project_id: 0, reader: juror.clone(), uri: str_to_bounded("ipfs://brief_juror") }.into());
+        assert_eq!(Projects::brief(0, &juror).unwrap().key, [2u8; 32]);
+        assert!(!<Projects as Arbitrable<_, _, _, _>>::brief_withheld(0, &juror));
+    });
+}
+
+// Helper that takes project 0 to InProgress with `freelancer` hired
+fn hire(client: &AccountId32, freelancer: &AccountId32) {
+    let _ = <Balances as Mutate<_>>::set_balance(client, 2000);
+    assert_ok!(Projects::create_project(
+        RawOrigin::Signed(client.clone()).into(),
+        1000,
+        str_to_bounded("ipfs://project_details"),
+        1000,
+        Default::default(),
+        HiringMode::Open
+    ));
+    assert_ok!(Projects::apply_for_project(RawOrigin::Signed(freelancer.clone()).into(), 0, 1000, 1000, str_to_bounded("ipfs://proposal")));
+    assert_ok!(Projects::start_work(RawOrigin::Signed(client.clone()).into(), 0, freelancer.clone(), None));
+}
+
+#[test]
+fn submission_under_a_raw_cid_must_match_its_hash() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let freelancer = account("bob");
+        hire(&account("alice"), &freelancer);
+
+        // The CID of "delivered work" as a raw IPFS block
+        let uri = str_to_bounded("ipfs://bafkreiexr6bmbo6olgfox6re2nwmijimmiqhws7r2rejuurqdbz5ttpfs4");
+        assert_noop!(
+            Projects::submit_work(RawOrigin::Signed(freelancer.clone()).into(), 0, [1u8; 32], uri.clone(), BoundedVec::default()),
+            Error::<Test>::InvalidHash
+        );
+        let content_hash = sp_io::hashing::sha2_256(b"delivered work");
+        assert_ok!(Projects::submit_work(RawOrigin::Signed(freelancer).into(), 0, content_hash, uri, BoundedVec::default()));
+        assert!(Projects::content_check_pending(0).is_some());
+    });
+}
+
+#[test]
+fn offchain_worker_checks_submissions_against_their_hash() {
+    use codec::Decode;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+    use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+    use std::sync::Arc;
+
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let keystore = MemoryKeystore::new();
+    keystore.sr25519_generate_new(crate::KEY_TYPE, None).unwrap();
+
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt(Arc::new(keystore)));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        let freelancer = account("bob");
+        hire(&account("alice"), &freelancer);
+        let content_hash = sp_io::hashing::sha2_256(b"delivered work");
+        assert_ok!(Projects::submit_work(
+            RawOrigin::Signed(freelancer).into(),
+            0,
+            content_hash,
+            str_to_bounded("ipfs://QmWork"),
+            BoundedVec::default()
+        ));
+
+        // The gateway serves different content than the freelancer committed to
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: "http://127.0.0.1:8080/ipfs/QmWork".into(),
+            response: Some(b"something else".to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        Projects::offchain_worker(1);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        let check = crate::Call::record_content_check { project_id: 0, content_hash, check: ContentCheck::Mismatch };
+        assert_eq!(tx.function, RuntimeCall::Projects(check));
+
+        // The check is claimed, so the next block doesn't fetch it again
+        Projects::offchain_worker(2);
+        assert!(pool_state.read().transactions.is_empty());
+
+        // Results only count for the current submission
+        assert_noop!(
+            Projects::record_content_check(RawOrigin::Signed(account("oracle")).into(), 0, [1u8; 32], ContentCheck::Verified),
+            Error::<Test>::InvalidHash
+        );
+        assert_ok!(Projects::record_content_check(RawOrigin::Signed(account("oracle")).into(), 0, content_hash, ContentCheck::Mismatch));
+        System::assert_last_event(Event::ContentChecked { project_id: 0, check: ContentCheck::Mismatch }.into());
+        assert_eq!(<Projects as Arbitrable<_, _, _, _>>::get_content_check(0), Some(ContentCheck::Mismatch));
+        assert_noop!(
+            Projects::record_content_check(RawOrigin::Signed(account("oracle")).into(), 0, content_hash, ContentCheck::Verified),
+            Error::<Test>::ContentAlreadyChecked
+        );
+    });
+}
+
+#[test]
+fn accepted_bid_becomes_the_budget_and_releases_the_rest() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ApplicationDeposit::set(50);
+        let (client, bob, carol) = (account("alice"), account("bob"), account("carol"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        let _ = <Balances as Mutate<_>>::set_balance(&bob, 100);
+        let _ = <Balances as Mutate<_>>::set_balance(&carol, 100);
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            1000,
+            Default::default(),
+            HiringMode::Open
+        ));
+
+        // Bids stay within the budget and proposals need a duration and a cover letter
+        let letter = str_to_bounded("ipfs://cover_letter");
+        assert_noop!(
+            Projects::apply_for_project(RawOrigin::Signed(bob.clone()).into(), 0, 1001, 50, letter.clone()),
+            Error::<Test>::InvalidBid
+        );
+        assert_noop!(
+            Projects::apply_for_project(RawOrigin::Signed(bob.clone()).into(), 0, 600, 0, letter.clone()),
+            Error::<Test>::InvalidDuration
+        );
+        assert_noop!(
+            Projects::apply_for_project(RawOrigin::Signed(bob.clone()).into(), 0, 600, 50, BoundedVec::default()),
+            Error::<Test>::InvalidUri
+        );
+        assert_ok!(Projects::apply_for_project(RawOrigin::Signed(bob.clone()).into(), 0, 600, 50, letter.clone()));
+        assert_ok!(Projects::apply_for_project(RawOrigin::Signed(carol.clone()).into(), 0, 800, 200, letter));
+        assert_eq!(Balances::reserved_balance(&bob), 50);
+        assert_eq!(Projects::proposal(0, &bob).unwrap().bid, 600);
+
+        assert_ok!(Projects::start_work(RawOrigin::Signed(client.clone()).into(), 0, bob.clone(), None));
+        System::assert_has_event(Event::ProposalAccepted { project_id: 0, freelancer: bob.clone(), bid: 600, released: 400 }.into());
+
+        let project = Projects::projects(0).unwrap();
+        assert_eq!(project.budget, 600);
+        assert_eq!(project.submission_block, Some(51));
+        assert_eq!(pallet_balances::Locks::<Test>::get(&client)[0].amount, 600);
+
+        // Every applicant gets their deposit back
+        assert_eq!(Balances::reserved_balance(&bob), 0);
+        assert_eq!(Balances::reserved_balance(&carol), 0);
+        assert!(Projects::proposal(0, &carol).is_none());
+    });
+}
+
+#[test]
+fn withdrawn_application_returns_the_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ApplicationDeposit::set(50);
+        let (client, freelancer) = (account("alice"), account("bob"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        let _ = <Balances as Mutate<_>>::set_balance(&freelancer, 100);
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            1000,
+            Default::default(),
+            HiringMode::Open
+        ));
+        assert_ok!(Projects::apply_for_project(RawOrigin::Signed(freelancer.clone()).into(), 0, 900, 100, str_to_bounded("ipfs://proposal")));
+
+        assert_ok!(Projects::withdraw_application(RawOrigin::Signed(freelancer.clone()).into(), 0));
+        System::assert_last_event(Event::ApplicationWithdrawn { project_id: 0, applicant: freelancer.clone() }.into());
+        assert_eq!(Balances::reserved_balance(&freelancer), 0);
+        assert!(Projects::project_applicants(0).is_empty());
+        assert_noop!(
+            Projects::start_work(RawOrigin::Signed(client).into(), 0, freelancer.clone(), None),
+            Error::<Test>::ApplicantNotFound
+        );
+        assert_noop!(
+            Projects::withdraw_application(RawOrigin::Signed(freelancer).into(), 0),
+            Error::<Test>::ApplicantNotFound
+        );
+    });
+}
+
+#[test]
+fn lazy_migration_to_v2_gives_applicants_full_budget_proposals() {
+    use crate::migrations::v2::LazyMigrationV2;
+    use frame_support::{
+        migrations::SteppedMigration,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, freelancer) = (account("alice"), account("bob"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            300,
+            Default::default(),
+            HiringMode::Open
+        ));
+        // An application from before proposals
+        crate::ProjectApplicants::<Test>::insert(0, BoundedVec::truncate_from(vec![freelancer.clone()]));
+        StorageVersion::new(1).put::<Projects>();
+
+        assert!(matches!(LazyMigrationV2::<Test>::step(None, &mut WeightMeter::new()), Ok(None)));
+        assert_eq!(Projects::on_chain_storage_version(), 2);
+        let proposal = Projects::proposal(0, &freelancer).unwrap();
+        assert_eq!((proposal.bid, proposal.duration, proposal.deposit), (1000, 300, 0));
+
+        assert_ok!(Projects::start_work(RawOrigin::Signed(client).into(), 0, freelancer, None));
+    });
+}
+
+#[test]
+fn invite_only_projects_take_proposals_from_invited_freelancers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, bob, carol) = (account("alice"), account("bob"), account("carol"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        assert_noop!(
+            Projects::create_project(RawOrigin::Signed(client.clone()).into(), 1000, str_to_bounded("ipfs://project_details"), 1000, Default::default(), HiringMode::InviteOnly(Default::default())),
+            Error::<Test>::InvalidInvitation
+        );
+        assert_noop!(
+            Projects::create_project(
+                RawOrigin::Signed(client.clone()).into(),
+                1000,
+                str_to_bounded("ipfs://project_details"),
+                1000,
+                Default::default(),
+                HiringMode::InviteOnly(vec![bob.clone(), bob.clone()].try_into().unwrap())
+            ),
+            Error::<Test>::InvalidInvitation
+        );
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            1000,
+            Default::default(),
+            HiringMode::InviteOnly(vec![bob.clone()].try_into().unwrap())
+        ));
+        System::assert_last_event(Event::FreelancerInvited { project_id: 0, freelancer: bob.clone() }.into());
+        assert_eq!(Projects::invitations(0).unwrap().into_inner(), vec![bob.clone()]);
+
+        assert_noop!(
+            Projects::apply_for_project(RawOrigin::Signed(carol).into(), 0, 900, 100, str_to_bounded("ipfs://proposal")),
+            Error::<Test>::NotInvited
+        );
+        assert_ok!(Projects::apply_for_project(RawOrigin::Signed(bob.clone()).into(), 0, 900, 100, str_to_bounded("ipfs://proposal")));
+        assert_ok!(Projects::start_work(RawOrigin::Signed(client).into(), 0, bob, None));
+        assert!(Projects::invitations(0).is_none());
+    });
+}
+
+#[test]
+fn direct_hire_starts_when_the_freelancer_accepts_the_offer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, bob, carol) = (account("alice"), account("bob"), account("carol"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            200,
+            Default::default(),
+            HiringMode::DirectHire(bob.clone())
+        ));
+        System::assert_last_event(Event::OfferMade { project_id: 0, freelancer: bob.clone(), expires_at: 51 }.into());
+        assert_eq!(Projects::project_applicants(0).into_inner(), vec![bob.clone()]);
+
+        // Nobody else can apply or take the offer
+        assert_noop!(
+            Projects::apply_for_project(RawOrigin::Signed(carol.clone()).into(), 0, 900, 100, str_to_bounded("ipfs://proposal")),
+            Error::<Test>::NotInvited
+        );
+        assert_noop!(Projects::accept_offer(RawOrigin::Signed(carol).into(), 0), Error::<Test>::NoOffer);
+        assert_noop!(Projects::expire_offer(RawOrigin::Signed(client).into(), 0), Error::<Test>::OfferNotExpired);
+
+        System::set_block_number(51);
+        assert_ok!(Projects::accept_offer(RawOrigin::Signed(bob.clone()).into(), 0));
+        System::assert_has_event(Event::OfferAccepted { project_id: 0, freelancer: bob.clone() }.into());
+        System::assert_last_event(Event::WorkStarted { project_id: 0, freelancer: bob.clone() }.into());
+
+        let project = Projects::projects(0).unwrap();
+        assert_eq!(project.status, ProjectStatus::InProgress);
+        assert_eq!(project.freelancer, Some(bob.clone()));
+        assert_eq!((project.budget, project.submission_block), (1000, Some(251)));
+        assert!(Projects::offer_deadline(0).is_none());
+        assert!(Projects::project_applicants(0).is_empty());
+        assert_noop!(Projects::accept_offer(RawOrigin::Signed(bob).into(), 0), Error::<Test>::InvalidStatus);
+    });
+}
+
+#[test]
+fn declined_and_expired_offers_release_the_escrow() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, bob) = (account("alice"), account("bob"));
+        let _ = <Balances as Mutate<_>>::set_balance(&client, 2000);
+        for _ in 0..2 {
+            assert_ok!(Projects::create_project(
+                RawOrigin::Signed(client.clone()).into(),
+                500,
+                str_to_bounded("ipfs://project_details"),
+                200,
+                Default::default(),
+                HiringMode::DirectHire(bob.clone())
+            ));
+        }
+
+        assert_ok!(Projects::decline_offer(RawOrigin::Signed(bob.clone()).into(), 0));
+        System::assert_last_event(Event::OfferDeclined { project_id: 0, freelancer: bob.clone() }.into());
+        assert_eq!(Projects::projects(0).unwrap().status, ProjectStatus::Cancelled);
+
+        System::set_block_number(52);
+        assert_noop!(Projects::accept_offer(RawOrigin::Signed(bob.clone()).into(), 1), Error::<Test>::OfferExpired);
+        assert_ok!(Projects::expire_offer(RawOrigin::Signed(account("anyone")).into(), 1));
+        System::assert_last_event(Event::OfferExpired { project_id: 1, freelancer: bob }.into());
+        assert_eq!(Projects::projects(1).unwrap().status, ProjectStatus::Cancelled);
+        assert!(Projects::offer_deadline(1).is_none());
+
+        // Both locks are gone, so the whole balance can be spent again
+        assert!(pallet_balances::Locks::<Test>::get(&client).is_empty());
+    });
+}
+
+#[test]
+fn offchain_worker_skips_claimed_checks_before_applying_the_limit() {
+    use codec::Encode;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt};
+    use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+    use std::sync::Arc;
+
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let keystore = MemoryKeystore::new();
+    keystore.sr25519_generate_new(crate::KEY_TYPE, None).unwrap();
+
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt(Arc::new(keystore)));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MaxContentChecksPerBlock::set(1);
+        let (client, freelancer) = (account("alice"), account("bob"));
+        hire(&client, &freelancer);
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client.clone()).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            1000,
+            Default::default(),
+            HiringMode::DirectHire(freelancer.clone())
+        ));
+        assert_ok!(Projects::accept_offer(RawOrigin::Signed(freelancer.clone()).into(), 1));
+        let content_hash = sp_io::hashing::sha2_256(b"delivered work");
+        for project_id in 0..2u32 {
+            let uri = str_to_bounded(&format!("ipfs://QmWork{project_id}"));
+            assert_ok!(Projects::submit_work(RawOrigin::Signed(freelancer.clone()).into(), project_id, content_hash, uri, BoundedVec::default()));
+        }
+
+        // The first pending check failed to fetch in this block and is claimed until the retry
+        let order: Vec<u32> = crate::PendingContentChecks::<Test>::iter_keys().collect();
+        let claim_key = [b"projects::content-check::".as_slice(), &order[0].encode()].concat();
+        sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, &claim_key, &1u64.encode());
+
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: format!("http://127.0.0.1:8080/ipfs/QmWork{}", order[1]),
+            response: Some(b"delivered work".to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        Projects::offchain_worker(1);
+        assert_eq!(pool_state.read().transactions.len(), 1);
+    });
+}
+
+#[test]
+fn pending_content_check_is_dropped_once_the_work_is_accepted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, freelancer) = (account("alice"), account("bob"));
+        hire(&client, &freelancer);
+        let content_hash = sp_io::hashing::sha2_256(b"delivered work");
+        assert_ok!(Projects::submit_work(
+            RawOrigin::Signed(freelancer).into(),
+            0,
+            content_hash,
+            str_to_bounded("ipfs://QmWork"),
+            BoundedVec::default()
+        ));
+        assert!(Projects::content_check_pending(0).is_some());
+
+        assert_ok!(Projects::accept_work(RawOrigin::Signed(client).into(), 0, 5));
+        assert!(Projects::content_check_pending(0).is_none());
+    });
+}
+
+#[test]
+fn offchain_worker_records_oversize_content_as_unverifiable() {
+    use codec::Decode;
+    use frame_support::traits::Hooks;
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+    use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+    use std::sync::Arc;
+
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let keystore = MemoryKeystore::new();
+    keystore.sr25519_generate_new(crate::KEY_TYPE, None).unwrap();
+
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt(Arc::new(keystore)));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        MaxContentSize::set(8);
+        let (client, freelancer) = (account("alice"), account("bob"));
+        hire(&client, &freelancer);
+        let content_hash = sp_io::hashing::sha2_256(b"delivered work");
+
+        // Plain HTTP(S) links are never fetched
+        assert_ok!(Projects::submit_work(
+            RawOrigin::Signed(freelancer.clone()).into(),
+            0,
+            content_hash,
+            str_to_bounded("https://example.com/work"),
+            BoundedVec::default()
+        ));
+        assert!(Projects::content_check_pending(0).is_none());
+
+        assert_ok!(Projects::create_project(
+            RawOrigin::Signed(client).into(),
+            1000,
+            str_to_bounded("ipfs://project_details"),
+            1000,
+            Default::default(),
+            HiringMode::DirectHire(freelancer.clone())
+        ));
+        assert_ok!(Projects::accept_offer(RawOrigin::Signed(freelancer.clone()).into(), 1));
+        assert_ok!(Projects::submit_work(
+            RawOrigin::Signed(freelancer).into(),
+            1,
+            content_hash,
+            str_to_bounded("ipfs://QmWork"),
+            BoundedVec::default()
+        ));
+        // The gateway sends no length, so the worker stops reading at the limit
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: "http://127.0.0.1:8080/ipfs/QmWork".into(),
+            response: Some(b"delivered work".to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        Projects::offchain_worker(1);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        let check = crate::Call::record_content_check { project_id: 1, content_hash, check: ContentCheck::Unverifiable };
+        assert_eq!(tx.function, RuntimeCall::Projects(check));
+    });
+}
@@ -0,0 +1,119 @@
+//! RPC interface for the reputation pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_reputation_runtime_api::{AttestationRecord, JurorTier, ScoreBreakdown};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_reputation_runtime_api::ReputationApi as ReputationRuntimeApi;
+
+#[rpc(client, server)]
+pub trait ReputationApi<BlockHash, AccountId, ProjectId, Balance, BlockNumber> {
+    #[method(name = "reputation_score")]
+    fn reputation_score(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Option<u32>>;
+
+    #[method(name = "reputation_clientScore")]
+    fn client_score(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Option<u32>>;
+
+    #[method(name = "reputation_jurorTier")]
+    fn juror_tier(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<JurorTier>;
+
+    #[method(name = "reputation_scoreBreakdown")]
+    fn score_breakdown(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ScoreBreakdown>>;
+
+    #[method(name = "reputation_attestations")]
+    fn attestations(
+        &self,
+        account: AccountId,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AttestationRecord<ProjectId, Balance, BlockNumber>>>;
+}
+
+/// Provides RPC methods to query an account's reputation.
+pub struct Reputation<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Reputation<C, Block> {
+    /// Creates a new instance of the reputation RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(e: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Unable to query reputation.", Some(e.to_string()))
+}
+
+impl<C, Block, AccountId, ProjectId, Balance, BlockNumber>
+    ReputationApiServer<<Block as BlockT>::Hash, AccountId, ProjectId, Balance, BlockNumber>
+    for Reputation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ReputationRuntimeApi<Block, AccountId, ProjectId, Balance, BlockNumber>,
+    AccountId: Codec + Clone + Send + Sync + 'static,
+    ProjectId: Codec + Send + Sync + 'static,
+    Balance: Codec + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + 'static,
+{
+    fn reputation_score(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<u32>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().reputation_score(at, account).map_err(runtime_error)
+    }
+
+    fn client_score(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<Option<u32>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().client_score(at, account).map_err(runtime_error)
+    }
+
+    fn juror_tier(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<JurorTier> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().juror_tier(at, account).map_err(runtime_error)
+    }
+
+    fn score_breakdown(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ScoreBreakdown>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().score_breakdown(at, account).map_err(runtime_error)
+    }
+
+    fn attestations(
+        &self,
+        account: AccountId,
+        start: u32,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<AttestationRecord<ProjectId, Balance, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .attestations(at, account, start, limit)
+            .map_err(runtime_error)
+    }
+}
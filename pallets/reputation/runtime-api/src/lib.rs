@@ -0,0 +1,34 @@
+//! Runtime API for querying reputation scores and attestations.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_reputation::{AttestationRecord, JurorTier, ScoreBreakdown};
+
+sp_api::decl_runtime_apis! {
+    pub trait ReputationApi<AccountId, ProjectId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        ProjectId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Freelancer reputation score (0-10000), `None` if the account is not registered.
+        fn reputation_score(account: AccountId) -> Option<u32>;
+        /// Client reputation score (0-10000), `None` if the account is not registered.
+        fn client_score(account: AccountId) -> Option<u32>;
+        /// The juror tier the account's history qualifies it for.
+        fn juror_tier(account: AccountId) -> JurorTier;
+        /// Per-component breakdown of the reputation score.
+        fn score_breakdown(account: AccountId) -> Option<ScoreBreakdown>;
+        /// Up to `limit` attestations of `account`, starting at nonce `start`.
+        fn attestations(
+            account: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<AttestationRecord<ProjectId, Balance, BlockNumber>>;
+    }
+}
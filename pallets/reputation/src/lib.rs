@@ -8,12 +8,74 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
 use frame_support::{
     dispatch::DispatchResult,
-    traits::{Get},
+    traits::{ConstU32, Get, Randomness},
     BoundedVec,
 };
-use sp_runtime::Permill;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+    traits::{Hash, Saturating},
+    DispatchError, Permill, Percent, RuntimeDebug,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::{prelude::vec::Vec, TypeInfo};
+
+/// A skill tag chosen by the client, e.g. `b"rust"` or `b"ui-design"`.
+pub type Skill = BoundedVec<u8, ConstU32<32>>;
+
+/// The side of a project an account was on.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ProjectRole {
+    Client,
+    Freelancer,
+}
+
+/// Reputation of an account as shared with other chains.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReputationSummary<AccountId, BlockNumber> {
+    pub account: AccountId,
+    pub score: u32,
+    pub tier: JurorTier,
+    pub projects_completed: u32,
+    /// Block of this chain at which the summary was taken.
+    pub as_of: BlockNumber,
+}
+
+/// Delivers reputation summaries to other parachains, e.g. over XCM.
+pub trait ReputationSender<AccountId, BlockNumber> {
+    /// Send `summary` to parachain `dest`, which dispatches it to the call identified by
+    /// `callback` (pallet index, call index).
+    fn send_summary(
+        dest: u32,
+        callback: [u8; 2],
+        summary: ReputationSummary<AccountId, BlockNumber>,
+    ) -> DispatchResult;
+}
+
+impl<AccountId, BlockNumber> ReputationSender<AccountId, BlockNumber> for () {
+    fn send_summary(
+        _dest: u32,
+        _callback: [u8; 2],
+        _summary: ReputationSummary<AccountId, BlockNumber>,
+    ) -> DispatchResult {
+        Err(DispatchError::Other("No reputation sender configured"))
+    }
+}
+
+/// Randomness from the parent block hash mixed with the subject. The block author can bias it,
+/// but nobody can predict it before the parent block is built.
+pub struct ParentHashRandomness<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> Randomness<T::Hash, BlockNumberFor<T>> for ParentHashRandomness<T> {
+    fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+        let parent_hash = frame_system::Pallet::<T>::parent_hash();
+        let known_since = frame_system::Pallet::<T>::block_number().saturating_sub(1u32.into());
+        (T::Hashing::hash_of(&(parent_hash, subject)), known_since)
+    }
+}
 
 
 pub trait ReputationInterface<AccountId, Balance, ProjectId, BlockNumber, MaxJurors: Get<u32>> {
@@ -22,13 +84,18 @@ pub trait ReputationInterface<AccountId, Balance, ProjectId, BlockNumber, MaxJur
         project_value: Balance,
         client_rating: u32,
         project_id: ProjectId,
+        skills: &[Skill],
     ) -> DispatchResult;
         
+    /// Record a dispute outcome. `loser_role` says which ledger the loss counts against. A
+    /// credential the loser holds for the project is burned, since the ruling overturned it.
     fn on_dispute_outcome(
         winner: &AccountId,
         loser: &AccountId,
         project_id: ProjectId,
         project_value: Balance,
+        skills: &[Skill],
+        loser_role: ProjectRole,
     ) -> DispatchResult;
 
     fn on_project_created(
@@ -44,21 +111,61 @@ pub trait ReputationInterface<AccountId, Balance, ProjectId, BlockNumber, MaxJur
         client: &AccountId,
         project_id: ProjectId,
     ) -> DispatchResult;
-        
+
+    /// Record the freelancer's rating (0-5000) of a client after completion.
+    fn on_client_rated(
+        client: &AccountId,
+        project_value: Balance,
+        freelancer_rating: u32,
+        project_id: ProjectId,
+    ) -> DispatchResult;
+
+    /// Record that a client and a freelancer started working together.
+    fn on_work_started(
+        client: &AccountId,
+        freelancer: &AccountId,
+        project_id: ProjectId,
+    ) -> DispatchResult;
+
+    /// Select up to `count` jurors of at least `min_tier` for the dispute on `project_id`.
+    /// `parties` are excluded together with anyone who has a conflict of interest with them;
+    /// `exclude` is only excluded. Jurors with a track record in any of `skills` are drawn first.
     fn get_eligible_jurors(
+        project_id: ProjectId,
         min_tier: JurorTier, 
+        parties: &[AccountId],
         exclude: &[AccountId],
         count: u32,
+        skills: &[Skill],
     ) -> BoundedVec<AccountId, MaxJurors>;
 
+    /// Average skill score (0-10000) of `account` over `skills`, for ranking applicants.
+    fn skill_score(
+        account: &AccountId,
+        skills: &[Skill],
+    ) -> u32;
+
+    /// Whether `juror` worked with any of `parties` within the conflict window.
+    fn has_conflict_of_interest(
+        juror: &AccountId,
+        parties: &[AccountId],
+    ) -> bool;
+
     fn on_jury_vote(
         juror: &AccountId,
         voted_with_majority: bool,
+        project_id: ProjectId,
     ) -> DispatchResult;
 
     fn slash_juror(
         juror: &AccountId
     ) -> DispatchResult;
+
+    /// Split a jury reward between the juror (listed first) and its delegators.
+    fn reward_shares(
+        juror: &AccountId,
+        reward: Balance,
+    ) -> Vec<(AccountId, Balance)>;
 }
 
 #[frame_support::pallet]
@@ -67,20 +174,44 @@ pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-    use frame_support::{BoundedVec, traits::{Currency, ReservableCurrency, LockableCurrency, EnsureOrigin}};
+    use frame_support::{BoundedVec, PalletId, traits::{Currency, Imbalance, ReservableCurrency, LockableCurrency, EnsureOrigin, ExistenceRequirement}};
+    use frame_support::traits::tokens::nonfungibles_v2;
     use frame_system::WeightInfo;
     use scale_info::TypeInfo;
     use codec::{Encode, Decode, MaxEncodedLen};
     use scale_info::prelude::ops::Add;
     use sp_runtime::{
-		traits::{ Saturating}
+		traits::{ Saturating, AccountIdConversion, One, TrailingZeroInput},
+        Perbill,
     };
     use sp_runtime::Vec;
 
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// Number of score histogram buckets; scores 0-10000 fall into buckets of 100 points.
+    pub const SCORE_BUCKETS: u32 = 101;
+    const SCORE_BUCKET_WIDTH: u32 = 100;
+
+    /// Weight of a single fresh rating or dispute outcome in `DecayedMetrics`.
+    pub const EVENT_WEIGHT: u64 = 1000;
+    /// Number of recent ratings needed for full rating credit.
+    const RATING_CONFIDENCE: u64 = 5 * EVENT_WEIGHT;
+    /// Number of jury votes needed for full jury credit.
+    const JURY_CONFIDENCE: u32 = 10;
+    /// Maximum number of attestations returned by `attestation_page`.
+    pub const MAX_ATTESTATION_PAGE: u32 = 100;
+    /// Number of leaves in each tier's `PoolStakeTree`; juror pools may not be larger.
+    pub const STAKE_TREE_SIZE: u32 = 1 << 16;
+    /// Share of a pool's total stake between consecutive draws. Stepping by the golden ratio
+    /// spreads draws evenly over the stake, whatever the pool's size.
+    const DRAW_STEP: Permill = Permill::from_parts(618_034);
+
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -92,29 +223,61 @@ pub mod pallet {
         pub registration_block: BlockNumber,
         pub last_activity_block: BlockNumber,
 
-        // --- Freelancer-Specific Metrics ---
-        pub projects_completed: u32,
-        pub projects_failed: u32,
-        pub total_earned: Balance,
-
-        // --- Client-Specific Metrics ---
-        pub projects_posted: u32,
-        pub total_spent: Balance,
+        // --- Role Ledgers ---
+        pub freelancer: FreelancerStats<Balance>,
+        pub client: ClientStats<Balance>,
+        pub juror: JurorStats,
         
         // --- Dispute Metrics (Universal) ---
         pub disputes_initiated: u32,
         pub disputes_won: u32,
         pub disputes_lost: u32,
-        
-        // --- Rating Metrics (Universal) ---
+    }
+
+    /// Metrics earned by delivering work. Only these count towards juror tiers.
+    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound(serialize = "Balance: serde::Serialize"))]
+    #[serde(bound(deserialize = "Balance: serde::Deserialize<'de>"))]
+    pub struct FreelancerStats<Balance> {
+        pub projects_completed: u32,
+        pub projects_failed: u32,
+        pub total_earned: Balance,
         pub avg_rating_received: u32, // 0-5000 scale
         pub total_ratings_received: u32,
-        
-        // --- Juror Metrics (Universal) ---
+    }
+
+    /// Metrics earned by posting and paying for projects.
+    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound(serialize = "Balance: serde::Serialize"))]
+    #[serde(bound(deserialize = "Balance: serde::Deserialize<'de>"))]
+    pub struct ClientStats<Balance> {
+        pub projects_posted: u32,
+        pub projects_completed: u32, // work accepted
+        pub projects_cancelled: u32,
+        pub total_spent: Balance,
+        pub avg_rating_received: u32, // 0-5000 scale, rated by freelancers
+        pub total_ratings_received: u32,
+    }
+
+    /// Metrics earned by serving on juries.
+    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct JurorStats {
         pub jury_participation: u32,
         pub jury_accuracy: Permill,
     }
 
+    /// Track record of an account in one skill.
+    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct SkillStats {
+        pub projects_completed: u32,
+        pub avg_rating: u32, // 0-5000 scale
+        pub disputes_won: u32,
+        pub disputes_lost: u32,
+    }
+
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct Attestation<T: Config> {
@@ -123,18 +286,53 @@ pub mod pallet {
         pub outcome: AttestationOutcome,
         pub value: BalanceOf<T>,
         pub timestamp: BlockNumberFor<T>,
+        pub rating: Option<u32>, // 0-5000 scale
         pub metadata: BoundedVec<u8, T::MaxMetadata>,
     }
 
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    /// An attestation together with its nonce, as returned by the runtime API.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound(serialize = "ProjectId: serde::Serialize, Balance: serde::Serialize, BlockNumber: serde::Serialize"))]
+    #[serde(bound(deserialize = "ProjectId: serde::Deserialize<'de>, Balance: serde::Deserialize<'de>, BlockNumber: serde::Deserialize<'de>"))]
+    pub struct AttestationRecord<ProjectId, Balance, BlockNumber> {
+        pub nonce: u32,
+        pub attestor: AttestorType,
+        pub project_id: ProjectId,
+        pub outcome: AttestationOutcome,
+        pub value: Balance,
+        pub timestamp: BlockNumber,
+        pub rating: Option<u32>,
+    }
+
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    #[derive(serde::Serialize, serde::Deserialize)]
     pub enum AttestorType {
         ClientApproval,
         ArbitrationWin,
         ArbitrationLoss,
         JuryParticipation,
+        FreelancerRating,
+        /// Reported by a whitelisted partner parachain.
+        PartnerChain(u32),
     }
 
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    impl AttestorType {
+        /// Whether the attestation records working on the project as one of its parties,
+        /// as opposed to judging it or work done on another chain.
+        pub fn is_party(&self) -> bool {
+            matches!(
+                self,
+                AttestorType::ClientApproval
+                    | AttestorType::ArbitrationWin
+                    | AttestorType::ArbitrationLoss
+                    | AttestorType::FreelancerRating
+            )
+        }
+    }
+
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    #[derive(serde::Serialize, serde::Deserialize)]
     pub enum AttestationOutcome {
         Positive,
         Negative,
@@ -143,6 +341,17 @@ pub mod pallet {
 
 
 
+    /// Time-weighted rating and dispute history. Every entry loses `time_decay_rate` of its
+    /// weight per `DecayPeriod`; amounts are in thousandths of an event.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct DecayedMetrics<BlockNumber> {
+        pub rating_sum: u64,      // sum of rating (0-5000) × weight
+        pub rating_weight: u64,
+        pub disputes_won: u64,
+        pub disputes_lost: u64,
+        pub last_decay_block: BlockNumber,
+    }
+
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
     pub struct WeightConfig {
         pub completion_weight: u32,
@@ -162,7 +371,22 @@ pub mod pallet {
         pub min_score: u32,
     }
 
+    /// The components `calculate_reputation_score` adds up, before the cap at 10000.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct ScoreBreakdown {
+        pub completion: u32,
+        pub rating: u32,
+        pub volume: u32,
+        pub activity: u32,
+        pub dispute: u32,
+        pub arbitration: u32,
+        pub jury: u32,
+        pub total: u32,
+    }
+
     #[derive(Encode, Decode, Clone, PartialEq, PartialOrd, Eq, MaxEncodedLen, TypeInfo, Debug, Copy)]
+    #[derive(serde::Serialize, serde::Deserialize)]
     pub enum JurorTier {
         Ineligible, // Not qualified or staked
         Bronze,     // Can judge small disputes
@@ -176,11 +400,74 @@ pub mod pallet {
         }
     }
 
+    /// Stake on its way out of the jury. It stays reserved, and slashable, until `unlock_at`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct UnlockChunk<Balance, BlockNumber> {
+        pub amount: Balance,
+        pub unlock_at: BlockNumber,
+    }
+
+    /// What an account needs to qualify for one juror tier.
+    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound(serialize = "Balance: serde::Serialize"))]
+    #[serde(bound(deserialize = "Balance: serde::Deserialize<'de>"))]
+    pub struct TierRequirement<Balance> {
+        /// Projects completed as a freelancer.
+        pub min_projects: u32,
+        /// Total earned as a freelancer.
+        pub min_earned: Balance,
+        /// Reputation score (0-10000).
+        pub min_score: u32,
+        /// Jury votes cast.
+        pub min_jury_votes: u32,
+        /// Share of votes with the majority; only checked once the account has voted.
+        pub min_jury_accuracy: Permill,
+        /// Stake reserved as a juror.
+        pub min_stake: Balance,
+    }
+
+    /// Juror tier requirements, set by governance.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound(serialize = "Balance: serde::Serialize"))]
+    #[serde(bound(deserialize = "Balance: serde::Deserialize<'de>"))]
+    pub struct TierThresholds<Balance> {
+        pub bronze: TierRequirement<Balance>,
+        pub silver: TierRequirement<Balance>,
+        pub gold: TierRequirement<Balance>,
+        /// Accounts that lost a larger share of their disputes are ineligible...
+        pub max_dispute_loss_rate: Permill,
+        /// ...once they took part in at least this many disputes.
+        pub loss_rate_min_disputes: u32,
+    }
+
+    impl<Balance: Default + Copy + From<u32> + Saturating> TierThresholds<Balance> {
+        /// The default requirements, with earnings in whole units of `unit`. No stake is
+        /// needed beyond the `JurorStake` every juror reserves.
+        pub fn in_units(unit: Balance) -> Self {
+            let requirement = |min_projects, min_earned: u32| TierRequirement {
+                min_projects,
+                min_earned: unit.saturating_mul(min_earned.into()),
+                ..Default::default()
+            };
+            Self {
+                bronze: requirement(5, 1000),
+                silver: requirement(20, 10000),
+                gold: requirement(50, 50000),
+                max_dispute_loss_rate: Permill::from_percent(50),
+                loss_rate_min_disputes: 3,
+            }
+        }
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type WeightInfo: WeightInfo;
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to remove a juror at once, e.g. after misconduct comes to light.
+        type JurorEmergencyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         /// Currency
         type Currency: Currency<Self::AccountId>
             + ReservableCurrency<Self::AccountId>
@@ -197,18 +484,106 @@ pub mod pallet {
         type MaxJurors: Get<u32>;
 
         #[pallet::constant]
+        /// Capacity of the Gold juror pool.
         type MaxGoldJurors: Get<u32>;
 
         #[pallet::constant]
+        /// Capacity of the Silver juror pool.
         type MaxSilverJurors: Get<u32>;
 
         #[pallet::constant]
+        /// Capacity of the Bronze juror pool.
         type MaxBronzeJurors: Get<u32>;
 
         #[pallet::constant]
         /// The percentage of a juror's stake to be slashed for misbehavior.
         type SlashRatio: Get<Permill>;
-            
+
+        #[pallet::constant]
+        /// How far back a past collaboration with a party disqualifies a juror.
+        type ConflictWindow: Get<BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// Maximum number of projects remembered per account for conflict-of-interest checks.
+        type MaxRecentProjects: Get<u32>;
+
+        /// Source of the random offset applied to jury draws.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// Number of blocks over which `WeightConfig::time_decay_rate` is applied once.
+        type DecayPeriod: Get<BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// Maximum number of accounts re-scored, of jurors re-tiered and of collaborations
+        /// checked for expiry, per block in `on_idle`.
+        type MaxRescoresPerBlock: Get<u32>;
+
+        #[pallet::constant]
+        /// The pallet id, used for deriving the account that owns the credential collection.
+        type PalletId: Get<PalletId>;
+
+        /// The type used to identify NFT collections
+        type CollectionId: Member + Parameter + MaxEncodedLen + Copy;
+
+        /// The type used to identify credentials within the collection
+        type ItemId: Member + Parameter + MaxEncodedLen + Copy + From<u32>;
+
+        /// Collection settings of the credential collection. Must make items non-transferable.
+        type NftCollectionConfig;
+
+        /// Settings of a single credential.
+        type NftItemConfig;
+
+        /// The NFT implementation used to issue soulbound reputation credentials.
+        type Nfts: nonfungibles_v2::Create<Self::AccountId, Self::NftCollectionConfig>
+            + nonfungibles_v2::Mutate<
+                Self::AccountId,
+                Self::NftItemConfig,
+                CollectionId = Self::CollectionId,
+                ItemId = Self::ItemId,
+            >;
+
+        /// Settings used when creating the credential collection.
+        type CredentialCollectionConfig: Get<Self::NftCollectionConfig>;
+
+        /// Settings used when minting a credential.
+        type CredentialItemConfig: Get<Self::NftItemConfig>;
+
+        /// Origin of messages from other parachains, e.g. XCM `Transact`. Yields the para id.
+        type PartnerChainOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = u32>;
+
+        /// Sends reputation summaries to partner parachains.
+        type ReputationSender: ReputationSender<Self::AccountId, BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// Queries and attestations one partner chain may send per `PartnerQuotaPeriod`. They
+        /// arrive as unpaid XCM, so this is what bounds their cost.
+        type MaxPartnerCalls: Get<u32>;
+
+        #[pallet::constant]
+        /// Length of the window `MaxPartnerCalls` is counted over.
+        type PartnerQuotaPeriod: Get<BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// How long unbonded juror stake stays reserved and slashable.
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        #[pallet::constant]
+        /// Maximum number of unbonding chunks per juror; later unbonds merge into the last one.
+        type MaxUnlockingChunks: Get<u32>;
+
+        #[pallet::constant]
+        /// Maximum number of accounts delegating to one juror.
+        type MaxDelegators: Get<u32>;
+
+        #[pallet::constant]
+        /// One whole unit of `Currency`; the default juror tier requirements are given in it.
+        type BalanceUnit: Get<BalanceOf<Self>>;
+
+        #[pallet::constant]
+        /// Paid to the pallet account by `publish_reputation` for the message it sends.
+        type PublishFee: Get<BalanceOf<Self>>;
     }
 
 
@@ -218,18 +593,74 @@ pub mod pallet {
     pub type ReputationStats<T: Config> = 
         StorageMap<_, Blake2_128Concat, T::AccountId, ReputationData<BalanceOf<T>, BlockNumberFor<T>>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn skill_stats)]
+    /// Per-skill track record, updated on completions and dispute outcomes.
+    pub type SkillReputation<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, Skill, SkillStats, ValueQuery>;
+
 
     #[pallet::storage]
     #[pallet::getter(fn attestations)]
-    /// On-chain attestations from projects/arbitrations
+    /// On-chain attestations from projects/arbitrations, in the order they were issued
     pub type Attestations<T: Config> = 
         StorageDoubleMap<
             _, 
             Blake2_128Concat, T::AccountId,    // User
-            Blake2_128Concat, T::ProjectId,         // Attestation ID
+            Twox64Concat, u32,                 // Attestation nonce
             Attestation<T>
         >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_count)]
+    /// Number of attestations issued to an account; the nonce of the next one
+    pub type AttestationNonce<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    /// Index of attestations by project: (user, project, nonce) -> attestor
+    pub type ProjectAttestations<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Blake2_128Concat, T::ProjectId>,
+            NMapKey<Twox64Concat, u32>,
+        ),
+        AttestorType,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn collaborations)]
+    /// Last block at which two accounts were paired on a project (stored in both directions)
+    pub type Collaborations<T: Config> =
+        StorageDoubleMap<
+            _,
+            Blake2_128Concat, T::AccountId,
+            Blake2_128Concat, T::AccountId,
+            BlockNumberFor<T>
+        >;
+
+    #[pallet::storage]
+    /// Last pair checked by the `on_idle` sweep that removes collaborations older than `ConflictWindow`
+    pub type CollaborationCursor<T: Config> = StorageValue<_, (T::AccountId, T::AccountId)>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn recent_projects)]
+    /// Projects an account received a party attestation for within `ConflictWindow`, with the
+    /// block of the latest one, oldest first. When full the oldest entry makes room.
+    pub type RecentProjects<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::ProjectId, BlockNumberFor<T>), T::MaxRecentProjects>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn decayed_stats)]
+    /// Ratings and dispute outcomes weighted by age, as of `last_decay_block`
+    pub type DecayedStats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DecayedMetrics<BlockNumberFor<T>>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn reputation_weights)]
     /// Configurable weights for different reputation factors
@@ -242,6 +673,50 @@ pub mod pallet {
     pub type GlobalStats<T: Config> = 
         StorageValue<_, GlobalReputationStats, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn score_histogram)]
+    /// Number of users per score bucket (see `SCORE_BUCKETS`)
+    pub type ScoreHistogram<T: Config> = StorageValue<_, BoundedVec<u32, ConstU32<SCORE_BUCKETS>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn score_sum)]
+    /// Sum of all scores counted in the histogram
+    pub type ScoreSum<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn last_score)]
+    /// The score each account is currently counted with in the histogram
+    pub type LastScore<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn rescore_cursor)]
+    /// Last account re-scored by `on_idle`; `None` starts a new pass
+    pub type RescoreCursor<T: Config> = StorageValue<_, T::AccountId>;
+
+    #[pallet::storage]
+    /// Set when the histogram changed and `GlobalStats` must be recomputed
+    pub type GlobalStatsDirty<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn tier_thresholds)]
+    /// Requirements of each juror tier
+    pub type JurorTierThresholds<T: Config> =
+        StorageValue<_, TierThresholds<BalanceOf<T>>, ValueQuery, DefaultTierThresholds<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultTierThresholds<T: Config>() -> TierThresholds<BalanceOf<T>> {
+        TierThresholds::in_units(T::BalanceUnit::get())
+    }
+
+    #[pallet::storage]
+    /// Set when the tier thresholds changed and registered jurors must be re-tiered
+    pub type RetierPending<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn retier_cursor)]
+    /// Last juror re-tiered by `on_idle` in the current pass
+    pub type RetierCursor<T: Config> = StorageValue<_, T::AccountId>;
+
     #[pallet::storage]
     #[pallet::getter(fn juror_tier)]
     /// The cached and on-chain verified tier of a user for juror selection.
@@ -254,26 +729,40 @@ pub mod pallet {
     pub type JurorRegistry<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn gold_jurors)]
-    /// The active, opted-in pool of Gold tier jurors.
-    pub type GoldJurors<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxGoldJurors>, ValueQuery>;
+    #[pallet::getter(fn juror_at)]
+    /// The active, opted-in jurors of each tier, at indices `0..juror_pool_size(tier)`.
+    pub type JurorPools<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, JurorTier, Twox64Concat, u32, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pool_size)]
+    /// Number of jurors in each tier's pool.
+    pub type JurorPoolSize<T: Config> = StorageMap<_, Blake2_128Concat, JurorTier, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pool_index)]
+    /// Index of a juror in the pool of its tier (see `JurorTiers`).
+    pub type JurorPoolIndex<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn silver_jurors)]
-    /// The active, opted-in pool of Silver tier jurors.
-    pub type SilverJurors<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxSilverJurors>, ValueQuery>;
+    /// Fenwick tree over the draw weights of each tier's pool. Node `n` (1-based) holds the
+    /// weights of the `n & n.wrapping_neg()` jurors ending at pool index `n - 1`.
+    pub type PoolStakeTree<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, JurorTier, Twox64Concat, u32, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn bronze_jurors)]
-    /// The active, opted-in pool of Bronze tier jurors.
-    pub type BronzeJurors<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxBronzeJurors>, ValueQuery>;
+    #[pallet::getter(fn pool_stake)]
+    /// Sum of the draw weights in each tier's pool.
+    pub type PoolStake<T: Config> = StorageMap<_, Blake2_128Concat, JurorTier, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn next_juror_index)]
-    pub type NextJurorIndex<T: Config> = StorageMap<_, Blake2_128Concat, JurorTier, u32, ValueQuery>;
+    #[pallet::getter(fn draw_cursor)]
+    /// Position in the tier's total draw weight where the next jury draw starts, before the
+    /// draw's random offset is added.
+    pub type DrawCursor<T: Config> = StorageMap<_, Blake2_128Concat, JurorTier, BalanceOf<T>, ValueQuery>;
 
     // ---------------------- staking ----------------------
-    /// Amount reserved when joining the jury.
+    /// Minimum amount reserved when joining the jury.
     #[pallet::storage]
     #[pallet::getter(fn juror_stake)]
     pub type JurorStake<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
@@ -284,11 +773,94 @@ pub mod pallet {
     pub type StakeOf<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
 
+    /// Stake a juror unbonded, oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn unlocking)]
+    pub type Unlocking<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<UnlockChunk<BalanceOf<T>, BlockNumberFor<T>>, T::MaxUnlockingChunks>,
+        ValueQuery,
+    >;
+
+    /// Stake delegated to a juror, by delegator. Reserved from the delegator.
+    #[pallet::storage]
+    #[pallet::getter(fn delegation)]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId, // Juror
+        Blake2_128Concat, T::AccountId, // Delegator
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Total stake delegated to a juror.
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_stake)]
+    pub type DelegatedStake<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Number of accounts delegating to a juror, or still unbonding stake they undelegated
+    /// from it.
+    #[pallet::storage]
+    #[pallet::getter(fn delegator_count)]
+    pub type DelegatorCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Stake undelegated from a juror that is still unbonding, by delegator. It is slashed
+    /// along with the juror until it unlocks.
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding_delegation)]
+    pub type UnbondingDelegations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId, // Juror
+        Blake2_128Concat, T::AccountId, // Delegator
+        UnlockChunk<BalanceOf<T>, BlockNumberFor<T>>,
+    >;
+
+    /// Share of jury rewards a juror keeps before splitting the rest with its delegators.
+    #[pallet::storage]
+    #[pallet::getter(fn commission)]
+    pub type Commission<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Permill, ValueQuery>;
+
     /// Simple flag: true if juror is currently selected in an active dispute.
     #[pallet::storage]
     #[pallet::getter(fn juror_busy)]
     pub type JurorBusy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
+    // ---------------------- credentials ----------------------
+    /// Collection holding all soulbound credentials. Nothing is minted until it exists.
+    #[pallet::storage]
+    #[pallet::getter(fn credential_collection)]
+    pub type CredentialCollection<T: Config> = StorageValue<_, T::CollectionId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_credential_id)]
+    pub type NextCredentialId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Credential minted for a completed project.
+    #[pallet::storage]
+    #[pallet::getter(fn project_credentials)]
+    pub type ProjectCredentials<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::ProjectId, T::ItemId, OptionQuery>;
+
+    /// Credential minted the first time an account reached a juror tier.
+    #[pallet::storage]
+    #[pallet::getter(fn tier_credentials)]
+    pub type TierCredentials<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, JurorTier, T::ItemId, OptionQuery>;
+
+    // ---------------------- cross-chain ----------------------
+    /// Parachains allowed to query reputation and submit attestations, with the
+    /// (pallet index, call index) they receive summaries on.
+    #[pallet::storage]
+    #[pallet::getter(fn partner_chain)]
+    pub type PartnerChains<T: Config> = StorageMap<_, Blake2_128Concat, u32, [u8; 2], OptionQuery>;
+
+    /// Start of each partner chain's current quota period, and the calls it made since.
+    #[pallet::storage]
+    #[pallet::getter(fn partner_calls)]
+    pub type PartnerCalls<T: Config> = StorageMap<_, Blake2_128Concat, u32, (BlockNumberFor<T>, u32), ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -299,18 +871,39 @@ pub mod pallet {
             freelancer: T::AccountId, 
             project_id: T::ProjectId 
         },
+        ClientRated { 
+            client: T::AccountId, 
+            project_id: T::ProjectId 
+        },
         DisputeResolved { 
             winner: T::AccountId, 
             loser: T::AccountId, 
             project_id: T::ProjectId 
         },
         WeightsUpdated,
+        TierThresholdsUpdated,
         GlobalStatsUpdated,
         JurorTierUpdated { account: T::AccountId },
         JurorRegistered { account: T::AccountId },
         JurorDeregistered { account: T::AccountId },
         JurorAutomaticallyDeregistered { account: T::AccountId },
+        /// `JurorEmergencyOrigin` removed a juror.
+        JurorRemoved { account: T::AccountId, slashed: bool },
         JurorSlashed { account: T::AccountId, amount: BalanceOf<T> },
+        JurorBonded { account: T::AccountId, amount: BalanceOf<T> },
+        JurorUnbonded { account: T::AccountId, amount: BalanceOf<T>, unlock_at: BlockNumberFor<T> },
+        StakeWithdrawn { account: T::AccountId, amount: BalanceOf<T> },
+        Delegated { delegator: T::AccountId, juror: T::AccountId, amount: BalanceOf<T> },
+        Undelegated { delegator: T::AccountId, juror: T::AccountId, amount: BalanceOf<T> },
+        DelegatorSlashed { delegator: T::AccountId, juror: T::AccountId, amount: BalanceOf<T> },
+        CommissionSet { juror: T::AccountId, commission: Permill },
+        CredentialCollectionCreated { collection: T::CollectionId },
+        CredentialMinted { account: T::AccountId, item: T::ItemId },
+        CredentialRevoked { account: T::AccountId, item: T::ItemId },
+        PartnerChainUpdated { para_id: u32, callback: Option<[u8; 2]> },
+        ReputationSent { account: T::AccountId, para_id: u32 },
+        PartnerAttestationReceived { account: T::AccountId, para_id: u32, project_id: T::ProjectId },
+        AttestationCreated { account: T::AccountId, nonce: u32, attestor: AttestorType },
     }
 
     #[pallet::error]
@@ -327,6 +920,19 @@ pub mod pallet {
         JurorPoolFull,
         StakeTooLow,
         Busy,
+        CredentialCollectionExists,
+        /// The parachain is not a whitelisted partner.
+        UnknownPartnerChain,
+        /// No unbonded stake has unlocked yet.
+        NothingToWithdraw,
+        /// Jurors cannot delegate to themselves; use `bond_extra`.
+        SelfDelegation,
+        /// The caller has no stake delegated to this juror.
+        NotDelegated,
+        /// The juror has `MaxDelegators` delegators already.
+        TooManyDelegators,
+        /// The partner chain used up its `MaxPartnerCalls` for this period.
+        PartnerQuotaExceeded,
     }
 
     #[pallet::genesis_config]
@@ -336,6 +942,7 @@ pub mod pallet {
         /// Use a standard Vec for genesis configuration.
         pub initial_jurors: Vec<T::AccountId>,
         pub juror_stake: BalanceOf<T>,
+        pub tier_thresholds: TierThresholds<BalanceOf<T>>,
     }
 
     impl<T: Config> Default for GenesisConfig<T> {
@@ -344,6 +951,7 @@ pub mod pallet {
                 initial_users: Vec::new(),
                 initial_jurors: Vec::new(),
                 juror_stake: BalanceOf::<T>::zero(),
+                tier_thresholds: DefaultTierThresholds::<T>::get(),
             }
         }
     }
@@ -353,6 +961,7 @@ pub mod pallet {
         fn build(&self) {
             // Set the default juror stake amount
             JurorStake::<T>::put(self.juror_stake);
+            JurorTierThresholds::<T>::put(self.tier_thresholds.clone());
 
             // Populate initial reputation stats
             for (account, stats) in &self.initial_users {
@@ -374,34 +983,34 @@ pub mod pallet {
                 StakeOf::<T>::insert(juror_account, self.juror_stake);
                 JurorRegistry::<T>::insert(juror_account, true);
 
-                // Calculate tier and add to the appropriate BoundedVec list
-                let tier = Pallet::<T>::calculate_tier_from_stats(&stats);
+                // Calculate tier and add to the matching pool
+                let tier = Pallet::<T>::calculate_tier(juror_account);
                 if tier != JurorTier::Ineligible {
                     JurorTiers::<T>::insert(juror_account, tier);
-                    
-                    // --- THE KEY CHANGE IS HERE ---
-                    // We modify the BoundedVec storage directly inside the build logic.
-                    // This is safe because we are building from a clean state.
-                    match tier {
-                        JurorTier::Gold => GoldJurors::<T>::try_mutate(|jurors| {
-                            jurors.try_push(juror_account.clone())
-                        }).expect("Genesis juror pool should not be full; qed"),
-                        JurorTier::Silver => SilverJurors::<T>::try_mutate(|jurors| {
-                            jurors.try_push(juror_account.clone())
-                        }).expect("Genesis juror pool should not be full; qed"),
-                        JurorTier::Bronze => BronzeJurors::<T>::try_mutate(|jurors| {
-                            jurors.try_push(juror_account.clone())
-                        }).expect("Genesis juror pool should not be full; qed"),
-                        JurorTier::Ineligible => {
-                            // Should not reach here due to earlier check
-                        },
-                    };
+                    Pallet::<T>::add_juror_to_tier_list(juror_account, tier)
+                        .expect("Genesis juror pool should not be full; qed");
                 }
             }
         }
     }
 
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut used = Self::refresh_global_stats(remaining_weight);
+            used = used.saturating_add(Self::refresh_juror_tiers(remaining_weight.saturating_sub(used)));
+            used.saturating_add(Self::prune_collaborations(remaining_weight.saturating_sub(used)))
+        }
+
+        fn integrity_test() {
+            for capacity in [T::MaxGoldJurors::get(), T::MaxSilverJurors::get(), T::MaxBronzeJurors::get()] {
+                assert!(capacity <= STAKE_TREE_SIZE, "juror pools must fit the stake tree");
+            }
+            assert!(T::MaxRecentProjects::get() > 0, "accounts must remember at least one project");
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -419,21 +1028,16 @@ pub mod pallet {
             let initial_data = ReputationData {
                 registration_block: <frame_system::Pallet<T>>::block_number(),
                 last_activity_block: <frame_system::Pallet<T>>::block_number(),
-                projects_completed: 0,
-                projects_failed: 0,
-                total_earned: BalanceOf::<T>::zero(),
-                projects_posted: 0,
-                total_spent: BalanceOf::<T>::zero(),
+                freelancer: FreelancerStats::default(),
+                client: ClientStats::default(),
+                juror: JurorStats::default(),
                 disputes_initiated: 0,
                 disputes_won: 0,
                 disputes_lost: 0,
-                avg_rating_received: 0,
-                total_ratings_received: 0,
-                jury_participation: 0,
-                jury_accuracy: Permill::zero(),
             };
             
             ReputationStats::<T>::insert(&who, initial_data);
+            Self::refresh_score(&who);
 
             Self::deposit_event(Event::UserRegistered { account: who });
             Ok(())
@@ -473,7 +1077,7 @@ pub mod pallet {
             ensure!(!Self::juror_opted_in(&who), Error::<T>::AlreadyRegisteredAsJuror);
 
             // 2. Check if they meet the minimum tier requirement (e.g., Bronze)
-            let tier = Self::calculate_tier_from_stats(&Self::reputation_stats(&who));
+            let tier = Self::calculate_tier(&who);
             ensure!(tier >= JurorTier::Bronze, Error::<T>::InsufficientTier);
 
             let stake = JurorStake::<T>::get();
@@ -484,6 +1088,7 @@ pub mod pallet {
             JurorRegistry::<T>::insert(&who, true);
             JurorTiers::<T>::insert(&who, tier);
             Self::add_juror_to_tier_list(&who, tier)?;
+            Self::mint_tier_credential(&who, tier);
 
             Self::deposit_event(Event::JurorRegistered { account: who });
             Ok(())
@@ -495,86 +1100,389 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             ensure!(Self::juror_opted_in(&who), Error::<T>::NotRegisteredAsJuror);
             ensure!(!Self::juror_busy(&who), Error::<T>::Busy);
-            let stake = StakeOf::<T>::take(&who).ok_or(Error::<T>::NotRegisteredAsJuror)?;
-            T::Currency::unreserve(&who, stake);
             let tier = Self::juror_tier(&who);
             Self::remove_juror_from_tier_list(&who, tier)?;
+            Self::release_delegations(&who);
+            let stake = StakeOf::<T>::take(&who).ok_or(Error::<T>::NotRegisteredAsJuror)?;
+            Self::start_unbonding(&who, stake);
             JurorRegistry::<T>::remove(&who);
             JurorTiers::<T>::remove(&who);
             Self::deposit_event(Event::JurorDeregistered { account: who });
             Ok(())
         }
-        
-    }
-
-    impl<T: Config> Pallet<T> {
-        pub(crate) fn internal_project_completed(
-            freelancer: &T::AccountId,
-            project_value: BalanceOf<T>, // ← Uses type alias
-            client_rating: u32,
-            project_id: T::ProjectId,
-        ) -> DispatchResult {
-            ensure!(client_rating <= 5000, Error::<T>::InvalidScore);
-
-            ReputationStats::<T>::try_mutate(freelancer, |stats| -> DispatchResult {
-                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
-                stats.projects_completed = stats.projects_completed.saturating_add(1);
-                stats.total_earned = stats.total_earned.saturating_add(project_value);
-                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
-                
-                let total = stats.total_ratings_received;
-                stats.avg_rating_received = (stats.avg_rating_received
-                    .saturating_mul(total)
-                    .saturating_add(client_rating)) 
-                    / (total.saturating_add(1));
-                stats.total_ratings_received = total.saturating_add(1);
-                
-                Ok(())
-            })?;
 
-            Self::update_juror_tier(freelancer)?;
-            
-            Self::create_attestation(
-                freelancer,
-                project_id,
-                AttestorType::ClientApproval,
-                AttestationOutcome::Positive,
-                project_value,
-                client_rating,
+        /// Create the collection that holds soulbound credentials. The pallet account pays the
+        /// collection deposit, so it has to be funded first.
+        #[pallet::call_index(4)]
+        #[pallet::weight(Weight::default())]
+        pub fn create_credential_collection(origin: OriginFor<T>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(Self::credential_collection().is_none(), Error::<T>::CredentialCollectionExists);
+
+            let owner = Self::account_id();
+            let collection = <T::Nfts as nonfungibles_v2::Create<_, _>>::create_collection(
+                &owner,
+                &owner,
+                &T::CredentialCollectionConfig::get(),
             )?;
-            
-            Self::deposit_event(Event::ProjectCompleted { 
-                freelancer: freelancer.clone(), 
-                project_id 
-            });
-            
+            CredentialCollection::<T>::put(collection);
+            Self::deposit_event(Event::CredentialCollectionCreated { collection });
             Ok(())
         }
 
-        pub(crate) fn internal_dispute_outcome(
-            winner: &T::AccountId,
-            loser: &T::AccountId,
+        /// Burn the credential of a project whose outcome was overturned, e.g. on appeal.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::default())]
+        pub fn revoke_credential(
+            origin: OriginFor<T>,
+            account: T::AccountId,
             project_id: T::ProjectId,
-            _project_value: BalanceOf<T>,
         ) -> DispatchResult {
-            ReputationStats::<T>::try_mutate(winner, |stats| -> DispatchResult {
-                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
-                stats.disputes_won = stats.disputes_won.saturating_add(1);
-                //stats.projects_completed = stats.projects_completed.saturating_add(1);
-                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
-                Ok(())
-            })?;
-            
-            ReputationStats::<T>::try_mutate(loser, |stats| -> DispatchResult {
-                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
-                stats.disputes_lost = stats.disputes_lost.saturating_add(1);
-                stats.projects_failed = stats.projects_failed.saturating_add(1);
-                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
-                Ok(())
-            })?;
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            Self::burn_project_credential(&account, project_id)
+        }
+
+        /// Whitelist a partner parachain, or remove it with `None`. `callback` is the
+        /// (pallet index, call index) on the partner chain that receives `ReputationSummary`s.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::default())]
+        pub fn set_partner_chain(
+            origin: OriginFor<T>,
+            para_id: u32,
+            callback: Option<[u8; 2]>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            PartnerChains::<T>::set(para_id, callback);
+            Self::deposit_event(Event::PartnerChainUpdated { para_id, callback });
+            Ok(())
+        }
+
+        /// A partner chain asks for the reputation of `account`. The summary is sent back to
+        /// the partner's callback.
+        #[pallet::call_index(7)]
+        #[pallet::weight(Pallet::<T>::send_summary_weight().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+        pub fn query_reputation(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            let para_id = T::PartnerChainOrigin::ensure_origin(origin)?;
+            ensure!(PartnerChains::<T>::contains_key(para_id), Error::<T>::UnknownPartnerChain);
+            Self::use_partner_quota(para_id)?;
+            Self::send_summary(account, para_id)
+        }
+
+        /// Push the caller's own reputation to a partner chain, paying `PublishFee`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(Pallet::<T>::send_summary_weight())]
+        pub fn publish_reputation(origin: OriginFor<T>, para_id: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::Currency::transfer(&who, &Self::account_id(), T::PublishFee::get(), ExistenceRequirement::KeepAlive)?;
+            Self::send_summary(who, para_id)
+        }
+
+        /// A partner chain attests to work `account` did there. Stored in `Attestations` under
+        /// `AttestorType::PartnerChain`; it does not change local stats or scores.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(5, 5))]
+        pub fn submit_partner_attestation(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            project_id: T::ProjectId,
+            outcome: AttestationOutcome,
+            value: BalanceOf<T>,
+            rating: u32,
+        ) -> DispatchResult {
+            let para_id = T::PartnerChainOrigin::ensure_origin(origin)?;
+            ensure!(PartnerChains::<T>::contains_key(para_id), Error::<T>::UnknownPartnerChain);
+            ensure!(rating <= 5000, Error::<T>::InvalidScore);
+            ensure!(ReputationStats::<T>::contains_key(&account), Error::<T>::UserNotRegistered);
+            Self::use_partner_quota(para_id)?;
+
+            Self::create_attestation(&account, project_id, AttestorType::PartnerChain(para_id), outcome, value, Some(rating));
+            Self::deposit_event(Event::PartnerAttestationReceived { account, para_id, project_id });
+            Ok(())
+        }
+
+        /// Replace the juror tier requirements. Registered jurors are re-tiered in `on_idle`,
+        /// and whenever their stats change before that.
+        #[pallet::call_index(10)]
+        #[pallet::weight(Weight::default())]
+        pub fn set_tier_thresholds(
+            origin: OriginFor<T>,
+            thresholds: TierThresholds<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            JurorTierThresholds::<T>::put(thresholds);
+            // Restart any pass in progress, earlier jurors were checked against old thresholds
+            RetierCursor::<T>::kill();
+            RetierPending::<T>::put(true);
+            Self::deposit_event(Event::TierThresholdsUpdated);
+            Ok(())
+        }
+
+        /// Add to the caller's juror stake. More stake means more jury draws and may
+        /// qualify for a higher tier.
+        #[pallet::call_index(11)]
+        #[pallet::weight(Weight::default())]
+        pub fn bond_extra(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::juror_opted_in(&who), Error::<T>::NotRegisteredAsJuror);
+            T::Currency::reserve(&who, amount)?;
+            let stake = Self::stake_of(&who).unwrap_or_default().saturating_add(amount);
+            Self::set_juror_stake(&who, stake);
+            Self::update_juror_tier(&who)?;
+            Self::deposit_event(Event::JurorBonded { account: who, amount });
+            Ok(())
+        }
+
+        /// Move part of the caller's stake into unbonding. At least `JurorStake` has to stay
+        /// bonded; use `deregister_as_juror` to unbond everything.
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::default())]
+        pub fn unbond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::juror_opted_in(&who), Error::<T>::NotRegisteredAsJuror);
+            ensure!(!Self::juror_busy(&who), Error::<T>::Busy);
+            let stake = Self::stake_of(&who).unwrap_or_default();
+            ensure!(amount <= stake, Error::<T>::StakeTooLow);
+            let remaining = stake.saturating_sub(amount);
+            ensure!(remaining >= Self::juror_stake(), Error::<T>::StakeTooLow);
+            Self::set_juror_stake(&who, remaining);
+            Self::start_unbonding(&who, amount);
+            Self::update_juror_tier(&who)?;
+            Ok(())
+        }
+
+        /// Release the caller's unbonded stake whose unbonding period has passed.
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::default())]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let mut chunks = Self::unlocking(&who);
+            let mut amount = BalanceOf::<T>::zero();
+            chunks.retain(|chunk| {
+                if chunk.unlock_at > now {
+                    return true;
+                }
+                amount = amount.saturating_add(chunk.amount);
+                false
+            });
+            ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+            if chunks.is_empty() {
+                Unlocking::<T>::remove(&who);
+            } else {
+                Unlocking::<T>::insert(&who, chunks);
+            }
+            T::Currency::unreserve(&who, amount);
+            Self::deposit_event(Event::StakeWithdrawn { account: who, amount });
+            Ok(())
+        }
+
+        /// Back a registered juror with the caller's funds. Delegated stake adds to the juror's
+        /// draw weight, earns a share of its jury rewards after commission, and is slashed
+        /// along with the juror.
+        #[pallet::call_index(14)]
+        #[pallet::weight(Weight::default())]
+        pub fn delegate(origin: OriginFor<T>, juror: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who != juror, Error::<T>::SelfDelegation);
+            ensure!(Self::juror_opted_in(&juror), Error::<T>::NotRegisteredAsJuror);
+            ensure!(!amount.is_zero(), Error::<T>::StakeTooLow);
+
+            let current = Self::delegation(&juror, &who);
+            if !Self::holds_delegator_slot(&juror, &who) {
+                if Self::delegator_count(&juror) >= T::MaxDelegators::get() {
+                    Self::prune_unbonding_delegations(&juror);
+                }
+                ensure!(Self::delegator_count(&juror) < T::MaxDelegators::get(), Error::<T>::TooManyDelegators);
+            }
+            T::Currency::reserve(&who, amount)?;
+            Self::update_delegator_slot(&juror, &who, || {
+                Self::update_backing(&juror, || {
+                    Delegations::<T>::insert(&juror, &who, current.saturating_add(amount));
+                    DelegatedStake::<T>::mutate(&juror, |total| *total = total.saturating_add(amount));
+                })
+            });
+            Self::deposit_event(Event::Delegated { delegator: who, juror, amount });
+            Ok(())
+        }
+
+        /// Take back up to `amount` of the stake delegated to `juror`. It unbonds like juror
+        /// stake, and is slashed along with the juror until it unlocks.
+        #[pallet::call_index(15)]
+        #[pallet::weight(Weight::default())]
+        pub fn undelegate(origin: OriginFor<T>, juror: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let current = Self::delegation(&juror, &who);
+            ensure!(!current.is_zero(), Error::<T>::NotDelegated);
+            let amount = amount.min(current);
+            Self::unbond_delegation(&juror, &who, amount);
+            Self::deposit_event(Event::Undelegated { delegator: who, juror, amount });
+            Ok(())
+        }
+
+        /// Set the share of jury rewards the caller keeps before paying its delegators.
+        #[pallet::call_index(16)]
+        #[pallet::weight(Weight::default())]
+        pub fn set_commission(origin: OriginFor<T>, commission: Permill) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::juror_opted_in(&who), Error::<T>::NotRegisteredAsJuror);
+            Commission::<T>::insert(&who, commission);
+            Self::deposit_event(Event::CommissionSet { juror: who, commission });
+            Ok(())
+        }
+
+        /// Remove a juror right away, even while it sits on a jury, optionally slashing it
+        /// first. Its stake and delegations start unbonding.
+        #[pallet::call_index(17)]
+        #[pallet::weight(Weight::default())]
+        pub fn emergency_remove_juror(origin: OriginFor<T>, juror: T::AccountId, slash: bool) -> DispatchResult {
+            T::JurorEmergencyOrigin::ensure_origin(origin)?;
+            ensure!(Self::juror_opted_in(&juror), Error::<T>::NotRegisteredAsJuror);
+
+            if slash {
+                Self::internal_slash_juror(&juror)?;
+            }
+            // A slash that depletes the stake already removed the juror
+            if Self::juror_opted_in(&juror) {
+                let tier = Self::juror_tier(&juror);
+                Self::remove_juror_from_tier_list(&juror, tier)?;
+                Self::release_delegations(&juror);
+                if let Some(stake) = StakeOf::<T>::take(&juror) {
+                    Self::start_unbonding(&juror, stake);
+                }
+                JurorRegistry::<T>::remove(&juror);
+                JurorTiers::<T>::remove(&juror);
+            }
+            Self::deposit_event(Event::JurorRemoved { account: juror, slashed: slash });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub(crate) fn internal_project_completed(
+            freelancer: &T::AccountId,
+            project_value: BalanceOf<T>, // ← Uses type alias
+            client_rating: u32,
+            project_id: T::ProjectId,
+            skills: &[Skill],
+        ) -> DispatchResult {
+            ensure!(client_rating <= 5000, Error::<T>::InvalidScore);
+
+            Self::record_decayed(freelancer, |metrics| {
+                metrics.rating_sum = metrics.rating_sum.saturating_add(u64::from(client_rating).saturating_mul(EVENT_WEIGHT));
+                metrics.rating_weight = metrics.rating_weight.saturating_add(EVENT_WEIGHT);
+            });
+            ReputationStats::<T>::try_mutate(freelancer, |stats| -> DispatchResult {
+                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
+                let freelancer_stats = &mut stats.freelancer;
+                freelancer_stats.projects_completed = freelancer_stats.projects_completed.saturating_add(1);
+                freelancer_stats.total_earned = freelancer_stats.total_earned.saturating_add(project_value);
+                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
+                
+                let total = freelancer_stats.total_ratings_received;
+                freelancer_stats.avg_rating_received = (freelancer_stats.avg_rating_received
+                    .saturating_mul(total)
+                    .saturating_add(client_rating)) 
+                    / (total.saturating_add(1));
+                freelancer_stats.total_ratings_received = total.saturating_add(1);
+                
+                Ok(())
+            })?;
+
+            for skill in skills {
+                SkillReputation::<T>::mutate(freelancer, skill, |skill_stats| {
+                    let total = skill_stats.projects_completed;
+                    skill_stats.avg_rating = (skill_stats.avg_rating
+                        .saturating_mul(total)
+                        .saturating_add(client_rating))
+                        / (total.saturating_add(1));
+                    skill_stats.projects_completed = total.saturating_add(1);
+                });
+            }
+
+            Self::update_juror_tier(freelancer)?;
+            Self::refresh_score(freelancer);
+            
+            let nonce = Self::create_attestation(
+                freelancer,
+                project_id,
+                AttestorType::ClientApproval,
+                AttestationOutcome::Positive,
+                project_value,
+                Some(client_rating),
+            );
+            Self::mint_project_credential(freelancer, project_id, nonce);
+            
+            Self::deposit_event(Event::ProjectCompleted { 
+                freelancer: freelancer.clone(), 
+                project_id 
+            });
+            
+            Ok(())
+        }
+
+        pub(crate) fn internal_dispute_outcome(
+            winner: &T::AccountId,
+            loser: &T::AccountId,
+            project_id: T::ProjectId,
+            project_value: BalanceOf<T>,
+            skills: &[Skill],
+            loser_role: ProjectRole,
+        ) -> DispatchResult {
+            Self::record_decayed(winner, |metrics| {
+                metrics.disputes_won = metrics.disputes_won.saturating_add(EVENT_WEIGHT);
+            });
+            Self::record_decayed(loser, |metrics| {
+                metrics.disputes_lost = metrics.disputes_lost.saturating_add(EVENT_WEIGHT);
+            });
+            ReputationStats::<T>::try_mutate(winner, |stats| -> DispatchResult {
+                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
+                stats.disputes_won = stats.disputes_won.saturating_add(1);
+                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
+                Ok(())
+            })?;
+            
+            ReputationStats::<T>::try_mutate(loser, |stats| -> DispatchResult {
+                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
+                stats.disputes_lost = stats.disputes_lost.saturating_add(1);
+                // A client who loses still had the project delivered; only freelancers fail one
+                if loser_role == ProjectRole::Freelancer {
+                    stats.freelancer.projects_failed = stats.freelancer.projects_failed.saturating_add(1);
+                }
+                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
+                Ok(())
+            })?;
+
+            for skill in skills {
+                SkillReputation::<T>::mutate(winner, skill, |s| s.disputes_won = s.disputes_won.saturating_add(1));
+                SkillReputation::<T>::mutate(loser, skill, |s| s.disputes_lost = s.disputes_lost.saturating_add(1));
+            }
 
             Self::update_juror_tier(winner)?;
             Self::update_juror_tier(loser)?;
+            Self::refresh_score(winner);
+            Self::refresh_score(loser);
+
+            Self::create_attestation(
+                winner,
+                project_id,
+                AttestorType::ArbitrationWin,
+                AttestationOutcome::Positive,
+                project_value,
+                None,
+            );
+            if ProjectCredentials::<T>::contains_key(loser, project_id) {
+                Self::burn_project_credential(loser, project_id)?;
+            }
+
+            Self::create_attestation(
+                loser,
+                project_id,
+                AttestorType::ArbitrationLoss,
+                AttestationOutcome::Negative,
+                project_value,
+                None,
+            );
             
             Self::deposit_event(Event::DisputeResolved { 
                 winner: winner.clone(), 
@@ -588,23 +1496,24 @@ pub mod pallet {
         pub(crate) fn internal_jury_vote(
             juror: &T::AccountId,
             voted_with_majority: bool,
+            project_id: T::ProjectId,
         ) -> DispatchResult {
             ReputationStats::<T>::try_mutate(juror, |stats| -> DispatchResult {
                 ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
                 
-                stats.jury_participation = stats.jury_participation.saturating_add(1);
+                stats.juror.jury_participation = stats.juror.jury_participation.saturating_add(1);
                 
-                let total_votes = stats.jury_participation;
+                let total_votes = stats.juror.jury_participation;
                 if total_votes == 0 {
                     return Ok(());
                 }
-                let current_accuracy = stats.jury_accuracy.deconstruct();
+                let current_accuracy = stats.juror.jury_accuracy.deconstruct();
                 let vote_value = if voted_with_majority { 1_000_000u32 } else { 0u32 };
                 let new_accuracy = (current_accuracy
                     .saturating_mul(total_votes.saturating_sub(1))
                     .saturating_add(vote_value)) 
                     / total_votes;
-                stats.jury_accuracy = Permill::from_parts(new_accuracy);
+                stats.juror.jury_accuracy = Permill::from_parts(new_accuracy);
                 
                 stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
                 
@@ -612,100 +1521,501 @@ pub mod pallet {
             })?;
 
             Self::update_juror_tier(juror)?;
+            Self::refresh_score(juror);
+
+            let outcome = if voted_with_majority {
+                AttestationOutcome::Positive
+            } else {
+                AttestationOutcome::Negative
+            };
+            Self::create_attestation(
+                juror,
+                project_id,
+                AttestorType::JuryParticipation,
+                outcome,
+                BalanceOf::<T>::zero(),
+                None,
+            );
             
             Ok(())
         }
 
         pub(crate) fn internal_get_eligible_jurors(
+            project_id: T::ProjectId,
             min_tier: JurorTier,
+            parties: &[T::AccountId],
             exclude: &[T::AccountId],
             count: u32,
+            skills: &[Skill],
         ) -> BoundedVec<T::AccountId, T::MaxJurors> {
             let mut selected_jurors = BoundedVec::<T::AccountId, T::MaxJurors>::new();
             let required_count = count as usize;
+            let since = Self::conflict_window_start();
+            let party_projects = Self::recent_projects_of(parties, since);
+
+            let is_eligible = |
+                juror_to_check: &T::AccountId,
+                selected_jurors: &BoundedVec<T::AccountId, T::MaxJurors>,
+                experts_only: bool,
+            | {
+                !parties.contains(juror_to_check)
+                    && !exclude.contains(juror_to_check)
+                    && !selected_jurors.contains(juror_to_check)
+                    && !Self::conflicts_with(juror_to_check, parties, &party_projects, since)
+                    && (!experts_only || Self::knows_any_skill(juror_to_check, skills))
+            };
 
-            // The closure now accepts `selected_jurors` as a mutable argument
+            // Walks a tier's total stake from `DrawCursor` in steps of `DRAW_STEP` of the total,
+            // so jurors are hit in proportion to their stake, reading one juror at a time. The
+            // walk is shifted by a random offset, so the cursor alone does not predict a draw.
             let select_from_pool = |
                 selected_jurors: &mut BoundedVec<T::AccountId, T::MaxJurors>,
                 tier: JurorTier,
-                pool: &[T::AccountId]
+                experts_only: bool,
             | {
-                if selected_jurors.len() >= required_count || pool.is_empty() { return; }
+                let pool_size = Self::juror_pool_size(tier);
+                let total = Self::pool_stake(tier);
+                if selected_jurors.len() >= required_count || pool_size == 0 || total.is_zero() { return; }
 
-                let mut current_index = Self::next_juror_index(tier) as usize;
+                let step = (DRAW_STEP * total).max(One::one());
+                let offset = Self::draw_offset(project_id, tier, total);
+                let mut cursor = Self::draw_cursor(tier) % total;
                 let mut attempts = 0;
 
-                while selected_jurors.len() < required_count && attempts < pool.len() {
-                    let juror_to_check = &pool[current_index % pool.len()];
-
-                    if !exclude.contains(juror_to_check) && !selected_jurors.contains(juror_to_check) {
+                while selected_jurors.len() < required_count && attempts < pool_size {
+                    let index = Self::find_by_weight(tier, cursor.saturating_add(offset) % total);
+                    if let Some(juror) = Self::juror_at(tier, index)
+                        .filter(|juror| is_eligible(juror, selected_jurors, experts_only))
+                    {
                         // This unwrap is safe because we check the length above.
-                        selected_jurors.try_push(juror_to_check.clone()).unwrap();
+                        selected_jurors.try_push(juror).unwrap();
                     }
-                    current_index += 1;
+                    cursor = cursor.saturating_add(step) % total;
                     attempts += 1;
                 }
-                NextJurorIndex::<T>::insert(tier, (current_index % pool.len()) as u32);
+                DrawCursor::<T>::insert(tier, cursor);
+
+                // Large stakes may have been hit repeatedly; fill up in pool order
+                let mut index = 0;
+                while selected_jurors.len() < required_count && index < pool_size {
+                    if let Some(juror) = Self::juror_at(tier, index)
+                        .filter(|juror| is_eligible(juror, selected_jurors, experts_only))
+                    {
+                        selected_jurors.try_push(juror).unwrap();
+                    }
+                    index += 1;
+                }
             };
 
-            if min_tier <= JurorTier::Gold {
-                select_from_pool(&mut selected_jurors, JurorTier::Gold, &Self::gold_jurors());
+            // Domain experts first, then anyone eligible.
+            let passes: &[bool] = if skills.is_empty() { &[false] } else { &[true, false] };
+            for &experts_only in passes {
+                if min_tier <= JurorTier::Gold {
+                    select_from_pool(&mut selected_jurors, JurorTier::Gold, experts_only);
+                }
+                if selected_jurors.len() < required_count && min_tier <= JurorTier::Silver {
+                    select_from_pool(&mut selected_jurors, JurorTier::Silver, experts_only);
+                }
+                if selected_jurors.len() < required_count && min_tier <= JurorTier::Bronze {
+                    select_from_pool(&mut selected_jurors, JurorTier::Bronze, experts_only);
+                }
+            }
+
+            selected_jurors
+        }
+
+        pub(crate) fn internal_on_work_started(
+            client: &T::AccountId,
+            freelancer: &T::AccountId,
+        ) -> DispatchResult {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            Collaborations::<T>::insert(client, freelancer, current_block);
+            Collaborations::<T>::insert(freelancer, client, current_block);
+            Ok(())
+        }
+
+        pub(crate) fn internal_has_conflict_of_interest(
+            juror: &T::AccountId,
+            parties: &[T::AccountId],
+        ) -> bool {
+            let since = Self::conflict_window_start();
+            let party_projects = Self::recent_projects_of(parties, since);
+            Self::conflicts_with(juror, parties, &party_projects, since)
+        }
+
+        /// First block of the conflict-of-interest window.
+        fn conflict_window_start() -> BlockNumberFor<T> {
+            <frame_system::Pallet<T>>::block_number().saturating_sub(T::ConflictWindow::get())
+        }
+
+        /// Random offset below `total` for a draw from `tier`'s pool for `project_id`.
+        fn draw_offset(project_id: T::ProjectId, tier: JurorTier, total: BalanceOf<T>) -> BalanceOf<T> {
+            let (seed, _) = T::Randomness::random(&(b"jury_draw", project_id, tier).encode());
+            BalanceOf::<T>::decode(&mut TrailingZeroInput::new(seed.as_ref())).unwrap_or_default() % total
+        }
+
+        /// Projects the parties worked on, according to party attestations issued since `since`.
+        fn recent_projects_of(parties: &[T::AccountId], since: BlockNumberFor<T>) -> Vec<T::ProjectId> {
+            parties.iter()
+                .flat_map(|party| Self::recent_projects(party))
+                .filter(|(_, block)| *block >= since)
+                .map(|(project_id, _)| project_id)
+                .collect()
+        }
+
+        /// Remember that `account` worked on `project_id` at `block`, dropping projects that left
+        /// the conflict window and, if the list is still full, the oldest one.
+        pub(crate) fn record_recent_project(account: &T::AccountId, project_id: T::ProjectId, block: BlockNumberFor<T>) {
+            let since = Self::conflict_window_start();
+            if block < since {
+                return;
+            }
+            RecentProjects::<T>::mutate(account, |recent| {
+                recent.retain(|(id, at)| *at >= since && (*id != project_id || *at > block));
+                if recent.iter().any(|(id, _)| *id == project_id) {
+                    return;
+                }
+                if recent.is_full() {
+                    if recent.first().is_none_or(|(_, oldest)| *oldest > block) {
+                        return;
+                    }
+                    recent.remove(0);
+                }
+                let position = recent.partition_point(|(_, at)| *at <= block);
+                // Cannot fail: the list has room after the removal above
+                let _ = recent.try_insert(position, (project_id, block));
+            });
+        }
+
+        /// Remove a bounded number of collaborations older than `ConflictWindow`, which no longer
+        /// disqualify jurors. Sweeps the map continuously, resuming from `CollaborationCursor`.
+        pub(crate) fn prune_collaborations(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let prune_weight = db_weight.reads_writes(1, 1);
+            let mut used = db_weight.reads_writes(1, 1);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
             }
-            if selected_jurors.len() < required_count && min_tier <= JurorTier::Silver {
-                select_from_pool(&mut selected_jurors, JurorTier::Silver, &Self::silver_jurors());
+
+            let since = Self::conflict_window_start();
+            let mut cursor = CollaborationCursor::<T>::get();
+            let mut pairs = match &cursor {
+                Some((account, other)) => Collaborations::<T>::iter_from(Collaborations::<T>::hashed_key_for(account, other)),
+                None => Collaborations::<T>::iter(),
+            };
+            let mut expired = Vec::new();
+            let mut checked = 0u32;
+            while checked < T::MaxRescoresPerBlock::get()
+                && !remaining_weight.any_lt(used.saturating_add(prune_weight))
+            {
+                match pairs.next() {
+                    Some((account, other, block)) => {
+                        if block < since {
+                            expired.push((account.clone(), other.clone()));
+                        }
+                        cursor = Some((account, other));
+                    },
+                    // Reached the end of the map; the next call starts a new pass.
+                    None => {
+                        cursor = None;
+                        break;
+                    },
+                }
+                used = used.saturating_add(prune_weight);
+                checked += 1;
+            }
+            for (account, other) in expired {
+                Collaborations::<T>::remove(account, other);
             }
-            if selected_jurors.len() < required_count && min_tier <= JurorTier::Bronze {
-                select_from_pool(&mut selected_jurors, JurorTier::Bronze, &Self::bronze_jurors());
+            match cursor {
+                Some(pair) => CollaborationCursor::<T>::put(pair),
+                None => CollaborationCursor::<T>::kill(),
             }
+            used
+        }
 
-            selected_jurors
+        /// Whether `account` holds a party attestation for `project_id`.
+        fn worked_on(account: &T::AccountId, project_id: &T::ProjectId) -> bool {
+            ProjectAttestations::<T>::iter_prefix_values((account, project_id))
+                .any(|attestor| attestor.is_party())
+        }
+
+        /// A juror conflicts with the parties if they were paired with one of them, or if both
+        /// hold an attestation for the same recent project.
+        fn conflicts_with(
+            juror: &T::AccountId,
+            parties: &[T::AccountId],
+            party_projects: &[T::ProjectId],
+            since: BlockNumberFor<T>,
+        ) -> bool {
+            let paired = parties.iter().any(|party| {
+                Self::collaborations(juror, party).is_some_and(|block| block >= since)
+            });
+            paired || party_projects.iter().any(|project_id| Self::worked_on(juror, project_id))
         }
 
+        /// Slash `SlashRatio` of the juror's bonded and unbonding stake, so leaving the jury
+        /// does not escape penalties for recent rounds. Bonded stake is taken first, then the
+        /// most recent unbonding chunks. Delegators lose the same share of their delegation,
+        /// and of the stake they undelegated from the juror that is still unbonding. Only what
+        /// `slash_reserved` actually took is deducted.
         pub(crate) fn internal_slash_juror(juror: &T::AccountId) -> DispatchResult {
-            ensure!(Self::juror_opted_in(&juror), Error::<T>::NotRegisteredAsJuror);
-            let stake = StakeOf::<T>::get(&juror).ok_or(Error::<T>::NotRegisteredAsJuror)?;
-            let slash = T::SlashRatio::get() * stake;
-            let _ = T::Currency::slash_reserved(&juror, slash);
-            let new_stake = stake.saturating_sub(slash);
-            if new_stake.is_zero() {
-                // auto-kick if stake depleted
-                StakeOf::<T>::remove(&juror);
-                Self::remove_juror_from_juror_registry(juror)?;
-            } else {
-                StakeOf::<T>::insert(&juror, new_stake);
+            let stake = Self::stake_of(juror).unwrap_or_default();
+            let unbonding = Self::unlocking(juror)
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |sum, chunk| sum.saturating_add(chunk.amount));
+            let total = stake.saturating_add(unbonding);
+            Self::prune_unbonding_delegations(juror);
+            ensure!(
+                !total.is_zero()
+                    || !Self::delegated_stake(juror).is_zero()
+                    || UnbondingDelegations::<T>::iter_prefix(juror).next().is_some(),
+                Error::<T>::NotRegisteredAsJuror
+            );
+
+            let delegations: Vec<_> = Delegations::<T>::iter_prefix(juror).collect();
+            for (delegator, amount) in delegations {
+                let slash = T::SlashRatio::get() * amount;
+                if slash.is_zero() {
+                    continue;
+                }
+                let slashed = T::Currency::slash_reserved(&delegator, slash).0.peek();
+                if slashed.is_zero() {
+                    continue;
+                }
+                Self::remove_delegation(juror, &delegator, slashed);
+                Self::deposit_event(Event::DelegatorSlashed { delegator, juror: juror.clone(), amount: slashed });
             }
+
+            let unbonding_delegations: Vec<_> = UnbondingDelegations::<T>::iter_prefix(juror).collect();
+            for (delegator, chunk) in unbonding_delegations {
+                let slash = T::SlashRatio::get() * chunk.amount;
+                if slash.is_zero() {
+                    continue;
+                }
+                let slashed = T::Currency::slash_reserved(&delegator, slash).0.peek();
+                if slashed.is_zero() {
+                    continue;
+                }
+                Self::slash_unlocking(&delegator, slashed);
+                Self::update_delegator_slot(juror, &delegator, || {
+                    let amount = chunk.amount.saturating_sub(slashed);
+                    if amount.is_zero() {
+                        UnbondingDelegations::<T>::remove(juror, &delegator);
+                    } else {
+                        UnbondingDelegations::<T>::insert(juror, &delegator, UnlockChunk { amount, ..chunk });
+                    }
+                });
+                Self::deposit_event(Event::DelegatorSlashed { delegator, juror: juror.clone(), amount: slashed });
+            }
+
+            let slash = T::Currency::slash_reserved(juror, T::SlashRatio::get() * total).0.peek();
+
+            let from_stake = slash.min(stake);
+            if !from_stake.is_zero() {
+                let new_stake = stake.saturating_sub(from_stake);
+                if new_stake.is_zero() {
+                    // auto-kick if stake depleted
+                    Self::remove_juror_from_juror_registry(juror)?;
+                    StakeOf::<T>::remove(juror);
+                } else {
+                    Self::set_juror_stake(juror, new_stake);
+                    Self::update_juror_tier(juror)?;
+                }
+            }
+
+            Self::slash_unlocking(juror, slash.saturating_sub(from_stake));
+
             Self::deposit_event(Event::JurorSlashed { account: juror.clone(), amount: slash });
             Ok(())
         }
 
+        /// Take `amount` out of the account's unbonding chunks, most recent first.
+        fn slash_unlocking(account: &T::AccountId, amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return;
+            }
+            let mut chunks = Self::unlocking(account);
+            let mut rest = amount;
+            for chunk in chunks.iter_mut().rev() {
+                let taken = rest.min(chunk.amount);
+                chunk.amount = chunk.amount.saturating_sub(taken);
+                rest = rest.saturating_sub(taken);
+            }
+            chunks.retain(|chunk| !chunk.amount.is_zero());
+            if chunks.is_empty() {
+                Unlocking::<T>::remove(account);
+            } else {
+                Unlocking::<T>::insert(account, chunks);
+            }
+        }
+
+        /// Update the stake of a juror, keeping its draw weight in the pool in sync.
+        fn set_juror_stake(account: &T::AccountId, stake: BalanceOf<T>) {
+            Self::update_backing(account, || StakeOf::<T>::insert(account, stake));
+        }
+
+        /// Run `f`, which changes the stake backing `account`, and move the juror's draw
+        /// weight in the pool along.
+        fn update_backing(account: &T::AccountId, f: impl FnOnce()) {
+            let tier = Self::juror_tier(account);
+            let index = Self::juror_pool_index(account).filter(|_| Self::in_juror_pool(tier, account));
+            if let Some(index) = index {
+                Self::sub_pool_weight(tier, index, Self::draw_weight(account));
+            }
+            f();
+            if let Some(index) = index {
+                Self::add_pool_weight(tier, index, Self::draw_weight(account));
+            }
+        }
+
+        fn remove_delegation(juror: &T::AccountId, delegator: &T::AccountId, amount: BalanceOf<T>) {
+            Self::update_delegator_slot(juror, delegator, || {
+                Self::update_backing(juror, || {
+                    let remaining = Self::delegation(juror, delegator).saturating_sub(amount);
+                    if remaining.is_zero() {
+                        Delegations::<T>::remove(juror, delegator);
+                    } else {
+                        Delegations::<T>::insert(juror, delegator, remaining);
+                    }
+                    DelegatedStake::<T>::mutate(juror, |total| *total = total.saturating_sub(amount));
+                })
+            });
+        }
+
+        /// Move `amount` of a delegation into unbonding, recording which juror it backed so it
+        /// can still be slashed with it.
+        fn unbond_delegation(juror: &T::AccountId, delegator: &T::AccountId, amount: BalanceOf<T>) {
+            let unlock_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondingPeriod::get());
+            Self::update_delegator_slot(juror, delegator, || {
+                UnbondingDelegations::<T>::mutate(juror, delegator, |chunk| {
+                    let unbonding = chunk.as_ref().map(|chunk| chunk.amount).unwrap_or_default();
+                    *chunk = Some(UnlockChunk { amount: unbonding.saturating_add(amount), unlock_at });
+                });
+            });
+            Self::remove_delegation(juror, delegator, amount);
+            Self::start_unbonding(delegator, amount);
+        }
+
+        /// Forget undelegated stake that has finished unbonding, freeing its delegator slot.
+        fn prune_unbonding_delegations(juror: &T::AccountId) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let unlocked: Vec<_> = UnbondingDelegations::<T>::iter_prefix(juror)
+                .filter(|(_, chunk)| chunk.unlock_at <= now)
+                .map(|(delegator, _)| delegator)
+                .collect();
+            for delegator in unlocked {
+                Self::update_delegator_slot(juror, &delegator, || UnbondingDelegations::<T>::remove(juror, &delegator));
+            }
+        }
+
+        /// Whether `delegator` takes one of the juror's `MaxDelegators` slots: it delegates to
+        /// the juror, or is still unbonding stake undelegated from it.
+        fn holds_delegator_slot(juror: &T::AccountId, delegator: &T::AccountId) -> bool {
+            Delegations::<T>::contains_key(juror, delegator) || UnbondingDelegations::<T>::contains_key(juror, delegator)
+        }
+
+        /// Run `f`, which changes what `delegator` has at stake with `juror`, and keep
+        /// `DelegatorCount` in sync.
+        fn update_delegator_slot(juror: &T::AccountId, delegator: &T::AccountId, f: impl FnOnce()) {
+            let held = Self::holds_delegator_slot(juror, delegator);
+            f();
+            match (held, Self::holds_delegator_slot(juror, delegator)) {
+                (false, true) => DelegatorCount::<T>::mutate(juror, |count| *count = count.saturating_add(1)),
+                (true, false) => DelegatorCount::<T>::mutate(juror, |count| *count = count.saturating_sub(1)),
+                _ => {},
+            }
+        }
+
+        /// Start unbonding every delegation of a juror leaving the jury. Call after the juror
+        /// left its pool.
+        fn release_delegations(juror: &T::AccountId) {
+            let delegations: Vec<_> = Delegations::<T>::iter_prefix(juror).collect();
+            for (delegator, amount) in delegations {
+                Self::unbond_delegation(juror, &delegator, amount);
+                Self::deposit_event(Event::Undelegated { delegator, juror: juror.clone(), amount });
+            }
+            DelegatedStake::<T>::remove(juror);
+        }
+
+        /// Split `reward` by the juror's commission, then by each delegator's share of the
+        /// stake backing the juror. Rounding dust goes to the juror, listed first.
+        pub fn reward_shares(juror: &T::AccountId, reward: BalanceOf<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let backing = Self::stake_of(juror).unwrap_or_default().saturating_add(Self::delegated_stake(juror));
+            let after_commission = reward.saturating_sub(Self::commission(juror) * reward);
+            let mut juror_share = reward;
+            let mut shares = Vec::new();
+            if !backing.is_zero() {
+                for (delegator, amount) in Delegations::<T>::iter_prefix(juror) {
+                    let share = Perbill::from_rational(amount, backing) * after_commission;
+                    juror_share = juror_share.saturating_sub(share);
+                    shares.push((delegator, share));
+                }
+            }
+            shares.insert(0, (juror.clone(), juror_share));
+            shares
+        }
+
+        /// Keep `amount` reserved until `UnbondingPeriod` has passed.
+        fn start_unbonding(account: &T::AccountId, amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return;
+            }
+            let unlock_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondingPeriod::get());
+            Unlocking::<T>::mutate(account, |chunks| {
+                let chunk = UnlockChunk { amount, unlock_at };
+                if let Err(chunk) = chunks.try_push(chunk) {
+                    // Out of chunks: merge into the last one, which now unlocks later
+                    if let Some(last) = chunks.last_mut() {
+                        last.amount = last.amount.saturating_add(chunk.amount);
+                        last.unlock_at = unlock_at;
+                    }
+                }
+            });
+            Self::deposit_event(Event::JurorUnbonded { account: account.clone(), amount, unlock_at });
+        }
+
+        /// The tier `account` qualifies for. Jurors are measured by their reserved stake,
+        /// everyone else by the stake they would reserve when registering.
+        pub fn calculate_tier(account: &T::AccountId) -> JurorTier {
+            let score = Self::calculate_reputation_score(account).unwrap_or(0);
+            let stake = Self::stake_of(account).unwrap_or_else(Self::juror_stake);
+            Self::calculate_tier_from_stats(&Self::reputation_stats(account), score, stake)
+        }
+
         pub fn calculate_tier_from_stats(
             stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
+            score: u32,
+            stake: BalanceOf<T>,
         ) -> JurorTier {
-            const GOLD_PROJECTS: u32 = 50;
-            const GOLD_EARNED: u128 = 50000;
-            const SILVER_PROJECTS: u32 = 20;
-            const SILVER_EARNED: u128 = 10000;
-            const BRONZE_PROJECTS: u32 = 5;
-            const BRONZE_EARNED: u128 = 1000;
-
-            // More nuanced eligibility: allow jurors with good performance history
-            // but penalize those with high dispute loss rates
+            let thresholds = Self::tier_thresholds();
+
+            // Allow jurors with a few lost disputes, but not those who lose most of them
             let total_disputes = stats.disputes_won.saturating_add(stats.disputes_lost);
-            if total_disputes > 0 {
-                // Only disqualify if dispute loss rate is too high (e.g., > 50% and more than 2 disputes)
-                let loss_rate = (stats.disputes_lost.saturating_mul(1000)) / total_disputes;
-                if loss_rate > 500 && total_disputes > 2 {  // 50% loss rate with more than 2 disputes
+            if total_disputes > 0 && total_disputes >= thresholds.loss_rate_min_disputes {
+                let loss_rate = Permill::from_rational(stats.disputes_lost, total_disputes);
+                if loss_rate > thresholds.max_dispute_loss_rate {
                     return JurorTier::Ineligible;
                 }
             }
 
-            let earned_as_u128: u128 = stats.total_earned.try_into().unwrap_or(0);
+            // Only delivered work counts; client activity never qualifies a juror.
+            let freelancer = &stats.freelancer;
+            let juror = &stats.juror;
+            let meets = |requirement: &TierRequirement<BalanceOf<T>>| {
+                freelancer.projects_completed >= requirement.min_projects
+                    && freelancer.total_earned >= requirement.min_earned
+                    && score >= requirement.min_score
+                    && juror.jury_participation >= requirement.min_jury_votes
+                    && (juror.jury_participation == 0 || juror.jury_accuracy >= requirement.min_jury_accuracy)
+                    && stake >= requirement.min_stake
+            };
 
-            if stats.projects_completed >= GOLD_PROJECTS && earned_as_u128 >= GOLD_EARNED {
+            if meets(&thresholds.gold) {
                 JurorTier::Gold
-            } else if stats.projects_completed >= SILVER_PROJECTS && earned_as_u128 >= SILVER_EARNED {
+            } else if meets(&thresholds.silver) {
                 JurorTier::Silver
-            } else if stats.projects_completed >= BRONZE_PROJECTS && earned_as_u128 >= BRONZE_EARNED {
+            } else if meets(&thresholds.bronze) {
                 JurorTier::Bronze
             } else {
                 JurorTier::Ineligible
@@ -718,11 +2028,12 @@ pub mod pallet {
         ) -> DispatchResult {
             ReputationStats::<T>::try_mutate(client, |stats| -> DispatchResult {
                 ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
-                stats.projects_posted = stats.projects_posted.saturating_add(1);
-                stats.total_spent = stats.total_spent.saturating_add(budget);
+                stats.client.projects_posted = stats.client.projects_posted.saturating_add(1);
+                stats.client.total_spent = stats.client.total_spent.saturating_add(budget);
                 stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
                 Ok(())
             })?;
+            Self::refresh_score(client);
             Ok(())
         }
 
@@ -731,10 +2042,11 @@ pub mod pallet {
         ) -> DispatchResult {
             ReputationStats::<T>::try_mutate(client, |stats| -> DispatchResult {
                 ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
-                stats.projects_failed = stats.projects_failed.saturating_add(1);
+                stats.client.projects_cancelled = stats.client.projects_cancelled.saturating_add(1);
                 stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
                 Ok(())
             })?;
+            Self::refresh_score(client);
             Ok(())
         }
 
@@ -745,38 +2057,409 @@ pub mod pallet {
             ReputationStats::<T>::try_mutate(client, |stats| -> DispatchResult {
                 ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
                 // Increment projects where client accepted work
-                stats.projects_completed = stats.projects_completed.saturating_add(1);
+                stats.client.projects_completed = stats.client.projects_completed.saturating_add(1);
                 stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
                 Ok(())
             })?;
+            Self::refresh_score(client);
+            Ok(())
+        }
+
+        pub(crate) fn internal_on_client_rated(
+            client: &T::AccountId,
+            project_value: BalanceOf<T>,
+            freelancer_rating: u32,
+            project_id: T::ProjectId,
+        ) -> DispatchResult {
+            ensure!(freelancer_rating <= 5000, Error::<T>::InvalidScore);
+
+            ReputationStats::<T>::try_mutate(client, |stats| -> DispatchResult {
+                ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
+                let client_stats = &mut stats.client;
+                let total = client_stats.total_ratings_received;
+                client_stats.avg_rating_received = (client_stats.avg_rating_received
+                    .saturating_mul(total)
+                    .saturating_add(freelancer_rating))
+                    / (total.saturating_add(1));
+                client_stats.total_ratings_received = total.saturating_add(1);
+                stats.last_activity_block = <frame_system::Pallet<T>>::block_number();
+                Ok(())
+            })?;
+            Self::refresh_score(client);
+
+            let outcome = match freelancer_rating {
+                0..=1999 => AttestationOutcome::Negative,
+                2000..=2999 => AttestationOutcome::Neutral,
+                _ => AttestationOutcome::Positive,
+            };
+            Self::create_attestation(
+                client,
+                project_id,
+                AttestorType::FreelancerRating,
+                outcome,
+                project_value,
+                Some(freelancer_rating),
+            );
+
+            Self::deposit_event(Event::ClientRated { 
+                client: client.clone(), 
+                project_id 
+            });
+            Ok(())
+        }
+
+        /// Re-score an account and move it to its new histogram bucket.
+        pub(crate) fn refresh_score(account: &T::AccountId) {
+            let Ok(score) = Self::calculate_reputation_score(account) else { return };
+            let old_score = LastScore::<T>::get(account);
+            if old_score == Some(score) {
+                return;
+            }
+            let mut histogram = Self::histogram_buckets();
+            if let Some(old) = old_score {
+                let bucket = &mut histogram[Self::score_bucket(old)];
+                *bucket = bucket.saturating_sub(1);
+            }
+            let bucket = &mut histogram[Self::score_bucket(score)];
+            *bucket = bucket.saturating_add(1);
+            ScoreHistogram::<T>::put(BoundedVec::truncate_from(histogram));
+            ScoreSum::<T>::mutate(|sum| {
+                *sum = sum.saturating_sub(u64::from(old_score.unwrap_or(0))).saturating_add(u64::from(score));
+            });
+            LastScore::<T>::insert(account, score);
+            GlobalStatsDirty::<T>::put(true);
+        }
+
+        /// Re-score a bounded number of accounts (scores drift as history decays) and
+        /// recompute `GlobalStats` from the histogram if anything changed.
+        pub(crate) fn refresh_global_stats(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let rescore_weight = db_weight.reads_writes(6, 5);
+            let stats_weight = db_weight.reads_writes(3, 2);
+            let mut used = db_weight.reads_writes(1, 1);
+            if remaining_weight.any_lt(used.saturating_add(stats_weight)) {
+                return Weight::zero();
+            }
+
+            let mut accounts = match RescoreCursor::<T>::get() {
+                Some(last) => ReputationStats::<T>::iter_keys_from(ReputationStats::<T>::hashed_key_for(&last)),
+                None => ReputationStats::<T>::iter_keys(),
+            };
+            let mut rescored = 0u32;
+            let mut cursor = RescoreCursor::<T>::get();
+            while rescored < T::MaxRescoresPerBlock::get()
+                && !remaining_weight.any_lt(used.saturating_add(rescore_weight).saturating_add(stats_weight))
+            {
+                match accounts.next() {
+                    Some(account) => {
+                        Self::refresh_score(&account);
+                        cursor = Some(account);
+                    },
+                    // Reached the end of the map; the next call starts a new pass.
+                    None => {
+                        cursor = None;
+                        break;
+                    },
+                }
+                used = used.saturating_add(rescore_weight);
+                rescored += 1;
+            }
+            match cursor {
+                Some(account) => RescoreCursor::<T>::put(account),
+                None => RescoreCursor::<T>::kill(),
+            }
+
+            if GlobalStatsDirty::<T>::take() {
+                GlobalStats::<T>::put(Self::stats_from_histogram(&Self::histogram_buckets(), Self::score_sum()));
+                Self::deposit_event(Event::GlobalStatsUpdated);
+            }
+            used.saturating_add(stats_weight)
+        }
+
+        /// Re-tier a bounded number of registered jurors after the tier thresholds changed.
+        pub(crate) fn refresh_juror_tiers(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let retier_weight = db_weight.reads_writes(12, 8);
+            let mut used = db_weight.reads_writes(2, 2);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+            if !RetierPending::<T>::get() {
+                return db_weight.reads(1);
+            }
+
+            let mut jurors = match RetierCursor::<T>::get() {
+                Some(last) => JurorRegistry::<T>::iter_keys_from(JurorRegistry::<T>::hashed_key_for(&last)),
+                None => JurorRegistry::<T>::iter_keys(),
+            };
+            let mut retiered = 0u32;
+            let mut cursor = RetierCursor::<T>::get();
+            let mut finished = false;
+            while retiered < T::MaxRescoresPerBlock::get()
+                && !remaining_weight.any_lt(used.saturating_add(retier_weight))
+            {
+                match jurors.next() {
+                    Some(juror) => {
+                        // If the new tier's pool is full the juror keeps its old tier
+                        let _ = frame_support::storage::with_storage_layer(|| Self::update_juror_tier(&juror));
+                        cursor = Some(juror);
+                    },
+                    None => {
+                        finished = true;
+                        break;
+                    },
+                }
+                used = used.saturating_add(retier_weight);
+                retiered += 1;
+            }
+            if finished {
+                RetierCursor::<T>::kill();
+                RetierPending::<T>::kill();
+            } else if let Some(juror) = cursor {
+                RetierCursor::<T>::put(juror);
+            }
+            used
+        }
+
+        /// Aggregate statistics from the histogram. Median, min and max have bucket resolution.
+        fn stats_from_histogram(histogram: &[u32], score_sum: u64) -> GlobalReputationStats {
+            let total_users: u32 = histogram.iter().fold(0u32, |acc, count| acc.saturating_add(*count));
+            if total_users == 0 {
+                return GlobalReputationStats::default();
+            }
+            let bucket_floor = |bucket: usize| (bucket as u32).saturating_mul(SCORE_BUCKET_WIDTH);
+            let min_score = histogram.iter().position(|count| *count > 0).map(bucket_floor).unwrap_or(0);
+            let max_score = histogram.iter().rposition(|count| *count > 0)
+                .map(|bucket| bucket_floor(bucket).saturating_add(SCORE_BUCKET_WIDTH - 1).min(10000))
+                .unwrap_or(0);
+            let median_rank = total_users.saturating_add(1) / 2;
+            let mut seen = 0u32;
+            let mut median_score = 0u32;
+            for (bucket, count) in histogram.iter().enumerate() {
+                seen = seen.saturating_add(*count);
+                if seen >= median_rank {
+                    median_score = bucket_floor(bucket);
+                    break;
+                }
+            }
+            GlobalReputationStats {
+                total_users,
+                average_score: (score_sum / u64::from(total_users)) as u32,
+                median_score,
+                max_score,
+                min_score,
+            }
+        }
+
+        /// Share of scored users whose score is in the same bucket as `account` or lower
+        /// (useful for "top 10%" badges).
+        pub fn percentile_of(account: &T::AccountId) -> Option<Percent> {
+            let score = Self::last_score(account)?;
+            let histogram = Self::histogram_buckets();
+            let total: u64 = histogram.iter().map(|count| u64::from(*count)).sum();
+            if total == 0 {
+                return None;
+            }
+            let at_or_below: u64 = histogram[..=Self::score_bucket(score)].iter().map(|count| u64::from(*count)).sum();
+            Some(Percent::from_rational(at_or_below, total))
+        }
+
+        fn histogram_buckets() -> Vec<u32> {
+            let mut histogram = Self::score_histogram().into_inner();
+            histogram.resize(SCORE_BUCKETS as usize, 0);
+            histogram
+        }
+
+        /// Whether `account` completed a project in any of `skills`.
+        fn knows_any_skill(account: &T::AccountId, skills: &[Skill]) -> bool {
+            skills.iter().any(|skill| Self::skill_stats(account, skill).projects_completed > 0)
+        }
+
+        /// Score (0-10000) of a single skill: experience, rating quality and dispute record.
+        pub fn calculate_skill_score(stats: &SkillStats) -> u32 {
+            let experience = stats.projects_completed.min(20).saturating_mul(250);
+            let quality = stats.avg_rating.saturating_mul(stats.projects_completed.min(5)) / 5;
+            let disputes = stats.disputes_won.saturating_mul(250);
+            experience
+                .saturating_add(quality)
+                .saturating_add(disputes)
+                .saturating_sub(stats.disputes_lost.saturating_mul(500))
+                .min(10000)
+        }
+
+        pub(crate) fn internal_skill_score(account: &T::AccountId, skills: &[Skill]) -> u32 {
+            if skills.is_empty() {
+                return 0;
+            }
+            let total = skills.iter()
+                .map(|skill| Self::calculate_skill_score(&Self::skill_stats(account, skill)))
+                .fold(0u32, |acc, score| acc.saturating_add(score));
+            total / skills.len() as u32
+        }
+
+        /// Current reputation of a registered account, as shared with other chains.
+        pub fn reputation_summary(
+            account: &T::AccountId,
+        ) -> Result<ReputationSummary<T::AccountId, BlockNumberFor<T>>, Error<T>> {
+            let score = Self::calculate_reputation_score(account)?;
+            let stats = Self::reputation_stats(account);
+            Ok(ReputationSummary {
+                account: account.clone(),
+                score,
+                tier: Self::calculate_tier(account),
+                projects_completed: stats.freelancer.projects_completed,
+                as_of: <frame_system::Pallet<T>>::block_number(),
+            })
+        }
+
+        /// Building a summary reads the account's stats, score inputs and tier inputs; sending
+        /// it writes to the outbound message queue.
+        pub(crate) fn send_summary_weight() -> Weight {
+            T::DbWeight::get().reads_writes(12, 6)
+        }
+
+        /// Count a call against the partner's `MaxPartnerCalls`, starting a new period if the
+        /// last one is over.
+        fn use_partner_quota(para_id: u32) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            PartnerCalls::<T>::try_mutate(para_id, |(period_start, calls)| {
+                if now >= period_start.saturating_add(T::PartnerQuotaPeriod::get()) {
+                    *period_start = now;
+                    *calls = 0;
+                }
+                ensure!(*calls < T::MaxPartnerCalls::get(), Error::<T>::PartnerQuotaExceeded);
+                *calls += 1;
+                Ok(())
+            })
+        }
+
+        fn send_summary(account: T::AccountId, para_id: u32) -> DispatchResult {
+            let callback = Self::partner_chain(para_id).ok_or(Error::<T>::UnknownPartnerChain)?;
+            let summary = Self::reputation_summary(&account)?;
+            T::ReputationSender::send_summary(para_id, callback, summary)?;
+            Self::deposit_event(Event::ReputationSent { account, para_id });
             Ok(())
         }
 
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Mint a soulbound credential mirroring the project's attestation. Best effort: a
+        /// missing collection or a failed mint never blocks the completion itself.
+        fn mint_project_credential(account: &T::AccountId, project_id: T::ProjectId, nonce: u32) {
+            if ProjectCredentials::<T>::contains_key(account, project_id) {
+                return;
+            }
+            let Some(attestation) = Self::attestations(account, nonce) else { return };
+            let attributes = [
+                (&b"attestor"[..], attestation.attestor.encode()),
+                (&b"project_id"[..], attestation.project_id.encode()),
+                (&b"outcome"[..], attestation.outcome.encode()),
+                (&b"value"[..], attestation.value.encode()),
+                (&b"timestamp"[..], attestation.timestamp.encode()),
+                (&b"rating"[..], attestation.rating.encode()),
+                (&b"metadata"[..], attestation.metadata.to_vec()),
+            ];
+            if let Some(item) = Self::mint_credential(account, &attributes) {
+                ProjectCredentials::<T>::insert(account, project_id, item);
+            }
+        }
+
+        fn burn_project_credential(account: &T::AccountId, project_id: T::ProjectId) -> DispatchResult {
+            let collection = Self::credential_collection().ok_or(Error::<T>::NoNFTFound)?;
+            let item = ProjectCredentials::<T>::take(account, project_id).ok_or(Error::<T>::NoNFTFound)?;
+
+            <T::Nfts as nonfungibles_v2::Mutate<_, _>>::burn(&collection, &item, None)?;
+            Self::deposit_event(Event::CredentialRevoked { account: account.clone(), item });
+            Ok(())
+        }
+
+        /// Mint a soulbound credential the first time an account reaches `tier`.
+        fn mint_tier_credential(account: &T::AccountId, tier: JurorTier) {
+            if tier == JurorTier::Ineligible || TierCredentials::<T>::contains_key(account, tier) {
+                return;
+            }
+            let now = <frame_system::Pallet<T>>::block_number();
+            let attributes = [
+                (&b"tier"[..], tier.encode()),
+                (&b"timestamp"[..], now.encode()),
+            ];
+            if let Some(item) = Self::mint_credential(account, &attributes) {
+                TierCredentials::<T>::insert(account, tier, item);
+            }
+        }
+
+        fn mint_credential(account: &T::AccountId, attributes: &[(&[u8], Vec<u8>)]) -> Option<T::ItemId> {
+            let collection = Self::credential_collection()?;
+            let item = T::ItemId::from(Self::next_credential_id());
+
+            let minted = frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                <T::Nfts as nonfungibles_v2::Mutate<_, _>>::mint_into(
+                    &collection,
+                    &item,
+                    account,
+                    &T::CredentialItemConfig::get(),
+                    true,
+                )?;
+                for (key, value) in attributes {
+                    <T::Nfts as nonfungibles_v2::Mutate<_, _>>::set_attribute(&collection, &item, key, value)?;
+                }
+                Ok(())
+            });
+            if minted.is_err() {
+                return None;
+            }
+
+            NextCredentialId::<T>::mutate(|id| *id = id.saturating_add(1));
+            Self::deposit_event(Event::CredentialMinted { account: account.clone(), item });
+            Some(item)
+        }
+
+        fn score_bucket(score: u32) -> usize {
+            (score / SCORE_BUCKET_WIDTH).min(SCORE_BUCKETS - 1) as usize
+        }
+
+        /// Append an attestation to the account's history and return its nonce.
         fn create_attestation(
             account: &T::AccountId,
             project_id: T::ProjectId,
             attestor: AttestorType,
             outcome: AttestationOutcome,
             value: BalanceOf<T>,
-            rating: u32,
-        ) -> DispatchResult {
-            let metadata = BoundedVec::try_from(rating.encode())
-                .map_err(|_| Error::<T>::TooManySkills)?;
+            rating: Option<u32>,
+        ) -> u32 {
+            let nonce = AttestationNonce::<T>::mutate(account, |next| {
+                let nonce = *next;
+                *next = next.saturating_add(1);
+                nonce
+            });
             
             let attestation = Attestation {
-                attestor,
+                attestor: attestor.clone(),
                 project_id,
                 outcome,
                 value,
                 timestamp: <frame_system::Pallet<T>>::block_number(),
-                metadata,
+                rating,
+                metadata: BoundedVec::default(),
             };
             
-            Attestations::<T>::insert(account, project_id, attestation);
-            Ok(())
+            if attestor.is_party() {
+                Self::record_recent_project(account, project_id, attestation.timestamp);
+            }
+            Attestations::<T>::insert(account, nonce, attestation);
+            ProjectAttestations::<T>::insert((account, project_id, nonce), attestor.clone());
+            Self::deposit_event(Event::AttestationCreated { account: account.clone(), nonce, attestor });
+            nonce
         }
 
         pub fn calculate_reputation_score(account: &T::AccountId) -> Result<u32, Error<T>> {
+            Self::score_breakdown(account).map(|breakdown| breakdown.total)
+        }
+
+        /// Score components of a registered account; `total` is capped at 10000.
+        pub fn score_breakdown(account: &T::AccountId) -> Result<ScoreBreakdown, Error<T>> {
             let stats = Self::reputation_stats(account);
             ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
             
@@ -786,56 +2469,105 @@ pub mod pallet {
             // Component 1: Completion Rate Score (0-2500 points)
             let completion_score = Self::calculate_completion_score(&stats, weights.completion_weight);
             
+            // Ratings and dispute outcomes lose weight as they age
+            let decayed = Self::current_decayed_metrics(account, &stats, current_block, weights.time_decay_rate);
+            
             // Component 2: Rating Score (0-2500 points)
-            let rating_score = Self::calculate_rating_score(&stats);
+            let rating_score = Self::calculate_rating_score(&decayed);
             
             // Component 3: Volume Score (0-2000 points)
             let volume_score = Self::calculate_volume_score(&stats);
             
             // Component 4: Activity Score (0-1500 points)
-            let activity_score = Self::calculate_activity_score(&stats, current_block, weights.time_decay_rate);
+            let activity_score = Self::calculate_activity_score(&stats, current_block, weights.time_decay_rate, weights.activity_bonus);
             
             // Component 5: Dispute Score (0-1500 points, can be negative)
-            let dispute_score = Self::calculate_dispute_score(&stats, weights.dispute_penalty);
+            let dispute_score = Self::calculate_dispute_score(&decayed, weights.dispute_penalty);
+
+            // Component 6: Arbitration Bonus (0-500 points)
+            let arbitration_score = Self::calculate_arbitration_score(&decayed, weights.arbitration_bonus);
+
+            // Component 7: Jury Score (0-1000 points)
+            let jury_score = Self::calculate_jury_score(&stats, weights.jury_weight);
             
             // Sum all components
             let total_score = completion_score
                 .saturating_add(rating_score)
                 .saturating_add(volume_score)
                 .saturating_add(activity_score)
-                .saturating_add(dispute_score);
+                .saturating_add(dispute_score)
+                .saturating_add(arbitration_score)
+                .saturating_add(jury_score);
             
             // Cap at 10000
-            Ok(total_score.min(10000))
+            Ok(ScoreBreakdown {
+                completion: completion_score,
+                rating: rating_score,
+                volume: volume_score,
+                activity: activity_score,
+                dispute: dispute_score,
+                arbitration: arbitration_score,
+                jury: jury_score,
+                total: total_score.min(10000),
+            })
+        }
+
+        /// Up to `limit` (at most `MAX_ATTESTATION_PAGE`) attestations of `account`, oldest
+        /// first, starting at nonce `start`.
+        pub fn attestation_page(
+            account: &T::AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<AttestationRecord<T::ProjectId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let end = start
+                .saturating_add(limit.min(MAX_ATTESTATION_PAGE))
+                .min(Self::attestation_count(account));
+            (start..end)
+                .filter_map(|nonce| {
+                    Self::attestations(account, nonce).map(|attestation| AttestationRecord {
+                        nonce,
+                        attestor: attestation.attestor,
+                        project_id: attestation.project_id,
+                        outcome: attestation.outcome,
+                        value: attestation.value,
+                        timestamp: attestation.timestamp,
+                        rating: attestation.rating,
+                    })
+                })
+                .collect()
         }
 
         fn calculate_completion_score(
             stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
             weight: u32,
         ) -> u32 {
-            let total_projects = stats.projects_completed.saturating_add(stats.projects_failed);
+            let total_projects = stats.freelancer.projects_completed.saturating_add(stats.freelancer.projects_failed);
             
             if total_projects == 0 {
                 return 0;
             }
             
             // Completion rate as percentage (0-100)
-            let completion_rate = (stats.projects_completed.saturating_mul(100)) / total_projects;
+            let completion_rate = (stats.freelancer.projects_completed.saturating_mul(100)) / total_projects;
             
             // Scale to 0-2500 based on weight
             (completion_rate.saturating_mul(25).saturating_mul(weight)) / 100
         }
 
         fn calculate_rating_score(
-            stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
+            decayed: &DecayedMetrics<BlockNumberFor<T>>,
         ) -> u32 {
-            if stats.total_ratings_received == 0 {
+            if decayed.rating_weight == 0 {
                 return 0;
             }
             
-            // avg_rating_received is 0-5000 scale
+            // Time-weighted average on the 0-5000 scale, so recent ratings count more
+            let avg_rating = decayed.rating_sum / decayed.rating_weight;
             // Scale to 0-2500: (rating / 5000) * 2500 = rating / 2
-            stats.avg_rating_received / 2
+            let full_score = avg_rating / 2;
+            // Full credit needs RATING_CONFIDENCE recent ratings; aged-out ratings fade away
+            let confidence = decayed.rating_weight.min(RATING_CONFIDENCE);
+            (full_score.saturating_mul(confidence) / RATING_CONFIDENCE) as u32
         }
 
         fn calculate_volume_score(
@@ -843,17 +2575,18 @@ pub mod pallet {
         ) -> u32 {
             // Projects completed component (0-1000 points)
             // Logarithmic scaling: more projects = higher score, but diminishing returns
-            let project_score = match stats.projects_completed {
+            let projects_completed = stats.freelancer.projects_completed;
+            let project_score = match projects_completed {
                 0 => 0,
-                1..=5 => stats.projects_completed.saturating_mul(100),
-                6..=20 => 500 + ((stats.projects_completed - 5).saturating_mul(20)),
-                21..=50 => 800 + ((stats.projects_completed - 20).saturating_mul(5)),
+                1..=5 => projects_completed.saturating_mul(100),
+                6..=20 => 500 + ((projects_completed - 5).saturating_mul(20)),
+                21..=50 => 800 + ((projects_completed - 20).saturating_mul(5)),
                 _ => 1000,
             };
             
             // Value component (0-1000 points)
             // This would need to be scaled based on your token economics
-            let earned_u128: u128 = stats.total_earned.try_into().unwrap_or(0);
+            let earned_u128: u128 = stats.freelancer.total_earned.try_into().unwrap_or(0);
             let value_score = match earned_u128 {
                 0 => 0,
                 1..=10000 => (earned_u128 / 10) as u32,
@@ -868,6 +2601,7 @@ pub mod pallet {
             stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
             current_block: BlockNumberFor<T>,
             decay_rate: Permill,
+            activity_bonus: u32,
         ) -> u32 {
             // Account age bonus (0-750 points)
             let account_age = current_block.saturating_sub(stats.registration_block);
@@ -878,143 +2612,272 @@ pub mod pallet {
                 _ => 750,
             };
             
-            // Recent activity bonus (0-750 points)
-            let blocks_since_activity = current_block.saturating_sub(stats.last_activity_block);
-            let recency: u32 = blocks_since_activity.try_into().unwrap_or(u32::MAX);
+            // Recent activity bonus (0-750 points at activity_bonus = 100),
+            // decaying for every period without activity
+            let recency_factor = Self::decay_factor(stats.last_activity_block, current_block, decay_rate);
+            let recency_score = recency_factor * (activity_bonus.saturating_mul(750) / 100).min(750);
             
-            let recency_score = if recency < 10000 {
-                750
-            } else if recency < 50000 {
-                500
-            } else if recency < 100000 {
-                250
-            } else {
-                0
-            };
-            
-            // Apply time decay
-            let base_score = age_score.saturating_add(recency_score);
-            let decay_factor = decay_rate.deconstruct();
-            let decayed = (base_score.saturating_mul(1_000_000 - decay_factor)) / 1_000_000;
-            
-            decayed.min(1500)
+            age_score.saturating_add(recency_score).min(1500)
         }
 
         fn calculate_dispute_score(
-            stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
+            decayed: &DecayedMetrics<BlockNumberFor<T>>,
             penalty_weight: u32,
         ) -> u32 {
-            let total_disputes = stats.disputes_won.saturating_add(stats.disputes_lost);
+            let total_disputes = decayed.disputes_won.saturating_add(decayed.disputes_lost);
             
             if total_disputes == 0 {
                 return 1500; // Max points if no disputes
             }
             
             // Win rate percentage
-            let win_rate = (stats.disputes_won.saturating_mul(100)) / total_disputes;
+            let win_rate = (decayed.disputes_won.saturating_mul(100)) / total_disputes;
             
             // Base score: 0-1500 based on win rate
             let base_score = (win_rate.saturating_mul(15)).min(1500);
             
-            // Apply penalty for each lost dispute
-            let penalty = stats.disputes_lost.saturating_mul(penalty_weight);
+            // Apply penalty for each lost dispute, fading as the losses age
+            let penalty = decayed.disputes_lost.saturating_mul(u64::from(penalty_weight)) / EVENT_WEIGHT;
             
-            base_score.saturating_sub(penalty)
+            base_score.saturating_sub(penalty) as u32
+        }
+
+        fn calculate_arbitration_score(
+            decayed: &DecayedMetrics<BlockNumberFor<T>>,
+            bonus_weight: u32,
+        ) -> u32 {
+            // Bonus per (time-weighted) dispute won
+            let bonus = decayed.disputes_won.saturating_mul(u64::from(bonus_weight)) / EVENT_WEIGHT;
+            bonus.min(500) as u32
+        }
+
+        fn calculate_jury_score(
+            stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
+            jury_weight: u32,
+        ) -> u32 {
+            let juror = &stats.juror;
+            if juror.jury_participation == 0 {
+                return 0;
+            }
+            // Accuracy on a 0-1000 scale, reaching full credit after JURY_CONFIDENCE votes
+            let accuracy = juror.jury_accuracy.deconstruct() / 1000;
+            let participation = juror.jury_participation.min(JURY_CONFIDENCE);
+            let base_score = accuracy.saturating_mul(participation) / JURY_CONFIDENCE;
+            (base_score.saturating_mul(jury_weight) / 100).min(1000)
+        }
+
+        /// Share of weight left after decaying from `from` to `to` at `rate` per `DecayPeriod`.
+        fn decay_factor(from: BlockNumberFor<T>, to: BlockNumberFor<T>, rate: Permill) -> Permill {
+            let period = T::DecayPeriod::get();
+            if rate.is_zero() || period.is_zero() {
+                return Permill::one();
+            }
+            let periods: u32 = (to.saturating_sub(from) / period).try_into().unwrap_or(u32::MAX);
+            Permill::one().saturating_sub(rate).saturating_pow(periods as usize)
+        }
+
+        /// Decayed metrics of an account as of `now`. Accounts from before time-weighting
+        /// are seeded from their lifetime counters, dated at their last activity.
+        pub fn current_decayed_metrics(
+            account: &T::AccountId,
+            stats: &ReputationData<BalanceOf<T>, BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+            rate: Permill,
+        ) -> DecayedMetrics<BlockNumberFor<T>> {
+            let mut metrics = Self::decayed_stats(account);
+            if metrics.last_decay_block.is_zero() {
+                let ratings = u64::from(stats.freelancer.total_ratings_received).saturating_mul(EVENT_WEIGHT);
+                metrics = DecayedMetrics {
+                    rating_sum: u64::from(stats.freelancer.avg_rating_received).saturating_mul(ratings),
+                    rating_weight: ratings,
+                    disputes_won: u64::from(stats.disputes_won).saturating_mul(EVENT_WEIGHT),
+                    disputes_lost: u64::from(stats.disputes_lost).saturating_mul(EVENT_WEIGHT),
+                    last_decay_block: stats.last_activity_block,
+                };
+            }
+            let factor = Self::decay_factor(metrics.last_decay_block, now, rate);
+            DecayedMetrics {
+                rating_sum: factor.mul_floor(metrics.rating_sum),
+                rating_weight: factor.mul_floor(metrics.rating_weight),
+                disputes_won: factor.mul_floor(metrics.disputes_won),
+                disputes_lost: factor.mul_floor(metrics.disputes_lost),
+                last_decay_block: now,
+            }
+        }
+
+        /// Bring an account's decayed metrics up to date and add a new event to them.
+        fn record_decayed(account: &T::AccountId, add: impl FnOnce(&mut DecayedMetrics<BlockNumberFor<T>>)) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let rate = Self::reputation_weights().time_decay_rate;
+            let mut metrics = Self::current_decayed_metrics(account, &Self::reputation_stats(account), now, rate);
+            add(&mut metrics);
+            DecayedStats::<T>::insert(account, metrics);
         }
 
         pub fn calculate_client_reputation(account: &T::AccountId) -> Result<u32, Error<T>> {
             let stats = Self::reputation_stats(account);
             ensure!(stats.registration_block > BlockNumberFor::<T>::zero(), Error::<T>::UserNotRegistered);
             
-            // Component 1: Project posting reliability (0-3000)
-            let posting_score = if stats.projects_posted > 0 {
-                let completion_rate = (stats.projects_completed.saturating_mul(100)) 
-                    / stats.projects_posted;
-                completion_rate.saturating_mul(30)
+            // Component 1: Project posting reliability (0-2500)
+            let client = &stats.client;
+            let posting_score = if client.projects_posted > 0 {
+                let completion_rate = (client.projects_completed.saturating_mul(100)) 
+                    / client.projects_posted;
+                completion_rate.saturating_mul(25)
             } else {
                 0
             };
             
-            // Component 2: Payment history (0-3000)
-            let spent_u128: u128 = stats.total_spent.try_into().unwrap_or(0);
+            // Component 2: Payment history (0-2500)
+            let spent_u128: u128 = client.total_spent.try_into().unwrap_or(0);
             let payment_score = match spent_u128 {
                 0 => 0,
                 1..=50000 => (spent_u128 / 20) as u32,
-                _ => 3000,
+                _ => 2500,
             };
             
-            // Component 3: Cancellation penalty (0-2000, negative impact)
-            let cancel_rate = if stats.projects_posted > 0 {
-                (stats.projects_failed.saturating_mul(100)) / stats.projects_posted
+            // Component 3: Cancellation penalty (0-1500, negative impact)
+            let cancel_rate = if client.projects_posted > 0 {
+                (client.projects_cancelled.saturating_mul(100)) / client.projects_posted
             } else {
                 0
             };
-            let cancellation_score = 2000u32.saturating_sub(cancel_rate.saturating_mul(20));
+            let cancellation_score = 1500u32.saturating_sub(cancel_rate.saturating_mul(15));
             
-            // Component 4: Dispute handling (0-2000)
+            // Component 4: Dispute handling (0-1500)
             let dispute_score = if stats.disputes_initiated > 0 {
                 let win_rate = (stats.disputes_won.saturating_mul(100)) 
                     / stats.disputes_initiated;
-                win_rate.saturating_mul(20)
+                win_rate.saturating_mul(15)
             } else {
-                2000 // No disputes is good for clients
+                1500 // No disputes is good for clients
             };
+
+            // Component 5: Ratings from freelancers (0-2000), full credit after 5 ratings
+            let rating_score = client.avg_rating_received
+                .saturating_mul(2)
+                .saturating_mul(client.total_ratings_received.min(5))
+                / 25;
             
             let total = posting_score
                 .saturating_add(payment_score)
                 .saturating_add(cancellation_score)
-                .saturating_add(dispute_score);
+                .saturating_add(dispute_score)
+                .saturating_add(rating_score);
             
             Ok(total.min(10000))
         }
 
-        fn add_juror_to_tier_list(account: &T::AccountId, tier: JurorTier) -> DispatchResult {
+        fn pool_capacity(tier: JurorTier) -> u32 {
             match tier {
-                JurorTier::Gold => GoldJurors::<T>::try_mutate(|jurors| jurors.try_push(account.clone()))
-                    .map_err(|_| Error::<T>::JurorPoolFull)?,
-                JurorTier::Silver => SilverJurors::<T>::try_mutate(|jurors| jurors.try_push(account.clone()))
-                    .map_err(|_| Error::<T>::JurorPoolFull)?,
-                JurorTier::Bronze => BronzeJurors::<T>::try_mutate(|jurors| jurors.try_push(account.clone()))
-                    .map_err(|_| Error::<T>::JurorPoolFull)?,
-                JurorTier::Ineligible => {}, // Do nothing
+                JurorTier::Gold => T::MaxGoldJurors::get(),
+                JurorTier::Silver => T::MaxSilverJurors::get(),
+                JurorTier::Bronze => T::MaxBronzeJurors::get(),
+                JurorTier::Ineligible => 0,
+            }
+        }
+
+        /// Whether `account` is in the pool of `tier`.
+        pub fn in_juror_pool(tier: JurorTier, account: &T::AccountId) -> bool {
+            Self::juror_pool_index(account)
+                .and_then(|index| Self::juror_at(tier, index))
+                .is_some_and(|juror| &juror == account)
+        }
+
+        pub(crate) fn add_juror_to_tier_list(account: &T::AccountId, tier: JurorTier) -> DispatchResult {
+            if tier == JurorTier::Ineligible {
+                return Ok(());
             }
+            let size = Self::juror_pool_size(tier);
+            ensure!(size < Self::pool_capacity(tier), Error::<T>::JurorPoolFull);
+            JurorPools::<T>::insert(tier, size, account);
+            JurorPoolIndex::<T>::insert(account, size);
+            JurorPoolSize::<T>::insert(tier, size.saturating_add(1));
+            Self::add_pool_weight(tier, size, Self::draw_weight(account));
             Ok(())
         }
 
+        /// Swap-remove: the last juror of the pool moves into the freed index.
         fn remove_juror_from_tier_list(account: &T::AccountId, tier: JurorTier) -> DispatchResult {
-            match tier {
-                JurorTier::Gold => GoldJurors::<T>::mutate(|jurors| {
-                    if let Some(index) = jurors.iter().position(|j| j == account) {
-                        jurors.swap_remove(index);
-                    }
-                }),
-                JurorTier::Silver => SilverJurors::<T>::mutate(|jurors| {
-                    if let Some(index) = jurors.iter().position(|j| j == account) {
-                        jurors.swap_remove(index);
-                    }
-                }),
-                JurorTier::Bronze => BronzeJurors::<T>::mutate(|jurors| {
-                    if let Some(index) = jurors.iter().position(|j| j == account) {
-                        jurors.swap_remove(index);
-                    }
-                }),
-                JurorTier::Ineligible => {},
+            if !Self::in_juror_pool(tier, account) {
+                return Ok(());
+            }
+            let index = JurorPoolIndex::<T>::take(account).ok_or(Error::<T>::NotRegisteredAsJuror)?;
+            Self::sub_pool_weight(tier, index, Self::draw_weight(account));
+            let last = Self::juror_pool_size(tier).saturating_sub(1);
+            if index != last {
+                if let Some(moved) = JurorPools::<T>::get(tier, last) {
+                    let weight = Self::draw_weight(&moved);
+                    Self::sub_pool_weight(tier, last, weight);
+                    Self::add_pool_weight(tier, index, weight);
+                    JurorPools::<T>::insert(tier, index, &moved);
+                    JurorPoolIndex::<T>::insert(&moved, index);
+                }
             }
+            JurorPools::<T>::remove(tier, last);
+            JurorPoolSize::<T>::insert(tier, last);
             Ok(())
         }
 
+        /// A juror's weight in jury draws: its bonded and delegated stake, but at least one
+        /// unit so jurors are drawn even while `JurorStake` is zero.
+        pub fn draw_weight(account: &T::AccountId) -> BalanceOf<T> {
+            Self::stake_of(account)
+                .unwrap_or_default()
+                .saturating_add(Self::delegated_stake(account))
+                .max(One::one())
+        }
+
+        pub(crate) fn add_pool_weight(tier: JurorTier, index: u32, weight: BalanceOf<T>) {
+            Self::update_pool_weight(tier, index, |sum| sum.saturating_add(weight));
+            PoolStake::<T>::mutate(tier, |total| *total = total.saturating_add(weight));
+        }
+
+        fn sub_pool_weight(tier: JurorTier, index: u32, weight: BalanceOf<T>) {
+            Self::update_pool_weight(tier, index, |sum| sum.saturating_sub(weight));
+            PoolStake::<T>::mutate(tier, |total| *total = total.saturating_sub(weight));
+        }
+
+        fn update_pool_weight(tier: JurorTier, index: u32, f: impl Fn(BalanceOf<T>) -> BalanceOf<T>) {
+            let mut node = index.saturating_add(1);
+            while node <= STAKE_TREE_SIZE {
+                PoolStakeTree::<T>::mutate(tier, node, |sum| *sum = f(*sum));
+                node = node.saturating_add(node & node.wrapping_neg());
+            }
+        }
+
+        /// Pool index whose share of the tier's total draw weight contains `position`.
+        fn find_by_weight(tier: JurorTier, position: BalanceOf<T>) -> u32 {
+            let mut index = 0u32;
+            let mut remaining = position;
+            let mut step = STAKE_TREE_SIZE;
+            while step > 0 {
+                let node = index + step;
+                if node <= STAKE_TREE_SIZE {
+                    let weight = PoolStakeTree::<T>::get(tier, node);
+                    if weight <= remaining {
+                        index = node;
+                        remaining = remaining.saturating_sub(weight);
+                    }
+                }
+                step >>= 1;
+            }
+            index
+        }
+
         pub fn update_juror_tier(account: &T::AccountId) -> DispatchResult {
             if !Self::juror_opted_in(account) {
                 return Ok(());
             }
             let old = Self::juror_tier(account);
-            let new = Self::calculate_tier_from_stats(&Self::reputation_stats(account));
+            let new = Self::calculate_tier(account);
             if old != new {
                 Self::remove_juror_from_tier_list(account, old)?;
                 if new == JurorTier::Ineligible {
                     // auto-deregister
+                    Self::release_delegations(account);
                     if let Some(stake) = StakeOf::<T>::take(account) {
-                        T::Currency::unreserve(account, stake);
+                        Self::start_unbonding(account, stake);
                     }
                     JurorRegistry::<T>::remove(account);
                     JurorTiers::<T>::remove(account);
@@ -1022,6 +2885,7 @@ pub mod pallet {
                 } else {
                     Self::add_juror_to_tier_list(account, new)?;
                     JurorTiers::<T>::insert(account, new);
+                    Self::mint_tier_credential(account, new);
                     Self::deposit_event(Event::JurorTierUpdated { account: account.clone() });
                 }
             }
@@ -1031,6 +2895,7 @@ pub mod pallet {
         fn remove_juror_from_juror_registry(juror: &T::AccountId) -> DispatchResult {
             let tier = Self::juror_tier(juror);
             Self::remove_juror_from_tier_list(juror, tier)?;
+            Self::release_delegations(juror);
             JurorRegistry::<T>::remove(juror);
             JurorTiers::<T>::remove(juror);
             Self::deposit_event(Event::JurorAutomaticallyDeregistered { account: juror.clone() });
@@ -1053,8 +2918,9 @@ impl<T: pallet::Config> ReputationInterface<
         project_value: pallet::BalanceOf<T>, 
         client_rating: u32,
         project_id: T::ProjectId,
+        skills: &[Skill],
     ) -> DispatchResult {
-        Self::internal_project_completed(freelancer, project_value, client_rating, project_id)
+        Self::internal_project_completed(freelancer, project_value, client_rating, project_id, skills)
     }
     
     fn on_dispute_outcome(
@@ -1062,8 +2928,10 @@ impl<T: pallet::Config> ReputationInterface<
         loser: &T::AccountId,
         project_id: T::ProjectId,
         project_value: pallet::BalanceOf<T>,
+        skills: &[Skill],
+        loser_role: ProjectRole,
     ) -> DispatchResult {
-        Self::internal_dispute_outcome(winner, loser, project_id, project_value)
+        Self::internal_dispute_outcome(winner, loser, project_id, project_value, skills, loser_role)
     }
 
     fn on_project_created(
@@ -1086,24 +2954,66 @@ impl<T: pallet::Config> ReputationInterface<
         Self::internal_on_work_accepted(client, project_id)
     }
     
+    fn on_client_rated(
+        client: &T::AccountId,
+        project_value: pallet::BalanceOf<T>,
+        freelancer_rating: u32,
+        project_id: T::ProjectId,
+    ) -> DispatchResult {
+        Self::internal_on_client_rated(client, project_value, freelancer_rating, project_id)
+    }
+    
     fn on_jury_vote(
         juror: &T::AccountId,
         voted_with_majority: bool,
+        project_id: T::ProjectId,
     ) -> DispatchResult {
-        Self::internal_jury_vote(juror, voted_with_majority)
+        Self::internal_jury_vote(juror, voted_with_majority, project_id)
+    }
+    
+    fn on_work_started(
+        client: &T::AccountId,
+        freelancer: &T::AccountId,
+        _project_id: T::ProjectId,
+    ) -> DispatchResult {
+        Self::internal_on_work_started(client, freelancer)
     }
     
     fn get_eligible_jurors(
+        project_id: T::ProjectId,
         min_tier: JurorTier, 
+        parties: &[T::AccountId],
         exclude: &[T::AccountId],
         count: u32,
+        skills: &[Skill],
     ) -> BoundedVec<T::AccountId, T::MaxJurors> {
-        Self::internal_get_eligible_jurors(min_tier, exclude, count)
+        Self::internal_get_eligible_jurors(project_id, min_tier, parties, exclude, count, skills)
+    }
+
+    fn skill_score(
+        account: &T::AccountId,
+        skills: &[Skill],
+    ) -> u32 {
+        Self::internal_skill_score(account, skills)
+    }
+
+    fn has_conflict_of_interest(
+        juror: &T::AccountId,
+        parties: &[T::AccountId],
+    ) -> bool {
+        Self::internal_has_conflict_of_interest(juror, parties)
     }
 
     fn slash_juror(juror: &T::AccountId) -> DispatchResult {
         Self::internal_slash_juror(juror)
     }
+
+    fn reward_shares(
+        juror: &T::AccountId,
+        reward: pallet::BalanceOf<T>,
+    ) -> Vec<(T::AccountId, pallet::BalanceOf<T>)> {
+        Self::reward_shares(juror, reward)
+    }
 }
 
 
@@ -0,0 +1,728 @@
+//! Storage migrations for the reputation pallet.
+//!
+//! Each `vN` module upgrades storage from version `N - 1` to `N` as a [`SteppedMigration`] for
+//! `pallet-migrations`, so the work spreads over several blocks on a live chain. A migration
+//! is a no-op at any other version, and only translates storage: scores and juror tiers that
+//! depend on the translated data are left for `on_idle` to recompute. Under `try-runtime`
+//! every migration records what it expects in `pre_upgrade` and checks it in `post_upgrade`.
+//!
+//! [`SteppedMigration`]: frame_support::migrations::SteppedMigration
+
+use crate::pallet::JurorTier;
+
+/// Pool visited after `tier` by migrations that walk the juror pools, Gold first.
+fn next_pool(tier: JurorTier) -> Option<JurorTier> {
+    match tier {
+        JurorTier::Gold => Some(JurorTier::Silver),
+        JurorTier::Silver => Some(JurorTier::Bronze),
+        _ => None,
+    }
+}
+
+pub mod v1 {
+    use crate::pallet::{
+        BalanceOf, ClientStats, Config, FreelancerStats, GlobalStatsDirty, JurorStats, Pallet,
+        ReputationData, ReputationStats, RetierPending,
+    };
+    use codec::{Decode, Encode};
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+    use sp_runtime::Permill;
+
+    /// The flat, role-agnostic record stored before version 1.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct OldReputationData<Balance, BlockNumber> {
+        pub registration_block: BlockNumber,
+        pub last_activity_block: BlockNumber,
+        pub projects_completed: u32,
+        pub projects_failed: u32,
+        pub total_earned: Balance,
+        pub projects_posted: u32,
+        pub total_spent: Balance,
+        pub disputes_initiated: u32,
+        pub disputes_won: u32,
+        pub disputes_lost: u32,
+        pub avg_rating_received: u32,
+        pub total_ratings_received: u32,
+        pub jury_participation: u32,
+        pub jury_accuracy: Permill,
+    }
+
+    impl<Balance, BlockNumber> OldReputationData<Balance, BlockNumber> {
+        /// Split the shared counters into role ledgers.
+        ///
+        /// Every completed freelance project carried a client rating, so completions beyond the
+        /// number of ratings were client acceptances. Likewise every lost dispute counted as a
+        /// failed project; the remaining failures were client cancellations.
+        pub fn migrate(self) -> ReputationData<Balance, BlockNumber> {
+            let freelancer_completed = self.projects_completed.min(self.total_ratings_received);
+            let freelancer_failed = self.projects_failed.min(self.disputes_lost);
+
+            ReputationData {
+                registration_block: self.registration_block,
+                last_activity_block: self.last_activity_block,
+                freelancer: FreelancerStats {
+                    projects_completed: freelancer_completed,
+                    projects_failed: freelancer_failed,
+                    total_earned: self.total_earned,
+                    avg_rating_received: self.avg_rating_received,
+                    total_ratings_received: self.total_ratings_received,
+                },
+                client: ClientStats {
+                    projects_posted: self.projects_posted,
+                    projects_completed: self.projects_completed - freelancer_completed,
+                    projects_cancelled: self.projects_failed - freelancer_failed,
+                    total_spent: self.total_spent,
+                    avg_rating_received: 0,
+                    total_ratings_received: 0,
+                },
+                juror: JurorStats {
+                    jury_participation: self.jury_participation,
+                    jury_accuracy: self.jury_accuracy,
+                },
+                disputes_initiated: self.disputes_initiated,
+                disputes_won: self.disputes_won,
+                disputes_lost: self.disputes_lost,
+            }
+        }
+    }
+
+    pub mod old {
+        use super::*;
+
+        /// `ReputationStats` holding version 0 entries.
+        #[frame_support::storage_alias]
+        pub type ReputationStats<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            OldReputationData<BalanceOf<T>, BlockNumberFor<T>>,
+        >;
+    }
+
+    /// Moves `ReputationStats` from the flat record to role-specific ledgers and flags every
+    /// registered juror to be re-tiered against the freelancer ledger alone. Each step
+    /// translates as many records as the weight meter allows, resuming after the last
+    /// translated account.
+    pub struct LazyMigrationV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV1<T> {
+        type Cursor = T::AccountId;
+        type Identifier = MigrationId<17>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-reputation", version_from: 0, version_to: 1 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return Ok(None);
+            }
+
+            let required = T::DbWeight::get().reads_writes(1, 1);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            while meter.try_consume(required).is_ok() {
+                let mut remaining = match cursor {
+                    Some(ref last) => old::ReputationStats::<T>::iter_from(old::ReputationStats::<T>::hashed_key_for(last)),
+                    None => old::ReputationStats::<T>::iter(),
+                };
+                match remaining.next() {
+                    Some((who, old)) => {
+                        ReputationStats::<T>::insert(&who, old.migrate());
+                        cursor = Some(who);
+                    },
+                    None => {
+                        // Tiers and scores are recomputed by `on_idle` once every migration has run
+                        RetierPending::<T>::put(true);
+                        GlobalStatsDirty::<T>::put(true);
+                        StorageVersion::new(1).put::<Pallet<T>>();
+                        return Ok(None);
+                    },
+                }
+            }
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = (Pallet::<T>::on_chain_storage_version() == 0)
+                .then(|| ReputationStats::<T>::iter_keys().count() as u32);
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let Some(count) = Option::<u32>::decode(&mut &state[..])
+                .map_err(|_| "v1: cannot decode pre-upgrade state")?
+            else {
+                return Ok(());
+            };
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "v1: storage version not bumped");
+            ensure!(
+                ReputationStats::<T>::iter_values().count() as u32 == count,
+                "v1: reputation records lost in translation"
+            );
+            ensure!(RetierPending::<T>::get(), "v1: jurors not flagged for re-tiering");
+            Ok(())
+        }
+    }
+}
+
+pub mod v2 {
+    use crate::pallet::{
+        Attestation, AttestationNonce, AttestationOutcome, Attestations, AttestorType, BalanceOf,
+        Config, Pallet, ProjectAttestations,
+    };
+    use codec::{Decode, Encode, MaxEncodedLen};
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+    use sp_runtime::SaturatedConversion;
+
+    /// An attestation as stored before version 2, with the rating SCALE-encoded in `metadata`.
+    #[derive(Encode, Decode)]
+    pub struct OldAttestation<T: Config> {
+        pub attestor: AttestorType,
+        pub project_id: T::ProjectId,
+        pub outcome: AttestationOutcome,
+        pub value: BalanceOf<T>,
+        pub timestamp: BlockNumberFor<T>,
+        pub metadata: BoundedVec<u8, T::MaxMetadata>,
+    }
+
+    impl<T: Config> OldAttestation<T> {
+        pub fn migrate(self) -> Attestation<T> {
+            let rating = match self.metadata.len() {
+                4 => u32::decode(&mut &self.metadata[..]).ok(),
+                _ => None,
+            };
+            Attestation {
+                attestor: self.attestor,
+                project_id: self.project_id,
+                outcome: self.outcome,
+                value: self.value,
+                timestamp: self.timestamp,
+                rating,
+                metadata: BoundedVec::default(),
+            }
+        }
+    }
+
+    pub mod old {
+        use super::*;
+
+        /// `Attestations` keyed by `(AccountId, ProjectId)`.
+        #[frame_support::storage_alias]
+        pub type Attestations<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Blake2_128Concat,
+            <T as Config>::ProjectId,
+            OldAttestation<T>,
+        >;
+
+        /// Translated attestations waiting for a nonce. The big-endian timestamp key keeps each
+        /// account's attestations in timestamp order.
+        #[frame_support::storage_alias]
+        pub type StagedAttestations<T: Config> = StorageNMap<
+            Pallet<T>,
+            (
+                NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>,
+                NMapKey<Identity, [u8; 8]>,
+                NMapKey<Blake2_128Concat, <T as Config>::ProjectId>,
+            ),
+            Attestation<T>,
+        >;
+    }
+
+    /// Progress of [`LazyMigrationV2`].
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug)]
+    pub enum Stage {
+        /// Moving old attestations out of `Attestations` into `StagedAttestations`.
+        Staging,
+        /// Issuing nonces to staged attestations and writing them back.
+        Rekeying,
+    }
+
+    /// Re-keys `Attestations` by a per-account nonce, issued in timestamp order, builds the
+    /// `ProjectAttestations` index and moves ratings out of `metadata`.
+    ///
+    /// Old and new entries share the storage prefix, so every old attestation is first moved
+    /// aside, keyed by its timestamp, before any is written back under a nonce. Each step moves
+    /// one attestation at a time until the weight meter runs out.
+    pub struct LazyMigrationV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV2<T> {
+        type Cursor = Stage;
+        type Identifier = MigrationId<17>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-reputation", version_from: 1, version_to: 2 }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return Ok(None);
+            }
+
+            // Rekeying one attestation reads it and the nonce, then writes four entries
+            let required = T::DbWeight::get().reads_writes(2, 4);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let mut stage = cursor.unwrap_or(Stage::Staging);
+            while meter.try_consume(required).is_ok() {
+                match stage {
+                    Stage::Staging => match old::Attestations::<T>::iter().next() {
+                        Some((who, project_id, attestation)) => {
+                            old::Attestations::<T>::remove(&who, project_id);
+                            let timestamp = attestation.timestamp.saturated_into::<u64>().to_be_bytes();
+                            old::StagedAttestations::<T>::insert((&who, timestamp, project_id), attestation.migrate());
+                        },
+                        None => stage = Stage::Rekeying,
+                    },
+                    Stage::Rekeying => match old::StagedAttestations::<T>::iter().next() {
+                        Some(((who, timestamp, project_id), attestation)) => {
+                            old::StagedAttestations::<T>::remove((&who, timestamp, project_id));
+                            let nonce = AttestationNonce::<T>::mutate(&who, |next| {
+                                let nonce = *next;
+                                *next = next.saturating_add(1);
+                                nonce
+                            });
+                            ProjectAttestations::<T>::insert((&who, project_id, nonce), attestation.attestor.clone());
+                            Attestations::<T>::insert(&who, nonce, attestation);
+                        },
+                        None => {
+                            StorageVersion::new(2).put::<Pallet<T>>();
+                            return Ok(None);
+                        },
+                    },
+                }
+            }
+            Ok(Some(stage))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = (Pallet::<T>::on_chain_storage_version() == 1)
+                .then(|| old::Attestations::<T>::iter_keys().count() as u32);
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let Some(count) = Option::<u32>::decode(&mut &state[..])
+                .map_err(|_| "v2: cannot decode pre-upgrade state")?
+            else {
+                return Ok(());
+            };
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "v2: storage version not bumped");
+            ensure!(
+                Attestations::<T>::iter_values().count() as u32 == count,
+                "v2: attestations lost while re-keying"
+            );
+            ensure!(
+                AttestationNonce::<T>::iter_values().map(u64::from).sum::<u64>() == u64::from(count),
+                "v2: nonces do not match the number of attestations"
+            );
+            ensure!(old::StagedAttestations::<T>::iter_keys().next().is_none(), "v2: staged attestations left behind");
+            Ok(())
+        }
+    }
+}
+
+pub mod v3 {
+    use super::next_pool;
+    use crate::pallet::{Config, JurorPoolIndex, JurorPoolSize, JurorPools, JurorTier, JurorTiers, Pallet};
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    use scale_info::prelude::vec::Vec;
+
+    pub mod old {
+        use super::*;
+
+        /// The Gold pool as a single bounded vector.
+        #[frame_support::storage_alias]
+        pub type GoldJurors<T: Config> =
+            StorageValue<Pallet<T>, Vec<<T as frame_system::Config>::AccountId>, ValueQuery>;
+
+        /// The Silver pool as a single bounded vector.
+        #[frame_support::storage_alias]
+        pub type SilverJurors<T: Config> =
+            StorageValue<Pallet<T>, Vec<<T as frame_system::Config>::AccountId>, ValueQuery>;
+
+        /// The Bronze pool as a single bounded vector.
+        #[frame_support::storage_alias]
+        pub type BronzeJurors<T: Config> =
+            StorageValue<Pallet<T>, Vec<<T as frame_system::Config>::AccountId>, ValueQuery>;
+
+        /// The old vector of `tier`'s pool.
+        pub fn pool<T: Config>(tier: JurorTier) -> Vec<T::AccountId> {
+            match tier {
+                JurorTier::Gold => GoldJurors::<T>::get(),
+                JurorTier::Silver => SilverJurors::<T>::get(),
+                JurorTier::Bronze => BronzeJurors::<T>::get(),
+                JurorTier::Ineligible => Vec::new(),
+            }
+        }
+
+        /// Remove the old vector of `tier`'s pool.
+        pub fn kill_pool<T: Config>(tier: JurorTier) {
+            match tier {
+                JurorTier::Gold => GoldJurors::<T>::kill(),
+                JurorTier::Silver => SilverJurors::<T>::kill(),
+                JurorTier::Bronze => BronzeJurors::<T>::kill(),
+                JurorTier::Ineligible => {},
+            }
+        }
+    }
+
+    /// Moves the juror pools from one vector per tier into `JurorPools`. Jurors keep their
+    /// positions, so `NextJurorIndex` stays valid. The cursor is the pool being moved and the
+    /// position of the next juror in its old vector.
+    pub struct LazyMigrationV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV3<T> {
+        type Cursor = (JurorTier, u32);
+        type Identifier = MigrationId<17>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-reputation", version_from: 2, version_to: 3 }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 2 {
+                return Ok(None);
+            }
+
+            let read_pool = T::DbWeight::get().reads_writes(1, 1);
+            let required = T::DbWeight::get().reads_writes(2, 3);
+            if meter.remaining().any_lt(read_pool.saturating_add(required)) {
+                return Err(SteppedMigrationError::InsufficientWeight { required: read_pool.saturating_add(required) });
+            }
+
+            let (mut tier, mut position) = cursor.unwrap_or((JurorTier::Gold, 0));
+            loop {
+                if meter.try_consume(read_pool).is_err() {
+                    return Ok(Some((tier, position)));
+                }
+                let jurors = old::pool::<T>(tier);
+                while let Some(juror) = jurors.get(position as usize) {
+                    if meter.try_consume(required).is_err() {
+                        return Ok(Some((tier, position)));
+                    }
+                    // Skip stale entries; only jurors cached in this tier belong to its pool
+                    if JurorTiers::<T>::get(juror) == tier {
+                        let index = JurorPoolSize::<T>::get(tier);
+                        JurorPools::<T>::insert(tier, index, juror);
+                        JurorPoolIndex::<T>::insert(juror, index);
+                        JurorPoolSize::<T>::insert(tier, index.saturating_add(1));
+                    }
+                    position = position.saturating_add(1);
+                }
+                old::kill_pool::<T>(tier);
+                match next_pool(tier) {
+                    Some(next) => (tier, position) = (next, 0),
+                    None => {
+                        StorageVersion::new(3).put::<Pallet<T>>();
+                        return Ok(None);
+                    },
+                }
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            use codec::Encode;
+
+            // Jurors expected in the new pools: the ones cached in the tier they are listed in
+            let expected = (Pallet::<T>::on_chain_storage_version() == 2).then(|| {
+                [JurorTier::Gold, JurorTier::Silver, JurorTier::Bronze]
+                    .into_iter()
+                    .map(|tier| {
+                        old::pool::<T>(tier).iter().filter(|juror| JurorTiers::<T>::get(juror) == tier).count() as u32
+                    })
+                    .sum::<u32>()
+            });
+            Ok(expected.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            use codec::Decode;
+
+            let Some(expected) = Option::<u32>::decode(&mut &state[..])
+                .map_err(|_| "v3: cannot decode pre-upgrade state")?
+            else {
+                return Ok(());
+            };
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 3, "v3: storage version not bumped");
+            let pooled: u32 = [JurorTier::Gold, JurorTier::Silver, JurorTier::Bronze]
+                .into_iter()
+                .map(JurorPoolSize::<T>::get)
+                .sum();
+            ensure!(pooled == expected, "v3: juror pools do not match the old vectors");
+            ensure!(
+                !old::GoldJurors::<T>::exists() && !old::SilverJurors::<T>::exists() && !old::BronzeJurors::<T>::exists(),
+                "v3: old pool vectors left behind"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v4 {
+    use super::next_pool;
+    use crate::pallet::{
+        BalanceOf, Config, DelegatedStake, JurorPoolSize, JurorPools, JurorTier, Pallet, PoolStake, PoolStakeTree,
+        StakeOf,
+    };
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+    use sp_runtime::traits::{One, Saturating};
+
+    /// Number of leaves in each tier's stake tree at version 4.
+    const STAKE_TREE_SIZE: u32 = 1 << 16;
+
+    pub mod old {
+        use super::*;
+
+        /// Round-robin position of each tier's pool before draws were weighted by stake.
+        #[frame_support::storage_alias]
+        pub type NextJurorIndex<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, JurorTier, u32, ValueQuery>;
+    }
+
+    /// A juror's weight in draws at version 4: its bonded and delegated stake, but at least one
+    /// unit.
+    fn draw_weight<T: Config>(juror: &T::AccountId) -> BalanceOf<T> {
+        StakeOf::<T>::get(juror)
+            .unwrap_or_default()
+            .saturating_add(DelegatedStake::<T>::get(juror))
+            .max(One::one())
+    }
+
+    /// Builds the stake tree of every juror pool from the jurors' bonded stake and replaces
+    /// `NextJurorIndex` with `DrawCursor`. The cursor is the pool being built and the index of
+    /// the next juror in it.
+    pub struct LazyMigrationV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV4<T> {
+        type Cursor = (JurorTier, u32);
+        type Identifier = MigrationId<17>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-reputation", version_from: 3, version_to: 4 }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 3 {
+                return Ok(None);
+            }
+
+            // Every juror updates up to 17 tree nodes and the pool total
+            let required = T::DbWeight::get().reads_writes(22, 18);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            let (mut tier, mut index) = cursor.unwrap_or((JurorTier::Gold, 0));
+            while meter.try_consume(required).is_ok() {
+                if index >= JurorPoolSize::<T>::get(tier) {
+                    old::NextJurorIndex::<T>::remove(tier);
+                    match next_pool(tier) {
+                        Some(next) => (tier, index) = (next, 0),
+                        None => {
+                            StorageVersion::new(4).put::<Pallet<T>>();
+                            return Ok(None);
+                        },
+                    }
+                    continue;
+                }
+                if let Some(juror) = JurorPools::<T>::get(tier, index) {
+                    let weight = draw_weight::<T>(&juror);
+                    let mut node = index.saturating_add(1);
+                    while node <= STAKE_TREE_SIZE {
+                        PoolStakeTree::<T>::mutate(tier, node, |sum| *sum = sum.saturating_add(weight));
+                        node = node.saturating_add(node & node.wrapping_neg());
+                    }
+                    PoolStake::<T>::mutate(tier, |total| *total = total.saturating_add(weight));
+                }
+                index = index.saturating_add(1);
+            }
+            Ok(Some((tier, index)))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            use codec::Encode;
+            Ok((Pallet::<T>::on_chain_storage_version() == 3).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            use codec::Decode;
+            use sp_runtime::traits::Zero;
+
+            let applies = bool::decode(&mut &state[..]).map_err(|_| "v4: cannot decode pre-upgrade state")?;
+            if !applies {
+                return Ok(());
+            }
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 4, "v4: storage version not bumped");
+            for tier in [JurorTier::Gold, JurorTier::Silver, JurorTier::Bronze] {
+                let mut total = BalanceOf::<T>::zero();
+                for index in 0..JurorPoolSize::<T>::get(tier) {
+                    if let Some(juror) = JurorPools::<T>::get(tier, index) {
+                        total = total.saturating_add(draw_weight::<T>(&juror));
+                    }
+                }
+                ensure!(PoolStake::<T>::get(tier) == total, "v4: pool stake does not match its jurors");
+                ensure!(!old::NextJurorIndex::<T>::contains_key(tier), "v4: NextJurorIndex left behind");
+            }
+            Ok(())
+        }
+    }
+}
+
+pub mod v5 {
+    use crate::pallet::{Attestations, Config, Pallet, RecentProjects};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_runtime::traits::Saturating;
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::*,
+        traits::{GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+
+    /// Adds `project_id` to the account's recent projects at version 5, keeping them ordered by
+    /// block and the latest block per project. When full, the oldest entry makes room.
+    fn record<T: Config>(account: &T::AccountId, project_id: T::ProjectId, block: BlockNumberFor<T>) {
+        RecentProjects::<T>::mutate(account, |recent| {
+            recent.retain(|(id, at)| *id != project_id || *at > block);
+            if recent.iter().any(|(id, _)| *id == project_id) {
+                return;
+            }
+            if recent.is_full() {
+                if recent.first().is_none_or(|(_, oldest)| *oldest > block) {
+                    return;
+                }
+                recent.remove(0);
+            }
+            let position = recent.partition_point(|(_, at)| *at <= block);
+            let _ = recent.try_insert(position, (project_id, block));
+        });
+    }
+
+    /// Fills `RecentProjects` from the party attestations issued within the conflict window,
+    /// which conflict-of-interest checks read instead of every attestation of the parties. The
+    /// cursor is the account and nonce of the last attestation read.
+    pub struct LazyMigrationV5<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV5<T> {
+        type Cursor = (T::AccountId, u32);
+        type Identifier = MigrationId<17>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *b"pallet-reputation", version_from: 4, version_to: 5 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if Pallet::<T>::on_chain_storage_version() != 4 {
+                return Ok(None);
+            }
+
+            // Read the attestation, then the account's recent projects and write them back
+            let required = T::DbWeight::get().reads_writes(2, 1);
+            let since = frame_system::Pallet::<T>::block_number().saturating_sub(T::ConflictWindow::get());
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            while meter.try_consume(required).is_ok() {
+                let mut remaining = match &cursor {
+                    Some((account, nonce)) => Attestations::<T>::iter_from(Attestations::<T>::hashed_key_for(account, nonce)),
+                    None => Attestations::<T>::iter(),
+                };
+                let Some((account, nonce, attestation)) = remaining.next() else {
+                    StorageVersion::new(5).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+                if attestation.attestor.is_party() && attestation.timestamp >= since {
+                    record::<T>(&account, attestation.project_id, attestation.timestamp);
+                }
+                cursor = Some((account, nonce));
+            }
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            use codec::Encode;
+            Ok((Pallet::<T>::on_chain_storage_version() == 4).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            use codec::Decode;
+
+            let applies = bool::decode(&mut &state[..]).map_err(|_| "v5: cannot decode pre-upgrade state")?;
+            if !applies {
+                return Ok(());
+            }
+            ensure!(Pallet::<T>::on_chain_storage_version() >= 5, "v5: storage version not bumped");
+            for (account, recent) in RecentProjects::<T>::iter() {
+                ensure!(
+                    recent.windows(2).all(|pair| pair[0].1 <= pair[1].1),
+                    "v5: recent projects out of order"
+                );
+                ensure!(Attestations::<T>::iter_prefix_values(&account).next().is_some(), "v5: recent projects without attestations");
+            }
+            Ok(())
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use frame_support::{
     parameter_types,
-    traits::{ConstU32},
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
     weights::constants::RocksDbWeight,
     PalletId,
 };
@@ -10,9 +10,17 @@ use sp_runtime::{
     BuildStorage, AccountId32
 };
 
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use crate as pallet_reputation;
+use crate::{ReputationSender, ReputationSummary};
+use frame_support::dispatch::DispatchResult;
+use std::cell::RefCell;
 use sp_runtime::Permill;
+use pallet_nfts::{
+    CollectionConfig, CollectionConfigFor, CollectionSetting, CollectionSettings, ItemConfig,
+    ItemSetting, ItemSettings, MintSettings, PalletFeatures,
+};
+use sp_runtime::{MultiSignature, MultiSigner};
 
 // Configure a mock runtime to test the pallet.
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -23,6 +31,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Balances: pallet_balances,
+        Nfts: pallet_nfts,
         Reputation: pallet_reputation,
     }
 );
@@ -88,8 +97,50 @@ impl pallet_balances::Config for Test {
     type RuntimeFreezeReason = ();
     type DoneSlashHandler = ();
 }
+parameter_types! {
+    pub const ReputationPalletId: PalletId = PalletId(*b"tsk/repu");
+    pub NftFeatures: PalletFeatures = PalletFeatures::all_enabled();
+    pub CredentialCollectionConfig: CollectionConfigFor<Test> = CollectionConfig {
+        settings: CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into()),
+        max_supply: None,
+        mint_settings: MintSettings::default(),
+    };
+    pub CredentialItemConfig: ItemConfig = ItemConfig {
+        settings: ItemSettings::from_disabled(ItemSetting::Transferable.into()),
+    };
+}
+
+impl pallet_nfts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId32>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId32>;
+    type Locker = ();
+    type CollectionDeposit = ConstU64<0>;
+    type ItemDeposit = ConstU64<0>;
+    type MetadataDepositBase = ConstU64<0>;
+    type AttributeDepositBase = ConstU64<0>;
+    type DepositPerByte = ConstU64<0>;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<10>;
+    type ItemAttributesApprovalsLimit = ConstU32<2>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU64<10000>;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftFeatures;
+    type OffchainSignature = MultiSignature;
+    type OffchainPublic = MultiSigner;
+    type WeightInfo = ();
+    type BlockNumberProvider = System;
+}
+
 parameter_types! {
     pub const SlashRatio: Permill = Permill::from_percent(10);
+    pub static MaxPartnerCalls: u32 = 100;
 }
 
 impl pallet_reputation::Config for Test {
@@ -97,6 +148,7 @@ impl pallet_reputation::Config for Test {
     type Currency = Balances;
     type ProjectId = u32;
     type GovernanceOrigin = EnsureRoot<Self::AccountId>;
+    type JurorEmergencyOrigin = EnsureRoot<Self::AccountId>;
     type MaxMetadata = ConstU32<256>;
     type WeightInfo = ();
     type MaxJurors = ConstU32<500>;
@@ -104,6 +156,46 @@ impl pallet_reputation::Config for Test {
     type MaxSilverJurors = ConstU32<200>;
     type MaxBronzeJurors = ConstU32<200>;
     type SlashRatio = SlashRatio;
+    type ConflictWindow = ConstU64<1000>;
+    type MaxRecentProjects = ConstU32<4>;
+    type Randomness = pallet_reputation::ParentHashRandomness<Test>;
+    type DecayPeriod = ConstU64<100>;
+    type MaxRescoresPerBlock = ConstU32<10>;
+    type PalletId = ReputationPalletId;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NftCollectionConfig = CollectionConfigFor<Test>;
+    type NftItemConfig = ItemConfig;
+    type Nfts = Nfts;
+    type CredentialCollectionConfig = CredentialCollectionConfig;
+    type CredentialItemConfig = CredentialItemConfig;
+    // Root acts as partner parachain 2000
+    type PartnerChainOrigin = EnsureRootWithSuccess<AccountId32, ConstU32<2000>>;
+    type ReputationSender = MockReputationSender;
+    type MaxPartnerCalls = MaxPartnerCalls;
+    type PartnerQuotaPeriod = ConstU64<10>;
+    type UnbondingPeriod = ConstU64<100>;
+    type MaxUnlockingChunks = ConstU32<4>;
+    type MaxDelegators = ConstU32<8>;
+    type BalanceUnit = ConstU64<1>;
+    type PublishFee = ConstU64<10>;
+}
+
+/// A summary recorded by `MockReputationSender`: destination, callback and summary.
+pub type SentSummary = (u32, [u8; 2], ReputationSummary<AccountId32, u64>);
+
+thread_local! {
+    pub static SENT_SUMMARIES: RefCell<Vec<SentSummary>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records summaries instead of sending them.
+pub struct MockReputationSender;
+
+impl ReputationSender<AccountId32, u64> for MockReputationSender {
+    fn send_summary(dest: u32, callback: [u8; 2], summary: ReputationSummary<AccountId32, u64>) -> DispatchResult {
+        SENT_SUMMARIES.with(|s| s.borrow_mut().push((dest, callback, summary)));
+        Ok(())
+    }
 }
 
 // Build genesis storage according to the mock runtime.
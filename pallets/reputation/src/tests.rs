@@ -1,7 +1,7 @@
-use crate::{mock::*, Error, Event, JurorTier, ReputationStats, ReputationInterface, JurorBusy, JurorStake};
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use crate::{mock::*, AttestationOutcome, AttestorType, Error, Event, JurorTier, ReputationStats, ReputationInterface, JurorBusy, JurorStake, ProjectRole, Skill, TierRequirement, TierThresholds, UnlockChunk};
+use frame_support::{assert_noop, assert_ok, traits::{Currency, Hooks}, weights::Weight};
 use frame_system::RawOrigin;
-use sp_runtime::{AccountId32, Permill};
+use sp_runtime::{AccountId32, Percent, Permill};
 
 fn account(s: &str) -> AccountId32 {
     AccountId32::new([s.as_bytes(), &[0; 32][s.as_bytes().len()..]].concat().try_into().unwrap())
@@ -22,10 +22,10 @@ fn register_user_works() {
         // Assert: Reputation data was created with correct defaults
         let stats = Reputation::reputation_stats(&alice);
         assert_eq!(stats.registration_block, 1);
-        assert_eq!(stats.projects_completed, 0);
-        assert_eq!(stats.total_earned, 0);
+        assert_eq!(stats.freelancer.projects_completed, 0);
+        assert_eq!(stats.freelancer.total_earned, 0);
         assert_eq!(stats.disputes_lost, 0);
-        assert_eq!(stats.jury_accuracy, Permill::zero());
+        assert_eq!(stats.juror.jury_accuracy, Permill::zero());
     });
 }
 
@@ -55,18 +55,17 @@ fn register_as_juror_places_user_in_correct_tier_list() {
 
         // Arrange: Give Alice stats that qualify for Silver tier
         ReputationStats::<Test>::mutate(&alice, |stats| {
-            stats.projects_completed = 25;
-            stats.total_earned = 15000;
+            stats.freelancer.projects_completed = 25;
+            stats.freelancer.total_earned = 15000;
             stats.disputes_lost = 0;
         });
 
         // Act: Alice registers as a juror
         assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
 
-        // Assert: Alice is in the SilverJurors pool and her tier is cached correctly
-        let silver_jurors = Reputation::silver_jurors();
-        assert!(silver_jurors.contains(&alice));
-        assert!(!Reputation::gold_jurors().contains(&alice)); // Should not be in other pools
+        // Assert: Alice is in the Silver pool and her tier is cached correctly
+        assert!(Reputation::in_juror_pool(JurorTier::Silver, &alice));
+        assert!(!Reputation::in_juror_pool(JurorTier::Gold, &alice)); // Should not be in other pools
         assert_eq!(Reputation::juror_tier(&alice), JurorTier::Silver);
 
         // Assert: Event was emitted
@@ -83,23 +82,23 @@ fn juror_tier_updates_automatically_on_promotion() {
 
         // Arrange: Give Bob stats JUST BELOW Gold tier and register him as a juror
         ReputationStats::<Test>::mutate(&bob, |stats| {
-            stats.projects_completed = 49;
-            stats.total_earned = 49000;
+            stats.freelancer.projects_completed = 49;
+            stats.freelancer.total_earned = 49000;
             stats.disputes_lost = 0;
         });
         assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(bob.clone()).into()));
         
         // Assert pre-condition: He is a Silver juror
-        assert!(Reputation::silver_jurors().contains(&bob));
-        assert!(!Reputation::gold_jurors().contains(&bob));
+        assert!(Reputation::in_juror_pool(JurorTier::Silver, &bob));
+        assert!(!Reputation::in_juror_pool(JurorTier::Gold, &bob));
 
         // Act: A project completion pushes him over the Gold threshold
         System::set_block_number(2);
-        assert_ok!(Reputation::on_project_completed(&bob, 1001, 5000, 1));
+        assert_ok!(Reputation::on_project_completed(&bob, 1001, 5000, 1, &[]));
 
         // Assert: He was automatically moved from Silver to Gold
-        assert!(!Reputation::silver_jurors().contains(&bob));
-        assert!(Reputation::gold_jurors().contains(&bob));
+        assert!(!Reputation::in_juror_pool(JurorTier::Silver, &bob));
+        assert!(Reputation::in_juror_pool(JurorTier::Gold, &bob));
         assert_eq!(Reputation::juror_tier(&bob), JurorTier::Gold);
 
         // Check for the tier update event
@@ -120,21 +119,21 @@ fn juror_tier_allows_single_dispute_loss() {
 
         // Arrange: Give Alice Gold tier stats and register her as a juror
         ReputationStats::<Test>::mutate(&alice, |stats| {
-            stats.projects_completed = 50;
-            stats.total_earned = 50000;
+            stats.freelancer.projects_completed = 50;
+            stats.freelancer.total_earned = 50000;
             stats.disputes_lost = 0;
         });
         assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
-        assert!(Reputation::gold_jurors().contains(&alice)); // Pre-condition check
+        assert!(Reputation::in_juror_pool(JurorTier::Gold, &alice)); // Pre-condition check
 
         // Act: Alice loses a dispute to Bob - with the new logic, a single loss shouldn't disqualify
         System::set_block_number(2);
-        assert_ok!(Reputation::on_dispute_outcome(&bob, &alice, 1, 1000));
+        assert_ok!(Reputation::on_dispute_outcome(&bob, &alice, 1, 1000, &[], ProjectRole::Freelancer));
 
         // Assert: Alice should still be eligible as a juror since she only lost 1 out of 1 disputes (100% loss rate)
         // but with only 1 total dispute, she doesn't meet the "more than 2 disputes" threshold for disqualification
         assert_ne!(Reputation::juror_tier(&alice), JurorTier::Ineligible);
-        assert!(Reputation::gold_jurors().contains(&alice) || Reputation::silver_jurors().contains(&alice) || Reputation::bronze_jurors().contains(&alice));
+        assert!(Reputation::in_juror_pool(JurorTier::Gold, &alice) || Reputation::in_juror_pool(JurorTier::Silver, &alice) || Reputation::in_juror_pool(JurorTier::Bronze, &alice));
         assert!(Reputation::juror_opted_in(&alice));
     });
 }
@@ -154,8 +153,8 @@ fn juror_tier_demotes_with_high_loss_rate() {
 
         // Arrange: Give Alice Bronze tier stats and register her as a juror (lower tier needed to make test more visible)
         ReputationStats::<Test>::mutate(&alice, |stats| {
-            stats.projects_completed = 10; // Bronze level
-            stats.total_earned = 5000;
+            stats.freelancer.projects_completed = 10; // Bronze level
+            stats.freelancer.total_earned = 5000;
             stats.disputes_won = 0; // No wins yet
             stats.disputes_lost = 0; // No losses yet
         });
@@ -165,20 +164,20 @@ fn juror_tier_demotes_with_high_loss_rate() {
         // because loss rate > 50% AND total disputes > 2
         System::set_block_number(2);
         // Alice loses to opponents (so opponents win, Alice loses)
-        assert_ok!(Reputation::on_dispute_outcome(&bob, &alice, 1, 1000)); // Alice loses
-        assert_ok!(Reputation::on_dispute_outcome(&charlie, &alice, 2, 1000)); // Alice loses
-        assert_ok!(Reputation::on_dispute_outcome(&david, &alice, 3, 1000)); // Alice loses
+        assert_ok!(Reputation::on_dispute_outcome(&bob, &alice, 1, 1000, &[], ProjectRole::Freelancer)); // Alice loses
+        assert_ok!(Reputation::on_dispute_outcome(&charlie, &alice, 2, 1000, &[], ProjectRole::Freelancer)); // Alice loses
+        assert_ok!(Reputation::on_dispute_outcome(&david, &alice, 3, 1000, &[], ProjectRole::Freelancer)); // Alice loses
         // Alice wins 1 to show the ratio is 3 losses to 1 win = 75% loss rate
-        assert_ok!(Reputation::on_dispute_outcome(&alice, &bob, 4, 1000)); // Alice wins
+        assert_ok!(Reputation::on_dispute_outcome(&alice, &bob, 4, 1000, &[], ProjectRole::Freelancer)); // Alice wins
 
         // Update tier to reflect new reputation stats
         assert!(Reputation::update_juror_tier(&alice).is_ok());
 
         // Assert: With 3 losses out of 4 total disputes (75% loss rate > 50% and 4 > 2), Alice should be disqualified
         assert_eq!(Reputation::juror_tier(&alice), JurorTier::Ineligible);
-        assert!(!Reputation::gold_jurors().contains(&alice));
-        assert!(!Reputation::silver_jurors().contains(&alice));
-        assert!(!Reputation::bronze_jurors().contains(&alice));
+        assert!(!Reputation::in_juror_pool(JurorTier::Gold, &alice));
+        assert!(!Reputation::in_juror_pool(JurorTier::Silver, &alice));
+        assert!(!Reputation::in_juror_pool(JurorTier::Bronze, &alice));
         assert!(!Reputation::juror_opted_in(&alice));
 
         // Check for the automatic deregistration event
@@ -201,14 +200,14 @@ fn on_project_completed_updates_stats_correctly() {
         assert_ok!(Reputation::register_user(RawOrigin::Signed(freelancer.clone()).into()));
 
         // Act: Simulate a project completion via the trait interface
-        assert_ok!(Reputation::on_project_completed(&freelancer, 500, 4500, 1)); // 4500 = 4.5/5 rating
+        assert_ok!(Reputation::on_project_completed(&freelancer, 500, 4500, 1, &[])); // 4500 = 4.5/5 rating
 
         // Assert: Check that the freelancer's stats were updated
         let stats = Reputation::reputation_stats(&freelancer);
-        assert_eq!(stats.projects_completed, 1);
-        assert_eq!(stats.total_earned, 500);
-        assert_eq!(stats.avg_rating_received, 4500);
-        assert_eq!(stats.total_ratings_received, 1);
+        assert_eq!(stats.freelancer.projects_completed, 1);
+        assert_eq!(stats.freelancer.total_earned, 500);
+        assert_eq!(stats.freelancer.avg_rating_received, 4500);
+        assert_eq!(stats.freelancer.total_ratings_received, 1);
         assert_eq!(stats.last_activity_block, 50);
     });
 }
@@ -225,7 +224,7 @@ fn on_dispute_outcome_updates_stats_correctly() {
         assert_ok!(Reputation::register_user(RawOrigin::Signed(loser.clone()).into()));
         
         // Act: Simulate a dispute outcome
-        assert_ok!(Reputation::on_dispute_outcome(&winner, &loser, 1, 1000));
+        assert_ok!(Reputation::on_dispute_outcome(&winner, &loser, 1, 1000, &[], ProjectRole::Freelancer));
         
         // Assert: Check winner's stats
         let winner_stats = Reputation::reputation_stats(&winner);
@@ -235,7 +234,7 @@ fn on_dispute_outcome_updates_stats_correctly() {
         // Assert: Check loser's stats
         let loser_stats = Reputation::reputation_stats(&loser);
         assert_eq!(loser_stats.disputes_lost, 1);
-        assert_eq!(loser_stats.projects_failed, 1);
+        assert_eq!(loser_stats.freelancer.projects_failed, 1);
         assert_eq!(loser_stats.last_activity_block, 100);
     });
 }
@@ -250,14 +249,14 @@ fn on_jury_vote_updates_stats_correctly() {
         assert_ok!(Reputation::register_user(RawOrigin::Signed(juror.clone()).into()));
         
         // Act: Simulate two jury votes
-        assert_ok!(Reputation::on_jury_vote(&juror, true)); // Voted with majority
-        assert_ok!(Reputation::on_jury_vote(&juror, false)); // Voted against majority
+        assert_ok!(Reputation::on_jury_vote(&juror, true, 1)); // Voted with majority
+        assert_ok!(Reputation::on_jury_vote(&juror, false, 1)); // Voted against majority
 
         // Assert: Check juror's stats
         let juror_stats = Reputation::reputation_stats(&juror);
-        assert_eq!(juror_stats.jury_participation, 2);
+        assert_eq!(juror_stats.juror.jury_participation, 2);
         // After one correct and one incorrect vote, accuracy should be 50%
-        assert_eq!(juror_stats.jury_accuracy, Permill::from_percent(50));
+        assert_eq!(juror_stats.juror.jury_accuracy, Permill::from_percent(50));
         assert_eq!(juror_stats.last_activity_block, 200);
     });
 }
@@ -278,10 +277,10 @@ fn on_jury_vote_updates_stats_correctly() {
             assert_ok!(Reputation::register_user(RawOrigin::Signed(dave.clone()).into()));
 
             // Set stats and update tiers
-            ReputationStats::<Test>::mutate(&alice, |s| { s.projects_completed = 50; s.total_earned = 50000; });
-            ReputationStats::<Test>::mutate(&bob, |s| { s.projects_completed = 5; s.total_earned = 1000; });
-            ReputationStats::<Test>::mutate(&charlie, |s| { s.projects_completed = 1; });
-            ReputationStats::<Test>::mutate(&dave, |s| { s.projects_completed = 6; s.total_earned = 1500; });
+            ReputationStats::<Test>::mutate(&alice, |s| { s.freelancer.projects_completed = 50; s.freelancer.total_earned = 50000; });
+            ReputationStats::<Test>::mutate(&bob, |s| { s.freelancer.projects_completed = 5; s.freelancer.total_earned = 1000; });
+            ReputationStats::<Test>::mutate(&charlie, |s| { s.freelancer.projects_completed = 1; });
+            ReputationStats::<Test>::mutate(&dave, |s| { s.freelancer.projects_completed = 6; s.freelancer.total_earned = 1500; });
 
             assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
             assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(bob.clone()).into()));
@@ -289,7 +288,7 @@ fn on_jury_vote_updates_stats_correctly() {
 
             // Act: Get jurors of at least Bronze tier, excluding Bob
             let exclude_list = vec![bob.clone()];
-            let jurors = Reputation::get_eligible_jurors(JurorTier::Bronze, &exclude_list, 10);
+            let jurors = Reputation::get_eligible_jurors(1, JurorTier::Bronze, &[], &exclude_list, 10, &[]);
 
             // Assert: Should contain Alice and Dave, but not Bob (excluded) or Charlie (ineligible)
             assert_eq!(jurors.len(), 2);
@@ -300,6 +299,696 @@ fn on_jury_vote_updates_stats_correctly() {
         });
     }
 
+#[test]
+fn get_eligible_jurors_skips_conflicted_jurors() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(10);
+        let bob = account("bob");
+        let dave = account("dave");
+        let client = account("carol");
+        let freelancer = account("erin");
+        for who in [&bob, &dave, &client, &freelancer] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+        }
+        for who in [&bob, &dave] {
+            ReputationStats::<Test>::mutate(who, |s| { s.freelancer.projects_completed = 5; s.freelancer.total_earned = 1000; });
+            assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(who.clone()).into()));
+        }
+        let parties = [client.clone(), freelancer.clone()];
+
+        // Bob was hired by the client; Dave holds an attestation for a project the freelancer also worked on.
+        assert_ok!(Reputation::on_work_started(&client, &bob, 1));
+        assert_ok!(Reputation::on_project_completed(&dave, 100, 4000, 7, &[]));
+        assert_ok!(Reputation::on_project_completed(&freelancer, 100, 4000, 7, &[]));
+
+        assert!(Reputation::has_conflict_of_interest(&bob, &parties));
+        assert!(Reputation::has_conflict_of_interest(&dave, &parties));
+        assert!(Reputation::get_eligible_jurors(1, JurorTier::Bronze, &parties, &[], 10, &[]).is_empty());
+
+        // Both relationships fall out of the conflict window after `ConflictWindow` blocks.
+        System::set_block_number(1011);
+        assert!(!Reputation::has_conflict_of_interest(&bob, &parties));
+        let jurors = Reputation::get_eligible_jurors(1, JurorTier::Bronze, &parties, &[], 10, &[]);
+        assert_eq!(jurors.len(), 2);
+    });
+}
+
+#[test]
+fn ratings_and_disputes_decay_with_age() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(bob.clone()).into()));
+        // Half of the weight is lost every `DecayPeriod` (100 blocks in the mock).
+        assert_ok!(Reputation::update_weights(RawOrigin::Root.into(), 100, 100, 100, 100, Permill::from_percent(50), 100));
+
+        assert_ok!(Reputation::on_project_completed(&alice, 100, 5000, 1, &[]));
+        assert_ok!(Reputation::on_dispute_outcome(&alice, &bob, 2, 100, &[], ProjectRole::Freelancer));
+        let rate = Reputation::reputation_weights().time_decay_rate;
+        let fresh = Reputation::current_decayed_metrics(&alice, &Reputation::reputation_stats(&alice), 1, rate);
+        assert_eq!((fresh.rating_weight, fresh.disputes_won), (1000, 1000));
+        let fresh_score = Reputation::calculate_reputation_score(&alice).unwrap();
+
+        System::set_block_number(201);
+        let aged = Reputation::current_decayed_metrics(&alice, &Reputation::reputation_stats(&alice), 201, rate);
+        assert_eq!((aged.rating_weight, aged.disputes_won), (250, 250));
+        assert!(Reputation::calculate_reputation_score(&alice).unwrap() < fresh_score);
+
+        // The loser's penalty fades as well.
+        let bob_aged = Reputation::current_decayed_metrics(&bob, &Reputation::reputation_stats(&bob), 201, rate);
+        assert_eq!(bob_aged.disputes_lost, 250);
+    });
+}
+
+#[test]
+fn jury_weight_feeds_jury_accuracy_into_score() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let juror = account("juror");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(juror.clone()).into()));
+        for _ in 0..10 {
+            assert_ok!(Reputation::on_jury_vote(&juror, true, 1));
+        }
+        let without_jury = Reputation::calculate_reputation_score(&juror).unwrap();
+
+        assert_ok!(Reputation::update_weights(RawOrigin::Root.into(), 0, 0, 0, 100, Permill::zero(), 0));
+        // Full accuracy over ten votes is worth the full 1000 points.
+        assert_eq!(Reputation::calculate_reputation_score(&juror).unwrap(), without_jury + 1000);
+    });
+}
+
+#[test]
+fn global_stats_follow_score_histogram() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        let carol = account("carol");
+        for who in [&alice, &bob, &carol] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+        }
+        assert_ok!(Reputation::on_project_completed(&alice, 100, 5000, 1, &[]));
+        assert_eq!(Reputation::last_score(&alice), Some(2110));
+        assert_eq!(Reputation::last_score(&bob), Some(1500));
+
+        Reputation::on_idle(1, Weight::MAX);
+        System::assert_has_event(Event::GlobalStatsUpdated.into());
+        let stats = Reputation::global_stats();
+        assert_eq!(stats.total_users, 3);
+        assert_eq!(stats.average_score, (2110 + 1500 + 1500) / 3);
+        assert_eq!(stats.median_score, 1500);
+        assert_eq!(stats.min_score, 1500);
+        assert_eq!(stats.max_score, 2199);
+
+        assert_eq!(Reputation::percentile_of(&alice), Some(Percent::from_percent(100)));
+        assert_eq!(Reputation::percentile_of(&bob), Some(Percent::from_rational(2u64, 3u64)));
+        assert_eq!(Reputation::percentile_of(&account("nobody")), None);
+    });
+}
+
+#[test]
+fn on_idle_scores_accounts_added_without_a_hook() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let dave = account("dave");
+        // e.g. accounts from the genesis config never went through `refresh_score`
+        ReputationStats::<Test>::mutate(&dave, |s| { s.registration_block = 1; s.last_activity_block = 1; });
+        assert_eq!(Reputation::last_score(&dave), None);
+
+        Reputation::on_idle(1, Weight::MAX);
+        assert_eq!(Reputation::last_score(&dave), Some(1500));
+        assert_eq!(Reputation::global_stats().total_users, 1);
+        // A full pass resets the cursor.
+        assert_eq!(Reputation::rescore_cursor(), None);
+    });
+}
+
+#[test]
+fn client_activity_does_not_qualify_for_jury() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+
+        // Posting and accepting plenty of work only fills the client ledger.
+        for project_id in 0..60 {
+            assert_ok!(Reputation::on_project_created(&alice, 2_000));
+            assert_ok!(Reputation::on_work_accepted(&alice, project_id));
+        }
+
+        let stats = Reputation::reputation_stats(&alice);
+        assert_eq!(stats.client.projects_posted, 60);
+        assert_eq!(stats.client.projects_completed, 60);
+        assert_eq!(stats.client.total_spent, 120_000);
+        assert_eq!(stats.freelancer.projects_completed, 0);
+        assert_eq!(Reputation::calculate_tier(&alice), JurorTier::Ineligible);
+        assert_noop!(
+            Reputation::register_as_juror(RawOrigin::Signed(alice).into()),
+            Error::<Test>::InsufficientTier
+        );
+    });
+}
+
+#[test]
+fn freelancer_ratings_feed_client_reputation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::on_project_created(&alice, 1_000));
+        assert_ok!(Reputation::on_work_accepted(&alice, 0));
+        let unrated = Reputation::calculate_client_reputation(&alice).unwrap();
+
+        assert_ok!(Reputation::on_client_rated(&alice, 1_000, 4_000, 0));
+        System::assert_last_event(Event::ClientRated { client: alice.clone(), project_id: 0 }.into());
+
+        let stats = Reputation::reputation_stats(&alice);
+        assert_eq!(stats.client.avg_rating_received, 4_000);
+        assert_eq!(stats.client.total_ratings_received, 1);
+        // Client ratings stay off the freelancer ledger
+        assert_eq!(stats.freelancer.total_ratings_received, 0);
+
+        let attestation = Reputation::attestations(&alice, 0).unwrap();
+        assert_eq!(attestation.attestor, AttestorType::FreelancerRating);
+        assert_eq!(attestation.outcome, AttestationOutcome::Positive);
+
+        // One 4-star rating out of the 5 needed for full credit
+        assert_eq!(Reputation::calculate_client_reputation(&alice).unwrap(), unrated + 320);
+
+        assert_noop!(Reputation::on_client_rated(&alice, 1_000, 5_001, 1), Error::<Test>::InvalidScore);
+    });
+}
+
+fn db_weight() -> frame_support::weights::RuntimeDbWeight {
+    <Test as frame_system::Config>::DbWeight::get()
+}
+
+/// Step `M` to completion with room for `items` entries of `per_item` weight per step.
+/// Returns the number of steps taken.
+fn migrate_in_steps<M: frame_support::migrations::SteppedMigration>(per_item: Weight, items: u64) -> u32 {
+    use frame_support::weights::WeightMeter;
+
+    let mut cursor = None;
+    let mut steps = 0;
+    loop {
+        let mut meter = WeightMeter::with_limit(per_item.saturating_mul(items));
+        cursor = M::step(cursor, &mut meter).unwrap();
+        steps += 1;
+        if cursor.is_none() {
+            return steps;
+        }
+    }
+}
+
+#[test]
+fn migration_to_v1_splits_flat_stats_into_role_ledgers() {
+    use crate::migrations::v1::{LazyMigrationV1, OldReputationData};
+    use codec::Encode;
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let alice = account("alice");
+        let old = OldReputationData::<u64, u64> {
+            registration_block: 1,
+            last_activity_block: 5,
+            // 8 rated deliveries plus 4 accepted client projects
+            projects_completed: 12,
+            // 1 lost dispute plus 2 cancelled client projects
+            projects_failed: 3,
+            total_earned: 8_000,
+            projects_posted: 6,
+            total_spent: 3_000,
+            disputes_initiated: 1,
+            disputes_won: 0,
+            disputes_lost: 1,
+            avg_rating_received: 4_500,
+            total_ratings_received: 8,
+            jury_participation: 4,
+            jury_accuracy: Permill::from_percent(75),
+        };
+        frame_support::storage::unhashed::put_raw(
+            &ReputationStats::<Test>::hashed_key_for(&alice),
+            &old.encode(),
+        );
+        StorageVersion::new(0).put::<Reputation>();
+
+        migrate_in_steps::<LazyMigrationV1<Test>>(db_weight().reads_writes(1, 1), 1);
+
+        let stats = Reputation::reputation_stats(&alice);
+        assert_eq!(stats.last_activity_block, 5);
+        assert_eq!(stats.freelancer.projects_completed, 8);
+        assert_eq!(stats.freelancer.projects_failed, 1);
+        assert_eq!(stats.freelancer.total_earned, 8_000);
+        assert_eq!(stats.freelancer.avg_rating_received, 4_500);
+        assert_eq!(stats.client.projects_posted, 6);
+        assert_eq!(stats.client.projects_completed, 4);
+        assert_eq!(stats.client.projects_cancelled, 2);
+        assert_eq!(stats.client.total_spent, 3_000);
+        assert_eq!(stats.juror.jury_participation, 4);
+        assert_eq!(stats.juror.jury_accuracy, Permill::from_percent(75));
+        assert_eq!(stats.disputes_lost, 1);
+        assert_eq!(Reputation::on_chain_storage_version(), 1);
+        // Tiers are left to `on_idle` rather than recomputed with current pallet logic
+        assert!(crate::pallet::RetierPending::<Test>::get());
+
+        // Running it again is a no-op.
+        migrate_in_steps::<LazyMigrationV1<Test>>(db_weight().reads_writes(1, 1), 1);
+        assert_eq!(Reputation::reputation_stats(&alice).freelancer.projects_completed, 8);
+    });
+}
+
+fn create_credential_collection() {
+    let _ = Balances::deposit_creating(&Reputation::account_id(), 1_000);
+    assert_ok!(Reputation::create_credential_collection(RawOrigin::Root.into()));
+    System::assert_last_event(Event::CredentialCollectionCreated { collection: 0 }.into());
+}
+
+#[test]
+fn completed_project_mints_soulbound_credential() {
+    use codec::Encode;
+    use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let bob = account("bob");
+        let charlie = account("charlie");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(bob.clone()).into()));
+        create_credential_collection();
+        assert_noop!(
+            Reputation::create_credential_collection(RawOrigin::Root.into()),
+            Error::<Test>::CredentialCollectionExists
+        );
+
+        assert_ok!(Reputation::on_project_completed(&bob, 500, 4500, 7, &[]));
+
+        // The credential mirrors the attestation and cannot be moved
+        assert_eq!(Reputation::project_credentials(&bob, 7), Some(0));
+        assert_eq!(<Nfts as Inspect<_>>::owner(&0, &0), Some(bob.clone()));
+        assert_eq!(<Nfts as Inspect<_>>::system_attribute(&0, Some(&0), b"project_id"), Some(7u32.encode()));
+        assert_eq!(<Nfts as Inspect<_>>::system_attribute(&0, Some(&0), b"value"), Some(500u64.encode()));
+        assert_noop!(
+            Nfts::transfer(RawOrigin::Signed(bob.clone()).into(), 0, 0, charlie),
+            pallet_nfts::Error::<Test>::ItemsNonTransferable
+        );
+
+        // Governance revokes it, e.g. after a successful appeal
+        assert_ok!(Reputation::revoke_credential(RawOrigin::Root.into(), bob.clone(), 7));
+        System::assert_last_event(Event::CredentialRevoked { account: bob.clone(), item: 0 }.into());
+        assert_eq!(<Nfts as Inspect<_>>::owner(&0, &0), None);
+        assert_noop!(
+            Reputation::revoke_credential(RawOrigin::Root.into(), bob, 7),
+            Error::<Test>::NoNFTFound
+        );
+    });
+}
+
+#[test]
+fn reaching_a_juror_tier_mints_a_credential_once() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+
+        // Without a collection completions still succeed, they just mint nothing
+        assert_ok!(Reputation::on_project_completed(&alice, 500, 4500, 0, &[]));
+        assert_eq!(Reputation::project_credentials(&alice, 0), None);
+
+        create_credential_collection();
+        ReputationStats::<Test>::mutate(&alice, |s| {
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
+        });
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_eq!(Reputation::tier_credentials(&alice, JurorTier::Bronze), Some(0));
+
+        // Re-joining the same tier does not mint again
+        assert_ok!(Reputation::deregister_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_eq!(Reputation::next_credential_id(), 1);
+    });
+}
+
+#[test]
+fn skill_reputation_ranks_and_draws_domain_experts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let rust: Skill = b"rust".to_vec().try_into().unwrap();
+        let design: Skill = b"design".to_vec().try_into().unwrap();
+        let bob = account("bob");
+        let dave = account("dave");
+        for who in [&bob, &dave] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+            ReputationStats::<Test>::mutate(who, |s| { s.freelancer.projects_completed = 5; s.freelancer.total_earned = 1000; });
+            assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(who.clone()).into()));
+        }
+
+        // Dave has a Rust track record, Bob only a design one that he then loses a dispute in
+        assert_ok!(Reputation::on_project_completed(&dave, 100, 5000, 1, &[rust.clone()]));
+        assert_ok!(Reputation::on_project_completed(&bob, 100, 4000, 2, &[design.clone()]));
+        assert_ok!(Reputation::on_dispute_outcome(&dave, &bob, 3, 100, &[design.clone()], ProjectRole::Freelancer));
+
+        let stats = Reputation::skill_stats(&dave, &rust);
+        assert_eq!(stats.projects_completed, 1);
+        assert_eq!(stats.avg_rating, 5000);
+        assert_eq!(Reputation::skill_stats(&bob, &design).disputes_lost, 1);
+        assert_eq!(Reputation::skill_stats(&dave, &design).disputes_won, 1);
+
+        assert!(Reputation::skill_score(&dave, &[rust.clone()]) > Reputation::skill_score(&bob, &[rust.clone()]));
+        assert_eq!(Reputation::skill_score(&bob, &[]), 0);
+
+        // A single seat goes to the Rust expert regardless of rotation order
+        for _ in 0..2 {
+            let jurors = Reputation::get_eligible_jurors(1, JurorTier::Bronze, &[], &[], 1, &[rust.clone()]);
+            assert_eq!(jurors.to_vec(), vec![dave.clone()]);
+        }
+        // Non-experts still fill the remaining seats
+        assert_eq!(Reputation::get_eligible_jurors(1, JurorTier::Bronze, &[], &[], 2, &[rust]).len(), 2);
+    });
+}
+
+#[test]
+fn partner_chains_query_reputation_and_submit_attestations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::on_project_completed(&alice, 500, 4500, 1, &[]));
+
+        // Parachain 2000 is not whitelisted yet
+        assert_noop!(
+            Reputation::query_reputation(RawOrigin::Root.into(), alice.clone()),
+            Error::<Test>::UnknownPartnerChain
+        );
+        assert_noop!(
+            Reputation::set_partner_chain(RawOrigin::Signed(alice.clone()).into(), 2000, Some([42, 3])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Reputation::set_partner_chain(RawOrigin::Root.into(), 2000, Some([42, 3])));
+
+        // Queries and pushes both go to the partner's callback; pushing costs the caller `PublishFee`
+        assert_ok!(Reputation::query_reputation(RawOrigin::Root.into(), alice.clone()));
+        assert_noop!(
+            Reputation::publish_reputation(RawOrigin::Signed(alice.clone()).into(), 2000),
+            sp_runtime::ArithmeticError::Underflow
+        );
+        let _ = Balances::deposit_creating(&alice, 100);
+        assert_ok!(Reputation::publish_reputation(RawOrigin::Signed(alice.clone()).into(), 2000));
+        assert_eq!(Balances::free_balance(&alice), 90);
+        assert_eq!(Balances::free_balance(Reputation::account_id()), 10);
+        assert_noop!(
+            Reputation::publish_reputation(RawOrigin::Signed(alice.clone()).into(), 3000),
+            Error::<Test>::UnknownPartnerChain
+        );
+        let expected = Reputation::reputation_summary(&alice).unwrap();
+        assert_eq!(expected.projects_completed, 1);
+        SENT_SUMMARIES.with(|sent| {
+            assert_eq!(*sent.borrow(), vec![(2000, [42, 3], expected.clone()), (2000, [42, 3], expected)]);
+        });
+        System::assert_last_event(Event::ReputationSent { account: alice.clone(), para_id: 2000 }.into());
+
+        // Inbound attestations are stored under the partner's attestor type
+        assert_ok!(Reputation::submit_partner_attestation(
+            RawOrigin::Root.into(), alice.clone(), 9, AttestationOutcome::Positive, 300, 4000
+        ));
+        let attestation = Reputation::attestations(&alice, 1).unwrap();
+        assert_eq!(attestation.attestor, AttestorType::PartnerChain(2000));
+        assert_eq!(attestation.project_id, 9);
+        assert_eq!(attestation.value, 300);
+        // Partner attestations sit next to local ones and leave stats untouched
+        assert_eq!(Reputation::attestations(&alice, 0).unwrap().attestor, AttestorType::ClientApproval);
+        assert_eq!(Reputation::reputation_stats(&alice).freelancer.projects_completed, 1);
+
+        // Removing the partner revokes its access
+        assert_ok!(Reputation::set_partner_chain(RawOrigin::Root.into(), 2000, None));
+        assert_noop!(
+            Reputation::submit_partner_attestation(RawOrigin::Root.into(), alice.clone(), 10, AttestationOutcome::Positive, 300, 4000),
+            Error::<Test>::UnknownPartnerChain
+        );
+    });
+}
+
+#[test]
+fn attestations_keep_full_history_per_project() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        for who in [&alice, &bob] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+        }
+
+        // Completion, then a dispute and jury service on the same project id
+        assert_ok!(Reputation::on_project_completed(&alice, 500, 4500, 3, &[]));
+        assert_ok!(Reputation::on_dispute_outcome(&alice, &bob, 3, 500, &[], ProjectRole::Freelancer));
+        assert_ok!(Reputation::on_jury_vote(&bob, false, 3));
+
+        assert_eq!(Reputation::attestation_count(&alice), 2);
+        let completion = Reputation::attestations(&alice, 0).unwrap();
+        assert_eq!(completion.attestor, AttestorType::ClientApproval);
+        assert_eq!(completion.rating, Some(4500));
+        assert!(completion.metadata.is_empty());
+        let win = Reputation::attestations(&alice, 1).unwrap();
+        assert_eq!((win.attestor, win.outcome, win.rating), (AttestorType::ArbitrationWin, AttestationOutcome::Positive, None));
+
+        let loss = Reputation::attestations(&bob, 0).unwrap();
+        assert_eq!((loss.attestor, loss.outcome), (AttestorType::ArbitrationLoss, AttestationOutcome::Negative));
+        let jury = Reputation::attestations(&bob, 1).unwrap();
+        assert_eq!((jury.attestor, jury.outcome, jury.project_id), (AttestorType::JuryParticipation, AttestationOutcome::Negative, 3));
+        System::assert_last_event(Event::AttestationCreated { account: bob.clone(), nonce: 1, attestor: AttestorType::JuryParticipation }.into());
+    });
+}
+
+#[test]
+fn migration_to_v2_rekeys_attestations_by_nonce() {
+    use crate::migrations::v2::{LazyMigrationV2, OldAttestation};
+    use codec::Encode;
+    use frame_support::{storage::StoragePrefixedMap, traits::{GetStorageVersion, StorageVersion}, Blake2_128Concat, StorageHasher};
+
+    new_test_ext().execute_with(|| {
+        let alice = account("alice");
+        let old_key = |project_id: u32| {
+            [
+                crate::Attestations::<Test>::final_prefix().to_vec(),
+                Blake2_128Concat::hash(&alice.encode()),
+                Blake2_128Concat::hash(&project_id.encode()),
+            ].concat()
+        };
+        let old = |attestor, timestamp: u64, rating: u32| OldAttestation::<Test> {
+            attestor,
+            project_id: 0,
+            outcome: AttestationOutcome::Positive,
+            value: 100,
+            timestamp,
+            metadata: rating.encode().try_into().unwrap(),
+        };
+        // Stored out of order; nonces follow the timestamps
+        frame_support::storage::unhashed::put_raw(&old_key(8), &OldAttestation { project_id: 8, ..old(AttestorType::FreelancerRating, 9, 3000) }.encode());
+        frame_support::storage::unhashed::put_raw(&old_key(5), &OldAttestation { project_id: 5, ..old(AttestorType::ClientApproval, 2, 4000) }.encode());
+        StorageVersion::new(1).put::<Reputation>();
+
+        // One attestation per step: two to stage, one to switch stages, two to re-key, one to finish
+        let steps = migrate_in_steps::<LazyMigrationV2<Test>>(db_weight().reads_writes(2, 4), 1);
+        assert_eq!(steps, 6);
+
+        assert_eq!(Reputation::on_chain_storage_version(), 2);
+        assert_eq!(Reputation::attestation_count(&alice), 2);
+        let first = Reputation::attestations(&alice, 0).unwrap();
+        assert_eq!((first.project_id, first.rating, first.attestor), (5, Some(4000), AttestorType::ClientApproval));
+        assert!(first.metadata.is_empty());
+        let second = Reputation::attestations(&alice, 1).unwrap();
+        assert_eq!((second.project_id, second.rating), (8, Some(3000)));
+        assert_eq!(crate::ProjectAttestations::<Test>::get((&alice, 8, 1)), Some(AttestorType::FreelancerRating));
+
+        // Running it again is a no-op.
+        migrate_in_steps::<LazyMigrationV2<Test>>(db_weight().reads_writes(2, 4), 1);
+        assert_eq!(Reputation::attestation_count(&alice), 2);
+    });
+}
+
+#[test]
+fn score_breakdown_and_attestation_pages() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        assert!(matches!(Reputation::score_breakdown(&alice), Err(Error::<Test>::UserNotRegistered)));
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        for project_id in 0..5 {
+            assert_ok!(Reputation::on_project_completed(&alice, 500, 4000, project_id, &[]));
+        }
+
+        let breakdown = Reputation::score_breakdown(&alice).unwrap();
+        let sum = breakdown.completion + breakdown.rating + breakdown.volume + breakdown.activity
+            + breakdown.dispute + breakdown.arbitration + breakdown.jury;
+        assert_eq!(breakdown.total, sum.min(10000));
+        assert_eq!(Reputation::calculate_reputation_score(&alice).ok(), Some(breakdown.total));
+
+        let page = Reputation::attestation_page(&alice, 1, 3);
+        assert_eq!(page.iter().map(|record| record.nonce).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!((page[0].project_id, page[0].rating), (1, Some(4000)));
+        assert_eq!(Reputation::attestation_page(&alice, 3, 10).len(), 2);
+        assert!(Reputation::attestation_page(&alice, 5, 10).is_empty());
+        assert_eq!(Reputation::attestation_page(&alice, 0, u32::MAX).len(), 5);
+    });
+}
+
+#[test]
+fn governance_tier_thresholds_retier_jurors_on_idle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        for who in [&alice, &bob] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+            ReputationStats::<Test>::mutate(who, |s| {
+                s.freelancer.projects_completed = 10;
+                s.freelancer.total_earned = 2_000;
+            });
+            let _ = Balances::deposit_creating(who, 1_000);
+        }
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_eq!(Reputation::juror_tier(&alice), JurorTier::Bronze);
+
+        // Gold now takes 10 projects and a track record on juries; Bronze takes a stake
+        let thresholds = TierThresholds {
+            bronze: TierRequirement { min_projects: 1, min_stake: 500, ..Default::default() },
+            silver: TierRequirement { min_projects: 1_000, ..Default::default() },
+            gold: TierRequirement {
+                min_projects: 10,
+                min_jury_votes: 2,
+                min_jury_accuracy: Permill::from_percent(60),
+                ..Default::default()
+            },
+            ..TierThresholds::in_units(1)
+        };
+        assert_noop!(
+            Reputation::set_tier_thresholds(RawOrigin::Signed(alice.clone()).into(), thresholds.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Reputation::set_tier_thresholds(RawOrigin::Root.into(), thresholds));
+        System::assert_last_event(Event::TierThresholdsUpdated.into());
+        assert_noop!(
+            Reputation::register_as_juror(RawOrigin::Signed(bob.clone()).into()),
+            Error::<Test>::InsufficientTier
+        );
+
+        // Alice has no jury record yet and her zero stake is below Bronze's minimum
+        Reputation::on_idle(1, Weight::MAX);
+        assert!(!Reputation::juror_opted_in(&alice));
+        assert_eq!(Balances::reserved_balance(&alice), 0);
+        assert_eq!(Reputation::juror_pool_size(JurorTier::Bronze), 0);
+
+        // Accurate jurors qualify for Gold, inaccurate ones fall back to the lower tiers
+        ReputationStats::<Test>::mutate(&bob, |s| {
+            s.juror.jury_participation = 4;
+            s.juror.jury_accuracy = Permill::from_percent(75);
+        });
+        assert_eq!(Reputation::calculate_tier(&bob), JurorTier::Gold);
+        ReputationStats::<Test>::mutate(&bob, |s| s.juror.jury_accuracy = Permill::from_percent(50));
+        assert_eq!(Reputation::calculate_tier(&bob), JurorTier::Ineligible);
+
+        // Losing most disputes disqualifies regardless of the tier requirements
+        ReputationStats::<Test>::mutate(&bob, |s| {
+            s.juror.jury_accuracy = Permill::from_percent(75);
+            s.disputes_won = 1;
+            s.disputes_lost = 2;
+        });
+        assert_eq!(Reputation::calculate_tier(&bob), JurorTier::Ineligible);
+    });
+}
+
+#[test]
+fn juror_pools_swap_remove_and_keep_drawing() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let jurors = [account("alice"), account("bob"), account("charlie")];
+        for juror in &jurors {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(juror.clone()).into()));
+            ReputationStats::<Test>::mutate(juror, |s| {
+                s.freelancer.projects_completed = 10;
+                s.freelancer.total_earned = 2_000;
+            });
+            assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(juror.clone()).into()));
+        }
+        assert_eq!(Reputation::juror_pool_size(JurorTier::Bronze), 3);
+        assert_eq!(Reputation::juror_pool_index(&jurors[2]), Some(2));
+
+        // Charlie moves into Alice's slot
+        assert_ok!(Reputation::deregister_as_juror(RawOrigin::Signed(jurors[0].clone()).into()));
+        assert_eq!(Reputation::juror_pool_size(JurorTier::Bronze), 2);
+        assert_eq!(Reputation::juror_at(JurorTier::Bronze, 0), Some(jurors[2].clone()));
+        assert_eq!(Reputation::juror_pool_index(&jurors[2]), Some(0));
+        assert_eq!(Reputation::juror_at(JurorTier::Bronze, 2), None);
+        assert_eq!(Reputation::juror_pool_index(&jurors[0]), None);
+
+        let mut drawn = Reputation::get_eligible_jurors(1, JurorTier::Bronze, &[], &[], 3, &[]).into_inner();
+        drawn.sort();
+        assert_eq!(drawn, vec![jurors[1].clone(), jurors[2].clone()]);
+    });
+}
+
+#[test]
+fn migration_to_v3_moves_juror_pools_into_maps() {
+    use crate::migrations::v3::{old, LazyMigrationV3};
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let alice = account("alice");
+        let bob = account("bob");
+        let charlie = account("charlie");
+        crate::JurorTiers::<Test>::insert(&alice, JurorTier::Gold);
+        crate::JurorTiers::<Test>::insert(&bob, JurorTier::Bronze);
+        crate::JurorTiers::<Test>::insert(&charlie, JurorTier::Bronze);
+        old::GoldJurors::<Test>::put(vec![alice.clone()]);
+        // Bob left the jury but is still listed
+        old::BronzeJurors::<Test>::put(vec![charlie.clone(), account("dave"), bob.clone()]);
+        StorageVersion::new(2).put::<Reputation>();
+
+        migrate_in_steps::<LazyMigrationV3<Test>>(db_weight().reads_writes(2, 3), 2);
+
+        assert_eq!(Reputation::on_chain_storage_version(), 3);
+        assert!(!old::GoldJurors::<Test>::exists());
+        assert!(!old::BronzeJurors::<Test>::exists());
+        assert!(Reputation::in_juror_pool(JurorTier::Gold, &alice));
+        assert_eq!(Reputation::juror_pool_size(JurorTier::Silver), 0);
+        assert_eq!(Reputation::juror_pool_size(JurorTier::Bronze), 2);
+        assert_eq!(Reputation::juror_at(JurorTier::Bronze, 0), Some(charlie));
+        assert_eq!(Reputation::juror_at(JurorTier::Bronze, 1), Some(bob));
+    });
+}
+
+#[test]
+fn migration_to_v4_builds_stake_trees_in_steps() {
+    use crate::migrations::v4::{old, LazyMigrationV4};
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let jurors = [account("alice"), account("bob"), account("charlie")];
+        for (index, juror) in jurors.iter().enumerate() {
+            crate::JurorPools::<Test>::insert(JurorTier::Silver, index as u32, juror);
+        }
+        crate::JurorPoolSize::<Test>::insert(JurorTier::Silver, 3);
+        crate::StakeOf::<Test>::insert(&jurors[0], 100);
+        crate::StakeOf::<Test>::insert(&jurors[1], 40);
+        crate::DelegatedStake::<Test>::insert(&jurors[1], 10);
+        old::NextJurorIndex::<Test>::insert(JurorTier::Silver, 2);
+        StorageVersion::new(3).put::<Reputation>();
+
+        // One juror or exhausted pool per step: three jurors and three pools, then the version
+        let steps = migrate_in_steps::<LazyMigrationV4<Test>>(db_weight().reads_writes(22, 18), 1);
+        assert_eq!(steps, 6);
+
+        assert_eq!(Reputation::on_chain_storage_version(), 4);
+        // Charlie has no stake but still weighs one unit
+        assert_eq!(Reputation::pool_stake(JurorTier::Silver), 151);
+        assert_eq!(crate::PoolStakeTree::<Test>::get(JurorTier::Silver, 2), 150);
+        assert_eq!(crate::PoolStakeTree::<Test>::get(JurorTier::Silver, 3), 1);
+        assert_eq!(Reputation::pool_stake(JurorTier::Gold), 0);
+        assert!(!old::NextJurorIndex::<Test>::contains_key(JurorTier::Silver));
+    });
+}
+
     // ------- staking & slashing tests -------
 
 #[test]
@@ -308,8 +997,8 @@ fn register_as_juror_reserves_stake() {
         let alice = account("alice");
         assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
         ReputationStats::<Test>::mutate(&alice, |s| {
-            s.projects_completed = 10;
-            s.total_earned = 2_000;
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
         });
 
         let stake_amount = Reputation::juror_stake();
@@ -323,24 +1012,38 @@ fn register_as_juror_reserves_stake() {
 }
 
 #[test]
-fn deregister_as_juror_unreserves_stake() {
+fn deregister_as_juror_unbonds_stake() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         let alice = account("alice");
         assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
         ReputationStats::<Test>::mutate(&alice, |s| {
-            s.projects_completed = 10;
-            s.total_earned = 2_000;
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
         });
 
-        let stake = Reputation::juror_stake();
+        let stake = 500u64;
+        JurorStake::<Test>::put(stake);
         let _ = Balances::deposit_creating(&alice, stake + 1_000);
 
         assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
         assert_ok!(Reputation::deregister_as_juror(RawOrigin::Signed(alice.clone()).into()));
 
-        assert_eq!(Balances::reserved_balance(&alice), 0);
         assert_eq!(Reputation::stake_of(&alice), None);
         assert!(!Reputation::juror_opted_in(&alice));
+        // The stake stays reserved until the unbonding period is over
+        assert_eq!(Balances::reserved_balance(&alice), stake);
+        assert_eq!(Reputation::unlocking(&alice).into_inner(), vec![UnlockChunk { amount: stake, unlock_at: 101 }]);
+        assert_noop!(
+            Reputation::withdraw_unbonded(RawOrigin::Signed(alice.clone()).into()),
+            Error::<Test>::NothingToWithdraw
+        );
+
+        System::set_block_number(101);
+        assert_ok!(Reputation::withdraw_unbonded(RawOrigin::Signed(alice.clone()).into()));
+        assert_eq!(Balances::reserved_balance(&alice), 0);
+        assert!(Reputation::unlocking(&alice).is_empty());
+        System::assert_last_event(Event::StakeWithdrawn { account: alice, amount: stake }.into());
     });
 }
 
@@ -350,8 +1053,8 @@ fn deregister_fails_if_juror_busy() {
         let alice = account("alice");
         assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
         ReputationStats::<Test>::mutate(&alice, |s| {
-            s.projects_completed = 10;
-            s.total_earned = 2_000;
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
         });
         let stake = Reputation::juror_stake();
         let _ = Balances::deposit_creating(&alice, stake + 1_000);
@@ -374,8 +1077,8 @@ fn slash_juror_partial_slash_works() {
         System::set_block_number(1);
         assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
         ReputationStats::<Test>::mutate(&alice, |s| {
-            s.projects_completed = 10;
-            s.total_earned = 2_000;
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
         });
 
         // --- FIX: Set a non-zero stake for the test ---
@@ -396,3 +1099,422 @@ fn slash_juror_partial_slash_works() {
         System::assert_last_event(Event::JurorSlashed { account: alice, amount: slash }.into());
     });
 }
+
+#[test]
+fn bond_extra_raises_tier_and_draw_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        JurorStake::<Test>::put(100);
+        for who in [&alice, &bob] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+            ReputationStats::<Test>::mutate(who, |s| {
+                s.freelancer.projects_completed = 10;
+                s.freelancer.total_earned = 2_000;
+            });
+            let _ = Balances::deposit_creating(who, 10_000);
+            assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(who.clone()).into()));
+        }
+        // Silver only differs from Bronze by its stake requirement
+        assert_ok!(Reputation::set_tier_thresholds(RawOrigin::Root.into(), TierThresholds {
+            bronze: TierRequirement { min_projects: 5, ..Default::default() },
+            silver: TierRequirement { min_projects: 5, min_stake: 1_000, ..Default::default() },
+            gold: TierRequirement { min_projects: 1_000, ..Default::default() },
+            ..TierThresholds::in_units(1)
+        }));
+        assert_noop!(
+            Reputation::bond_extra(RawOrigin::Signed(account("charlie")).into(), 100),
+            Error::<Test>::NotRegisteredAsJuror
+        );
+
+        assert_ok!(Reputation::bond_extra(RawOrigin::Signed(alice.clone()).into(), 900));
+        assert_eq!(Reputation::stake_of(&alice), Some(1_000));
+        assert_eq!(Balances::reserved_balance(&alice), 1_000);
+        assert_eq!(Reputation::juror_tier(&alice), JurorTier::Silver);
+        System::assert_last_event(Event::JurorBonded { account: alice.clone(), amount: 900 }.into());
+
+        // Bob alone in Bronze, with three times Alice's old stake
+        assert_ok!(Reputation::bond_extra(RawOrigin::Signed(bob.clone()).into(), 200));
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 300);
+        assert_eq!(Reputation::pool_stake(JurorTier::Silver), 1_000);
+
+        // Unbonding below the minimum is refused; down to it moves Alice back to Bronze
+        assert_noop!(
+            Reputation::unbond(RawOrigin::Signed(alice.clone()).into(), 901),
+            Error::<Test>::StakeTooLow
+        );
+        assert_ok!(Reputation::unbond(RawOrigin::Signed(alice.clone()).into(), 900));
+        assert_eq!(Reputation::juror_tier(&alice), JurorTier::Bronze);
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 400);
+        assert_eq!(Reputation::pool_stake(JurorTier::Silver), 0);
+        assert_eq!(Balances::reserved_balance(&alice), 1_000);
+
+        // Draws over the Bronze pool hit Bob about three times as often as Alice
+        let mut draws = [0u32; 2];
+        for _ in 0..40 {
+            let drawn = Reputation::get_eligible_jurors(1, JurorTier::Bronze, &[], &[], 1, &[]);
+            draws[usize::from(drawn[0] == alice)] += 1;
+        }
+        assert_eq!(draws, [29, 11]);
+    });
+}
+
+#[test]
+fn slashing_reaches_unbonding_stake() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        ReputationStats::<Test>::mutate(&alice, |s| {
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
+        });
+        JurorStake::<Test>::put(1_000);
+        let _ = Balances::deposit_creating(&alice, 10_000);
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::deregister_as_juror(RawOrigin::Signed(alice.clone()).into()));
+
+        // Leaving the jury does not escape a penalty for a recent round
+        assert_ok!(Reputation::slash_juror(&alice));
+        assert_eq!(Balances::reserved_balance(&alice), 900);
+        assert_eq!(Reputation::unlocking(&alice)[0].amount, 900);
+
+        System::set_block_number(101);
+        assert_ok!(Reputation::withdraw_unbonded(RawOrigin::Signed(alice.clone()).into()));
+        assert_eq!(Balances::reserved_balance(&alice), 0);
+        assert_noop!(Reputation::slash_juror(&alice), Error::<Test>::NotRegisteredAsJuror);
+    });
+}
+
+#[test]
+fn delegation_adds_draw_weight_and_shares_rewards() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        JurorStake::<Test>::put(100);
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        ReputationStats::<Test>::mutate(&alice, |s| {
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
+        });
+        let _ = Balances::deposit_creating(&alice, 10_000);
+        let _ = Balances::deposit_creating(&bob, 10_000);
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+
+        assert_noop!(
+            Reputation::delegate(RawOrigin::Signed(alice.clone()).into(), alice.clone(), 100),
+            Error::<Test>::SelfDelegation
+        );
+        assert_noop!(
+            Reputation::delegate(RawOrigin::Signed(bob.clone()).into(), account("charlie"), 100),
+            Error::<Test>::NotRegisteredAsJuror
+        );
+
+        assert_ok!(Reputation::delegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 300));
+        assert_eq!(Reputation::delegation(&alice, &bob), 300);
+        assert_eq!(Reputation::delegated_stake(&alice), 300);
+        assert_eq!(Reputation::delegator_count(&alice), 1);
+        assert_eq!(Balances::reserved_balance(&bob), 300);
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 400);
+        System::assert_last_event(Event::Delegated { delegator: bob.clone(), juror: alice.clone(), amount: 300 }.into());
+
+        // After a 10% commission, Bob earns three quarters of the rest
+        assert_ok!(Reputation::set_commission(RawOrigin::Signed(alice.clone()).into(), Permill::from_percent(10)));
+        assert_eq!(
+            Reputation::reward_shares(&alice, 1_000),
+            vec![(alice.clone(), 325), (bob.clone(), 675)]
+        );
+
+        // Undelegating more than delegated takes back everything, through unbonding
+        assert_ok!(Reputation::undelegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 100));
+        assert_eq!(Reputation::delegation(&alice, &bob), 200);
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 300);
+        assert_ok!(Reputation::undelegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 1_000));
+        // The slot stays taken until the undelegated stake has unbonded
+        assert_eq!(Reputation::delegator_count(&alice), 1);
+        assert_eq!(Reputation::unbonding_delegation(&alice, &bob).map(|chunk| chunk.amount), Some(300));
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 100);
+        assert_eq!(Reputation::unlocking(&bob).iter().map(|chunk| chunk.amount).sum::<u64>(), 300);
+        assert_eq!(Balances::reserved_balance(&bob), 300);
+        assert_noop!(
+            Reputation::undelegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 100),
+            Error::<Test>::NotDelegated
+        );
+    });
+}
+
+#[test]
+fn delegators_share_slashes_and_unbond_when_juror_leaves() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        JurorStake::<Test>::put(1_000);
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        ReputationStats::<Test>::mutate(&alice, |s| {
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
+        });
+        let _ = Balances::deposit_creating(&alice, 10_000);
+        let _ = Balances::deposit_creating(&bob, 10_000);
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::delegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 500));
+
+        assert_ok!(Reputation::slash_juror(&alice));
+        System::assert_has_event(
+            Event::DelegatorSlashed { delegator: bob.clone(), juror: alice.clone(), amount: 50 }.into()
+        );
+        assert_eq!(Reputation::delegation(&alice, &bob), 450);
+        assert_eq!(Balances::reserved_balance(&bob), 450);
+        assert_eq!(Reputation::stake_of(&alice), Some(900));
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 1_350);
+
+        // Leaving the jury hands delegated stake back through unbonding
+        assert_ok!(Reputation::deregister_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_eq!(Reputation::delegation(&alice, &bob), 0);
+        assert_eq!(Reputation::delegated_stake(&alice), 0);
+        assert_eq!(Reputation::unlocking(&bob)[0].amount, 450);
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 0);
+
+        System::set_block_number(101);
+        assert_ok!(Reputation::withdraw_unbonded(RawOrigin::Signed(bob.clone()).into()));
+        assert_eq!(Balances::reserved_balance(&bob), 0);
+    });
+}
+
+#[test]
+fn undelegated_stake_is_slashed_until_it_unlocks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        let charlie = account("charlie");
+        JurorStake::<Test>::put(1_000);
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        ReputationStats::<Test>::mutate(&alice, |s| {
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
+        });
+        let _ = Balances::deposit_creating(&alice, 10_000);
+        let _ = Balances::deposit_creating(&bob, 10_000);
+        let _ = Balances::deposit_creating(&charlie, 10_000);
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::delegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 500));
+        assert_ok!(Reputation::delegate(RawOrigin::Signed(charlie.clone()).into(), alice.clone(), 500));
+
+        // Bob pulls out before the slash lands, but his stake still backed Alice
+        assert_ok!(Reputation::undelegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 500));
+        // Only 20 of Charlie's delegation is still reserved
+        <Balances as frame_support::traits::ReservableCurrency<_>>::unreserve(&charlie, 480);
+
+        assert_ok!(Reputation::slash_juror(&alice));
+        System::assert_has_event(
+            Event::DelegatorSlashed { delegator: bob.clone(), juror: alice.clone(), amount: 50 }.into()
+        );
+        assert_eq!(Balances::reserved_balance(&bob), 450);
+        assert_eq!(Reputation::unlocking(&bob)[0].amount, 450);
+        assert_eq!(Reputation::unbonding_delegation(&alice, &bob).map(|chunk| chunk.amount), Some(450));
+        // Charlie's delegation only loses what was actually slashed
+        System::assert_has_event(
+            Event::DelegatorSlashed { delegator: charlie.clone(), juror: alice.clone(), amount: 20 }.into()
+        );
+        assert_eq!(Reputation::delegation(&alice, &charlie), 480);
+
+        // Once unlocked, the stake is out of reach and the slot is freed
+        System::set_block_number(101);
+        assert_ok!(Reputation::withdraw_unbonded(RawOrigin::Signed(bob.clone()).into()));
+        assert_eq!(Balances::reserved_balance(&bob), 0);
+        assert_ok!(Reputation::slash_juror(&alice));
+        assert_eq!(Reputation::unbonding_delegation(&alice, &bob), None);
+        assert_eq!(Reputation::delegator_count(&alice), 1);
+    });
+}
+
+#[test]
+fn emergency_removal_unbonds_busy_juror() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = account("alice");
+        let bob = account("bob");
+        JurorStake::<Test>::put(1_000);
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        ReputationStats::<Test>::mutate(&alice, |s| {
+            s.freelancer.projects_completed = 10;
+            s.freelancer.total_earned = 2_000;
+        });
+        let _ = Balances::deposit_creating(&alice, 10_000);
+        let _ = Balances::deposit_creating(&bob, 10_000);
+        assert_ok!(Reputation::register_as_juror(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::delegate(RawOrigin::Signed(bob.clone()).into(), alice.clone(), 500));
+        JurorBusy::<Test>::insert(&alice, true);
+
+        assert_noop!(
+            Reputation::emergency_remove_juror(RawOrigin::Signed(bob.clone()).into(), alice.clone(), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Reputation::emergency_remove_juror(RawOrigin::Root.into(), alice.clone(), true));
+        System::assert_last_event(Event::JurorRemoved { account: alice.clone(), slashed: true }.into());
+
+        assert!(!Reputation::juror_opted_in(&alice));
+        assert_eq!(Reputation::stake_of(&alice), None);
+        assert_eq!(Reputation::unlocking(&alice)[0].amount, 900);
+        assert_eq!(Reputation::unlocking(&bob)[0].amount, 450);
+        assert_eq!(Reputation::pool_stake(JurorTier::Bronze), 0);
+        assert_noop!(
+            Reputation::emergency_remove_juror(RawOrigin::Root.into(), alice.clone(), false),
+            Error::<Test>::NotRegisteredAsJuror
+        );
+    });
+}
+
+#[test]
+fn default_tier_thresholds_are_given_in_whole_units() {
+    let thresholds = TierThresholds::<u64>::in_units(1_000_000);
+    assert_eq!(thresholds.bronze.min_earned, 1_000_000_000);
+    assert_eq!(thresholds.gold.min_earned, 50_000_000_000);
+    assert_eq!(thresholds.gold.min_stake, 0);
+    new_test_ext().execute_with(|| {
+        assert_eq!(Reputation::tier_thresholds(), TierThresholds::in_units(1));
+    });
+}
+
+#[test]
+fn client_dispute_loss_does_not_count_as_a_failed_project() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, freelancer) = (account("alice"), account("bob"));
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(client.clone()).into()));
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(freelancer.clone()).into()));
+
+        assert_ok!(Reputation::on_dispute_outcome(&freelancer, &client, 1, 1000, &[], ProjectRole::Client));
+        let stats = Reputation::reputation_stats(&client);
+        assert_eq!(stats.disputes_lost, 1);
+        assert_eq!(stats.freelancer.projects_failed, 0);
+    });
+}
+
+#[test]
+fn recent_projects_are_bounded_and_stale_pairings_pruned() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (client, freelancer, juror) = (account("alice"), account("bob"), account("dave"));
+        for who in [&client, &freelancer, &juror] {
+            assert_ok!(Reputation::register_user(RawOrigin::Signed(who.clone()).into()));
+        }
+
+        // `MaxRecentProjects` is 4 in the mock: the oldest project makes room for the fifth
+        for project_id in 1..=5 {
+            System::set_block_number(project_id * 10);
+            assert_ok!(Reputation::on_project_completed(&freelancer, 100, 4000, project_id as u32, &[]));
+        }
+        let projects: Vec<_> = Reputation::recent_projects(&freelancer).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(projects, vec![2, 3, 4, 5]);
+        assert_ok!(Reputation::on_project_completed(&juror, 100, 4000, 1, &[]));
+        assert!(!Reputation::has_conflict_of_interest(&juror, &[freelancer.clone()]));
+        assert_ok!(Reputation::on_project_completed(&juror, 100, 4000, 5, &[]));
+        assert!(Reputation::has_conflict_of_interest(&juror, &[freelancer.clone()]));
+
+        // Projects that left the window are dropped on the next attestation
+        System::set_block_number(1045);
+        assert_ok!(Reputation::on_project_completed(&freelancer, 100, 4000, 6, &[]));
+        let projects: Vec<_> = Reputation::recent_projects(&freelancer).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(projects, vec![5, 6]);
+
+        // Pairings older than `ConflictWindow` are removed by `on_idle`
+        assert_ok!(Reputation::on_work_started(&client, &juror, 7));
+        System::set_block_number(2100);
+        assert_ok!(Reputation::on_work_started(&client, &freelancer, 8));
+        Reputation::on_idle(2100, Weight::MAX);
+        assert_eq!(Reputation::collaborations(&client, &juror), None);
+        assert_eq!(Reputation::collaborations(&juror, &client), None);
+        assert_eq!(Reputation::collaborations(&client, &freelancer), Some(2100));
+        assert_eq!(Reputation::collaborations(&freelancer, &client), Some(2100));
+    });
+}
+
+#[test]
+fn migration_to_v5_fills_recent_projects_from_attestations() {
+    use crate::migrations::v5::LazyMigrationV5;
+    use crate::Attestation;
+    use frame_support::{traits::{GetStorageVersion, StorageVersion}, BoundedVec};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1500);
+        let alice = account("alice");
+        let attestation = |attestor, project_id, timestamp| Attestation::<Test> {
+            attestor,
+            project_id,
+            outcome: AttestationOutcome::Positive,
+            value: 100,
+            timestamp,
+            rating: None,
+            metadata: BoundedVec::default(),
+        };
+        crate::Attestations::<Test>::insert(&alice, 0, attestation(AttestorType::ClientApproval, 1, 400));
+        crate::Attestations::<Test>::insert(&alice, 1, attestation(AttestorType::ClientApproval, 2, 900));
+        crate::Attestations::<Test>::insert(&alice, 2, attestation(AttestorType::JuryParticipation, 3, 1000));
+        crate::Attestations::<Test>::insert(&alice, 3, attestation(AttestorType::FreelancerRating, 2, 1200));
+        StorageVersion::new(4).put::<Reputation>();
+
+        // One attestation per step, then one to finish
+        let steps = migrate_in_steps::<LazyMigrationV5<Test>>(db_weight().reads_writes(2, 1), 1);
+        assert_eq!(steps, 5);
+
+        // Project 1 left the window and jury service does not count
+        assert_eq!(Reputation::on_chain_storage_version(), 5);
+        assert_eq!(Reputation::recent_projects(&alice).into_inner(), vec![(2, 1200)]);
+    });
+}
+
+#[test]
+fn partner_chains_are_held_to_a_call_quota() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxPartnerCalls::set(2);
+        let alice = account("alice");
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::set_partner_chain(RawOrigin::Root.into(), 2000, Some([42, 3])));
+
+        // Queries and attestations share the quota
+        assert_ok!(Reputation::query_reputation(RawOrigin::Root.into(), alice.clone()));
+        assert_ok!(Reputation::submit_partner_attestation(
+            RawOrigin::Root.into(), alice.clone(), 9, AttestationOutcome::Positive, 300, 4000
+        ));
+        assert_noop!(
+            Reputation::query_reputation(RawOrigin::Root.into(), alice.clone()),
+            Error::<Test>::PartnerQuotaExceeded
+        );
+        assert_noop!(
+            Reputation::submit_partner_attestation(RawOrigin::Root.into(), alice.clone(), 10, AttestationOutcome::Positive, 300, 4000),
+            Error::<Test>::PartnerQuotaExceeded
+        );
+
+        // A new period starts once `PartnerQuotaPeriod` has passed
+        System::set_block_number(11);
+        assert_ok!(Reputation::query_reputation(RawOrigin::Root.into(), alice.clone()));
+        assert_eq!(Reputation::partner_calls(2000), (11, 1));
+    });
+}
+
+#[test]
+fn losing_a_dispute_burns_the_project_credential() {
+    use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob) = (account("alice"), account("bob"));
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(alice.clone()).into()));
+        assert_ok!(Reputation::register_user(RawOrigin::Signed(bob.clone()).into()));
+        create_credential_collection();
+        assert_ok!(Reputation::on_project_completed(&bob, 500, 4500, 7, &[]));
+        assert_eq!(Reputation::project_credentials(&bob, 7), Some(0));
+
+        // The appeal went the client's way, so the freelancer's completion no longer stands
+        assert_ok!(Reputation::on_dispute_outcome(&alice, &bob, 7, 500, &[], ProjectRole::Freelancer));
+        assert_eq!(Reputation::project_credentials(&bob, 7), None);
+        assert_eq!(<Nfts as Inspect<_>>::owner(&0, &0), None);
+        System::assert_has_event(Event::CredentialRevoked { account: bob, item: 0 }.into());
+    });
+}
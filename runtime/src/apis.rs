@@ -42,9 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    SLOT_DURATION, VERSION,
+    AccountId, Balance, Block, BlockNumber, ConsensusHook, Executive, InherentDataExt, Nonce,
+    ParachainSystem, Reputation, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+    TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 impl_runtime_apis! {
@@ -203,6 +203,30 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_reputation_runtime_api::ReputationApi<Block, AccountId, u32, Balance, BlockNumber>
+        for Runtime
+    {
+        fn reputation_score(account: AccountId) -> Option<u32> {
+            Reputation::calculate_reputation_score(&account).ok()
+        }
+        fn client_score(account: AccountId) -> Option<u32> {
+            Reputation::calculate_client_reputation(&account).ok()
+        }
+        fn juror_tier(account: AccountId) -> pallet_reputation::JurorTier {
+            Reputation::calculate_tier(&account)
+        }
+        fn score_breakdown(account: AccountId) -> Option<pallet_reputation::ScoreBreakdown> {
+            Reputation::score_breakdown(&account).ok()
+        }
+        fn attestations(
+            account: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<pallet_reputation::AttestationRecord<u32, Balance, BlockNumber>> {
+            Reputation::attestation_page(&account, start, limit)
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
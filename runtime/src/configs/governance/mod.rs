@@ -0,0 +1,189 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! On-chain governance. Token holders vote on referenda, and each kind of privileged
+//! marketplace action has its own track and origin. The council can act on juror emergencies
+//! directly and can cancel or kill referenda.
+
+pub mod origins;
+mod tracks;
+
+use frame_support::{
+    parameter_types,
+    traits::{
+        fungible::HoldConsideration, ConstU32, EitherOf, EitherOfDiverse, EqualPrivilegeOnly,
+        LinearStoragePrice,
+    },
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
+use sp_runtime::Perbill;
+
+use super::RuntimeBlockWeights;
+use crate::{
+    AccountId, Balance, Balances, BlockNumber, OracleMembership, OriginCaller, Preimage,
+    Referenda, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, Scheduler,
+    System, DAYS, MILLIUNIT, UNIT,
+};
+pub use origins::{FeeAdmin, JurorEmergency, MarketplacePause, OracleAdmin, ReputationAdmin};
+
+/// The council instance of `pallet_collective`.
+pub type CouncilCollective = pallet_collective::Instance1;
+/// The AI oracle set instance of `pallet_membership`.
+pub type OracleMembershipInstance = pallet_membership::Instance1;
+
+/// More than half of the council.
+pub type CouncilMajority = pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>;
+/// At least two thirds of the council.
+pub type CouncilTwoThirds = pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+
+/// Reputation weights, juror tier thresholds, credentials and partner chains.
+pub type ReputationGovernanceOrigin = EitherOf<EnsureRoot<AccountId>, ReputationAdmin>;
+/// Dispute fees and bonds.
+pub type FeeScheduleOrigin = EitherOf<EnsureRoot<AccountId>, FeeAdmin>;
+/// Adding and removing AI oracles.
+pub type OracleSetOrigin = EitherOf<EnsureRoot<AccountId>, OracleAdmin>;
+/// Removing jurors at once: a fast referendum or a council majority.
+pub type JurorEmergencyOrigin =
+    EitherOf<EnsureRoot<AccountId>, EitherOf<JurorEmergency, CouncilMajority>>;
+/// Pausing and unpausing marketplace calls: a fast referendum or a council majority.
+pub type PauseOrigin =
+    EitherOf<EnsureRoot<AccountId>, EitherOf<MarketplacePause, CouncilMajority>>;
+/// Members of the AI oracle set submit rulings.
+pub type AiOracleOrigin =
+    EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<OracleMembership, AccountId>>;
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeEvent = RuntimeEvent;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+    type BlockNumberProvider = System;
+}
+
+parameter_types! {
+    pub const PreimageBaseDeposit: Balance = UNIT;
+    pub const PreimageByteDeposit: Balance = MILLIUNIT;
+    pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type Consideration = HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+    >;
+}
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub const CouncilMaxMembers: u32 = 13;
+    pub MaxCouncilProposalWeight: Weight = Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = ConstU32<100>;
+    type MaxMembers = CouncilMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type MaxProposalWeight = MaxCouncilProposalWeight;
+    type DisapproveOrigin = EnsureRoot<AccountId>;
+    type KillOrigin = EnsureRoot<AccountId>;
+    type Consideration = ();
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Polls = Referenda;
+    type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, AccountId>;
+    type MaxVotes = ConstU32<512>;
+    type VoteLockingPeriod = ConstU32<{ 7 * DAYS }>;
+    type BlockNumberProvider = System;
+    type VotingHooks = ();
+}
+
+parameter_types! {
+    pub const AlarmInterval: BlockNumber = 1;
+    pub const SubmissionDeposit: Balance = 10 * UNIT;
+    pub const UndecidingTimeout: BlockNumber = 14 * DAYS;
+}
+
+impl pallet_referenda::Config for Runtime {
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Currency = Balances;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type CancelOrigin = EitherOf<EnsureRoot<AccountId>, CouncilTwoThirds>;
+    type KillOrigin = EitherOf<EnsureRoot<AccountId>, CouncilTwoThirds>;
+    type Slash = ();
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxQueued = ConstU32<100>;
+    type UndecidingTimeout = UndecidingTimeout;
+    type AlarmInterval = AlarmInterval;
+    type Tracks = tracks::TracksInfo;
+    type Preimages = Preimage;
+    type BlockNumberProvider = System;
+}
+
+impl origins::pallet_custom_origins::Config for Runtime {}
+
+impl pallet_membership::Config<OracleMembershipInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = OracleSetOrigin;
+    type RemoveOrigin = OracleSetOrigin;
+    type SwapOrigin = OracleSetOrigin;
+    type ResetOrigin = OracleSetOrigin;
+    type PrimeOrigin = OracleSetOrigin;
+    type MembershipInitialized = ();
+    type MembershipChanged = ();
+    type MaxMembers = ConstU32<16>;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
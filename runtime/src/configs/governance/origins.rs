@@ -0,0 +1,83 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Origins dispatched by referenda on the privileged tracks.
+
+pub use pallet_custom_origins::*;
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use frame_support::{pallet_prelude::*, traits::OriginTrait};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, DecodeWithMemTracking, TypeInfo, RuntimeDebug)]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Reputation weights, juror tier thresholds, credentials and partner chains.
+        ReputationAdmin,
+        /// Dispute fees and bonds.
+        FeeAdmin,
+        /// Membership of the AI oracle set.
+        OracleAdmin,
+        /// Removing misbehaving jurors at once.
+        JurorEmergency,
+        /// Pausing and unpausing marketplace calls.
+        MarketplacePause,
+    }
+
+    macro_rules! decl_unit_ensures {
+        ( $name:ident ) => {
+            pub struct $name;
+            impl<O: OriginTrait + From<Origin>> EnsureOrigin<O> for $name
+            where
+                for<'a> &'a O::PalletsOrigin: TryInto<&'a Origin>,
+            {
+                type Success = ();
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    if matches!(o.caller().try_into(), Ok(Origin::$name)) {
+                        Ok(())
+                    } else {
+                        Err(o)
+                    }
+                }
+                #[cfg(feature = "runtime-benchmarks")]
+                fn try_successful_origin() -> Result<O, ()> {
+                    Ok(O::from(Origin::$name))
+                }
+            }
+        };
+        ( $name:ident, $( $rest:tt )* ) => {
+            decl_unit_ensures! { $name }
+            decl_unit_ensures! { $( $rest )* }
+        };
+        () => {}
+    }
+    decl_unit_ensures!(ReputationAdmin, FeeAdmin, OracleAdmin, JurorEmergency, MarketplacePause);
+}
@@ -0,0 +1,157 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Referenda tracks: root, plus one track per kind of privileged marketplace action.
+
+use super::origins;
+use crate::{Balance, BlockNumber, RuntimeOrigin, DAYS, HOURS, MINUTES, UNIT};
+use alloc::borrow::Cow;
+use pallet_referenda::{Curve, Track};
+use sp_runtime::{str_array as s, Perbill};
+
+const fn linear(floor: u32, ceil: u32) -> Curve {
+    Curve::LinearDecreasing {
+        length: Perbill::from_percent(100),
+        floor: Perbill::from_percent(floor),
+        ceil: Perbill::from_percent(ceil),
+    }
+}
+
+static TRACKS_DATA: [Track<u16, Balance, BlockNumber>; 6] = [
+    Track {
+        id: 0,
+        info: pallet_referenda::TrackInfo {
+            name: s("root"),
+            max_deciding: 1,
+            decision_deposit: 1_000 * UNIT,
+            prepare_period: 2 * HOURS,
+            decision_period: 14 * DAYS,
+            confirm_period: DAYS,
+            min_enactment_period: DAYS,
+            min_approval: linear(50, 100),
+            min_support: linear(0, 50),
+        },
+    },
+    Track {
+        id: 1,
+        info: pallet_referenda::TrackInfo {
+            name: s("reputation_admin"),
+            max_deciding: 10,
+            decision_deposit: 100 * UNIT,
+            prepare_period: HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: linear(50, 100),
+            min_support: linear(0, 25),
+        },
+    },
+    Track {
+        id: 2,
+        info: pallet_referenda::TrackInfo {
+            name: s("fee_admin"),
+            max_deciding: 10,
+            decision_deposit: 100 * UNIT,
+            prepare_period: HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 3 * HOURS,
+            // Give parties time to see new fees before they apply
+            min_enactment_period: DAYS,
+            min_approval: linear(50, 100),
+            min_support: linear(0, 25),
+        },
+    },
+    Track {
+        id: 3,
+        info: pallet_referenda::TrackInfo {
+            name: s("oracle_admin"),
+            max_deciding: 5,
+            decision_deposit: 100 * UNIT,
+            prepare_period: HOURS,
+            decision_period: 7 * DAYS,
+            confirm_period: 6 * HOURS,
+            min_enactment_period: 10 * MINUTES,
+            min_approval: linear(60, 100),
+            min_support: linear(5, 50),
+        },
+    },
+    Track {
+        id: 4,
+        info: pallet_referenda::TrackInfo {
+            name: s("juror_emergency"),
+            max_deciding: 10,
+            decision_deposit: 50 * UNIT,
+            prepare_period: 10 * MINUTES,
+            decision_period: DAYS,
+            confirm_period: 10 * MINUTES,
+            min_enactment_period: MINUTES,
+            min_approval: linear(60, 100),
+            min_support: linear(1, 10),
+        },
+    },
+    Track {
+        id: 5,
+        info: pallet_referenda::TrackInfo {
+            name: s("marketplace_pause"),
+            max_deciding: 10,
+            decision_deposit: 50 * UNIT,
+            prepare_period: 10 * MINUTES,
+            decision_period: DAYS,
+            confirm_period: 10 * MINUTES,
+            min_enactment_period: MINUTES,
+            min_approval: linear(60, 100),
+            min_support: linear(1, 10),
+        },
+    },
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+
+    fn tracks() -> impl Iterator<Item = Cow<'static, Track<Self::Id, Balance, BlockNumber>>> {
+        TRACKS_DATA.iter().map(Cow::Borrowed)
+    }
+
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(0),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::ReputationAdmin => Ok(1),
+                origins::Origin::FeeAdmin => Ok(2),
+                origins::Origin::OracleAdmin => Ok(3),
+                origins::Origin::JurorEmergency => Ok(4),
+                origins::Origin::MarketplacePause => Ok(5),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
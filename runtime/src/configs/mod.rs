@@ -23,6 +23,7 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
+pub mod governance;
 #[path = "xcm.rs"]
 mod xcm_config;
 
@@ -34,14 +35,15 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, TransformOrigin, VariantCountOf, ConstU128,
+        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, Contains, EitherOfDiverse,
+        TransformOrigin, VariantCountOf, ConstU128,
     },
     weights::{ConstantMultiplier, Weight},
     PalletId,
 };
 use frame_system::{
     limits::{BlockLength, BlockWeights},
-    EnsureRoot,
+    EnsureRoot, EnsureSigned,
 };
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
@@ -53,6 +55,16 @@ use sp_runtime::Perbill;
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::BodyId;
 use sp_runtime::Permill;
+use sp_runtime::{
+    generic::{Era, SignedPayload},
+    traits::{StaticLookup, Verify},
+    SaturatedConversion,
+};
+use codec::Encode;
+use pallet_nfts::{
+    CollectionConfig, CollectionConfigFor, CollectionSetting, CollectionSettings, ItemConfig,
+    ItemSetting, ItemSettings, MintSettings, PalletFeatures,
+};
 
 // Local module imports
 use super::{
@@ -60,11 +72,17 @@ use super::{
     AccountId, Aura, Balance, Balances, Block, BlockNumber, CollatorSelection, ConsensusHook, Hash,
     MessageQueue, Nonce, PalletInfo, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent,
     RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
-    System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, HOURS,
+    System, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, HOURS, DAYS,
     MAXIMUM_BLOCK_WEIGHT, MICROUNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION, UNIT,
-    Reputation, Projects, Arbitration,
+    Reputation, Projects, Arbitration, Nfts, Signature, TxPause, MultiBlockMigrations, OracleMembership,
+    TxExtension, UncheckedExtrinsic,
+};
+use xcm_config::{
+    EnsureSiblingParachain, RelayLocation, XcmOriginToTransactDispatchOrigin, XcmReputationSender,
+};
+use governance::{
+    AiOracleOrigin, FeeScheduleOrigin, JurorEmergencyOrigin, PauseOrigin, ReputationGovernanceOrigin,
 };
-use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
 parameter_types! {
     pub const Version: RuntimeVersion = VERSION;
@@ -125,7 +143,11 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = SS58Prefix;
     /// The action to take on a Runtime Upgrade
     type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+    /// Calls paused through `TxPause` are rejected.
+    type BaseCallFilter = TxPause;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
+    /// Multi-block migrations run from `MultiBlockMigrations`.
+    type MultiBlockMigrator = MultiBlockMigrations;
 }
 
 impl cumulus_pallet_weight_reclaim::Config for Runtime {
@@ -189,6 +211,61 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = (); // Configure based on benchmarking results.
 }
 
+/// Calls `TxPause` can never pause: everything outside the marketplace pallets, and the
+/// marketplace calls that give users their funds back or settle disputes.
+pub struct UnpausableCalls;
+impl Contains<pallet_tx_pause::RuntimeCallNameOf<Runtime>> for UnpausableCalls {
+    fn contains(full_name: &pallet_tx_pause::RuntimeCallNameOf<Runtime>) -> bool {
+        match (full_name.0.as_slice(), full_name.1.as_slice()) {
+            (b"Projects", call) => {
+                matches!(call, b"cancel_project" | b"withdraw_application" | b"decline_offer" | b"expire_offer")
+            },
+            (b"Arbitration", call) => {
+                matches!(call, b"enforce_final_ruling" | b"finalize_round" | b"set_fee_schedule" | b"set_deadlines_halted")
+            },
+            _ => true,
+        }
+    }
+}
+
+impl pallet_tx_pause::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PauseOrigin = PauseOrigin;
+    type UnpauseOrigin = PauseOrigin;
+    type WhitelistedCalls = UnpausableCalls;
+    type MaxNameLen = ConstU32<256>;
+    type WeightInfo = pallet_tx_pause::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Migrations too large for a single block, stepped through one block at a time.
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type Migrations = (
+        pallet_projects::migrations::v1::LazyMigrationV1<Runtime>,
+        pallet_projects::migrations::v2::LazyMigrationV2<Runtime>,
+        pallet_reputation::migrations::v1::LazyMigrationV1<Runtime>,
+        pallet_reputation::migrations::v2::LazyMigrationV2<Runtime>,
+        pallet_reputation::migrations::v3::LazyMigrationV3<Runtime>,
+        pallet_reputation::migrations::v4::LazyMigrationV4<Runtime>,
+        pallet_reputation::migrations::v5::LazyMigrationV5<Runtime>,
+        pallet_arbitration::migrations::v1::LazyMigrationV1<Runtime>,
+    );
+    #[cfg(feature = "runtime-benchmarks")]
+    type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+    type CursorMaxLen = ConstU32<65_536>;
+    type IdentifierMaxLen = ConstU32<256>;
+    type MigrationStatusHandler = ();
+    type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+    type MaxServiceWeight = MbmServiceWeight;
+    type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
     pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
@@ -316,12 +393,63 @@ impl pallet_collator_selection::Config for Runtime {
 }
 
 /// custom pallets
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+/// Lets offchain workers sign transactions with keys from the node's keystore.
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<UncheckedExtrinsic> {
+        // Valid for about half of `BlockHashCount` blocks
+        let period = BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+        let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+        let tx_ext: TxExtension = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+        )
+            .into();
+        let raw_payload = SignedPayload::new(call, tx_ext).ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let (call, tx_ext, _) = raw_payload.deconstruct();
+        let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+        Some(UncheckedExtrinsic::new_signed(call, address, signature, tx_ext))
+    }
+}
+
 parameter_types! {
     pub const ProjectsPalletId: PalletId = PalletId(*b"tsk/proj");
     pub const MaxApplicantsLength: u32 = 100;
     pub const ReviewPeriod: BlockNumber = 10000;
     pub const MaxJurors: u32 = 100;
     pub const JurorSlashRatio: Permill = Permill::from_percent(10);
+    pub const IpfsGateway: &'static str = "https://ipfs.io";
+    pub const ApplicationDeposit: Balance = UNIT / 10;
 }
 
 impl pallet_projects::Config for Runtime {
@@ -331,22 +459,101 @@ impl pallet_projects::Config for Runtime {
     type PalletId = ProjectsPalletId;
     type MaxApplicants = MaxApplicantsLength;
     type ReviewPeriod = ReviewPeriod;
+    type RatingPeriod = ConstU32<{ 14 * DAYS }>; // Parties can rate and review each other for two weeks
+    type OfferPeriod = ConstU32<{ 3 * DAYS }>; // Direct-hire offers lapse after three days
+    type MaxSkills = ConstU32<5>;
+    type ApplicationDeposit = ApplicationDeposit; // Returned once the client hires someone
     type WeightInfo = (); // Configure based on benchmarking results.
     type Reputation = Reputation;
+    type AuthorityId = pallet_projects::crypto::VerifierAuthId;
+    type VerifierOrigin = AiOracleOrigin; // Oracles run the content-check offchain worker
+    type IpfsGateway = IpfsGateway;
+    type MaxContentChecksPerBlock = ConstU32<10>;
+    type MaxContentSize = ConstU32<{ 10 * 1024 * 1024 }>; // Larger submissions are recorded as unverifiable
+}
+
+parameter_types! {
+    pub NftFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type Locker = ();
+    type CollectionDeposit = ConstU128<{ 10 * UNIT }>;
+    type ItemDeposit = ConstU128<{ UNIT / 100 }>;
+    type MetadataDepositBase = ConstU128<{ UNIT / 10 }>;
+    type AttributeDepositBase = ConstU128<{ UNIT / 10 }>;
+    type DepositPerByte = ConstU128<{ UNIT / 1000 }>;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<20>;
+    type ItemAttributesApprovalsLimit = ConstU32<30>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU32<{ 365 * DAYS }>;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftFeatures;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+    type BlockNumberProvider = System;
+}
+
+parameter_types! {
+    pub const ReputationPalletId: PalletId = PalletId(*b"tsk/repu");
+    // Credentials are soulbound: neither the collection nor its items can be transferred.
+    pub CredentialCollectionConfig: CollectionConfigFor<Runtime> = CollectionConfig {
+        settings: CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into()),
+        max_supply: None,
+        mint_settings: MintSettings::default(),
+    };
+    pub CredentialItemConfig: ItemConfig = ItemConfig {
+        settings: ItemSettings::from_disabled(ItemSetting::Transferable.into()),
+    };
 }
 
 impl pallet_reputation::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type ProjectId = u32;
-    type GovernanceOrigin = EnsureRoot<AccountId>;
+    type GovernanceOrigin = ReputationGovernanceOrigin;
+    type JurorEmergencyOrigin = JurorEmergencyOrigin;
     type MaxMetadata = ConstU32<256>;
     type WeightInfo = ();
     type MaxJurors = MaxApplicantsLength;
-    type MaxGoldJurors = ConstU32<100>;
-    type MaxSilverJurors = ConstU32<200>;
-    type MaxBronzeJurors = ConstU32<200>;
+    type MaxGoldJurors = ConstU32<1_000>;
+    type MaxSilverJurors = ConstU32<5_000>;
+    type MaxBronzeJurors = ConstU32<10_000>;
     type SlashRatio = JurorSlashRatio;
+    type ConflictWindow = ConstU32<{ 180 * DAYS }>; // Past collaborations within ~6 months disqualify a juror
+    type MaxRecentProjects = ConstU32<64>;
+    type Randomness = pallet_reputation::ParentHashRandomness<Runtime>;
+    type DecayPeriod = ConstU32<{ 30 * DAYS }>; // time_decay_rate applies once per ~month
+    type MaxRescoresPerBlock = ConstU32<20>;
+    type PalletId = ReputationPalletId;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NftCollectionConfig = CollectionConfigFor<Runtime>;
+    type NftItemConfig = ItemConfig;
+    type Nfts = Nfts;
+    type CredentialCollectionConfig = CredentialCollectionConfig;
+    type CredentialItemConfig = CredentialItemConfig;
+    type PartnerChainOrigin = EnsureSiblingParachain;
+    type ReputationSender = XcmReputationSender;
+    type MaxPartnerCalls = ConstU32<100>; // Per partner chain and quota period
+    type PartnerQuotaPeriod = ConstU32<{ HOURS }>;
+    type UnbondingPeriod = ConstU32<{ 28 * DAYS }>; // Stake stays slashable through recent appeal rounds
+    type MaxUnlockingChunks = ConstU32<16>;
+    type MaxDelegators = ConstU32<64>;
+    type BalanceUnit = ConstU128<UNIT>;
+    type PublishFee = ConstU128<{ UNIT / 10 }>;
 }
 
 parameter_types! {
@@ -358,7 +565,10 @@ impl pallet_arbitration::Config for Runtime {
     type Currency = Balances;
     type ProjectId = u32;
     type WeightInfo = ();
-    type AiOracleOrigin = EnsureRoot<AccountId>; // For now, using root as oracle origin
+    type AiOracleOrigin = AiOracleOrigin; // Members of the oracle set, managed on the oracle_admin track
+    type Oracles = OracleMembership;
+    type FeeOrigin = FeeScheduleOrigin;
+    type HaltOrigin = PauseOrigin;
     type Arbitrable = Projects; // Projects pallet implements the Arbitrable trait
     type Reputation = Reputation;
     type MaxJurors = MaxApplicantsLength;
@@ -367,6 +577,12 @@ impl pallet_arbitration::Config for Runtime {
     type AiProcessingPeriod = ConstU32<100>; // 100 blocks for AI processing
     type VotingPeriod = ConstU32<200>; // 200 blocks for voting
     type AppealPeriod = ConstU32<100>; // 100 blocks for appeals
+    type DisputeRetentionPeriod = ConstU32<{ 30 * DAYS }>; // Keep full dispute history for 30 days
+    type MaxDeadlinesPerBlock = ConstU32<50>;
+    type EnforcementBounty = ConstU128<{ UNIT / 10 }>; // 0.1 UNIT for manual finalize/enforce calls
+    type MaxChallengesPerRound = ConstU32<2>;
+    type ChallengeBond = ConstU128<{ 5 * UNIT }>;
+    type ReplacementVotingPeriod = ConstU32<{ 2 * DAYS }>;
     type MinimumAiBond = ConstU128<{5 * UNIT}>; // 0.5 UNIT minimum for AI bond
     type MinimumFirstAppealBond = ConstU128<{20 * UNIT}>; // 2.0 UNIT minimum for first appeal
     type MinimumFinalAppealBond = ConstU128<{50 * UNIT}>; // 5.0 UNIT minimum for final appeal
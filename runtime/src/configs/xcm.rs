@@ -24,23 +24,29 @@
 // For more information, please refer to <http://unlicense.org>
 
 use crate::{
-    AccountId, AllPalletsWithSystem, Balance, Balances, ParachainInfo, ParachainSystem,
-    PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, WeightToFee,
-    XcmpQueue, MILLIUNIT, UNIT,
+    AccountId, AllPalletsWithSystem, Balance, Balances, BlockNumber, ParachainInfo,
+    ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason,
+    RuntimeOrigin, WeightToFee, XcmpQueue, MILLIUNIT, UNIT,
 };
+use alloc::vec;
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
 use frame_support::{
+    dispatch::DispatchResult,
     parameter_types,
     traits::{
-        fungible::HoldConsideration, ConstU32, Contains, Everything, LinearStoragePrice, Nothing,
+        fungible::HoldConsideration, ConstU32, Contains, EnsureOrigin, Everything,
+        LinearStoragePrice, Nothing, ProcessMessageError,
     },
     weights::Weight,
 };
+use pallet_reputation::{PartnerChains, ReputationSender, ReputationSummary};
 use frame_system::EnsureRoot;
 use pallet_xcm::{AuthorizedAliasers, XcmPassthrough};
 use parachains_common::TREASURY_PALLET_ID;
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{traits::AccountIdConversion, DispatchError};
 use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
@@ -50,7 +56,10 @@ use xcm_builder::{
     SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
     TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{
+    traits::{Properties, ShouldExecute},
+    XcmExecutor,
+};
 
 parameter_types! {
     pub const RelayLocation: Location = Location::parent();
@@ -134,6 +143,52 @@ impl Contains<Location> for ParentOrParentsExecutivePlurality {
     }
 }
 
+/// Sibling parachains whitelisted as reputation partners in `pallet_reputation::PartnerChains`.
+pub struct ReputationPartners;
+impl Contains<Location> for ReputationPartners {
+    fn contains(location: &Location) -> bool {
+        matches!(
+            location.unpack(),
+            (1, [Parachain(id)]) if PartnerChains::<Runtime>::contains_key(id)
+        )
+    }
+}
+
+/// Allows a message from a location in `T` to run unpaid only if it is exactly an
+/// `UnpaidExecution` covering the message weight followed by a native `Transact` of
+/// `Reputation::query_reputation` or `Reputation::submit_partner_attestation`.
+pub struct AllowReputationCallsFrom<T>(PhantomData<T>);
+impl<T: Contains<Location>> ShouldExecute for AllowReputationCallsFrom<T> {
+    fn should_execute<Call>(
+        origin: &Location,
+        instructions: &mut [Instruction<Call>],
+        max_weight: Weight,
+        _properties: &mut Properties,
+    ) -> Result<(), ProcessMessageError> {
+        if !T::contains(origin) {
+            return Err(ProcessMessageError::Unsupported);
+        }
+        let [UnpaidExecution { weight_limit, .. }, Transact { origin_kind: OriginKind::Native, call, .. }] =
+            instructions
+        else {
+            return Err(ProcessMessageError::BadFormat);
+        };
+        match weight_limit {
+            Unlimited => {}
+            Limited(limit) if limit.all_gte(max_weight) => {}
+            _ => return Err(ProcessMessageError::Overweight(max_weight)),
+        }
+        let encoded = call.clone().into_encoded();
+        match RuntimeCall::decode(&mut &encoded[..]) {
+            Ok(RuntimeCall::Reputation(
+                pallet_reputation::Call::query_reputation { .. }
+                | pallet_reputation::Call::submit_partner_attestation { .. },
+            )) => Ok(()),
+            _ => Err(ProcessMessageError::Unsupported),
+        }
+    }
+}
+
 pub type Barrier = TrailingSetTopicAsId<
     DenyThenTry<
         DenyReserveTransferToRelayChain,
@@ -144,6 +199,8 @@ pub type Barrier = TrailingSetTopicAsId<
                     AllowTopLevelPaidExecutionFrom<Everything>,
                     AllowExplicitUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
                     // ^^^ Parent and its exec plurality get free execution
+                    AllowReputationCallsFrom<ReputationPartners>,
+                    // ^^^ Reputation partners query summaries and submit attestations for free
                 ),
                 UniversalLocation,
                 ConstU32<8>,
@@ -251,3 +308,44 @@ impl cumulus_pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type XcmExecutor = XcmExecutor<XcmConfig>;
 }
+
+/// Succeeds for `Transact`s dispatched by a sibling parachain with `OriginKind::Native`,
+/// yielding its para id.
+pub struct EnsureSiblingParachain;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingParachain {
+    type Success = u32;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<u32, RuntimeOrigin> {
+        let origin: Result<cumulus_pallet_xcm::Origin, RuntimeOrigin> = o.clone().into();
+        match origin {
+            Ok(cumulus_pallet_xcm::Origin::SiblingParachain(id)) => Ok(id.into()),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(cumulus_pallet_xcm::Origin::SiblingParachain(2000.into()).into())
+    }
+}
+
+/// Sends reputation summaries to sibling parachains as an unpaid `Transact` of
+/// `callback ++ summary`, dispatched there with this parachain's native origin.
+pub struct XcmReputationSender;
+impl ReputationSender<AccountId, BlockNumber> for XcmReputationSender {
+    fn send_summary(
+        dest: u32,
+        callback: [u8; 2],
+        summary: ReputationSummary<AccountId, BlockNumber>,
+    ) -> DispatchResult {
+        let mut call = callback.to_vec();
+        summary.encode_to(&mut call);
+        let message = Xcm(vec![
+            UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+            Transact { origin_kind: OriginKind::Native, fallback_max_weight: None, call: call.into() },
+        ]);
+        send_xcm::<XcmRouter>(Location::new(1, [Parachain(dest)]), message)
+            .map(|_| ())
+            .map_err(|_| DispatchError::Other("Failed to send reputation summary"))
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     AccountId, BalancesConfig, CollatorSelectionConfig, ParachainInfoConfig, PolkadotXcmConfig,
     RuntimeGenesisConfig, SessionConfig, SessionKeys, SudoConfig, EXISTENTIAL_DEPOSIT,
-    ReputationConfig, Balance, BlockNumber,
+    ReputationConfig, Balance, BlockNumber, UNIT, CouncilConfig, OracleMembershipConfig,
 };
 use alloc::{vec, vec::Vec};
 use cumulus_primitives_core::ParaId;
@@ -11,7 +11,7 @@ use serde_json::Value;
 use sp_genesis_builder::PresetId;
 use sp_keyring::Sr25519Keyring;
 use sp_runtime::Permill;
-use pallet_reputation::ReputationData;
+use pallet_reputation::{ClientStats, FreelancerStats, JurorStats, ReputationData, TierThresholds};
 
 /// The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
@@ -27,6 +27,8 @@ fn testnet_genesis(
     invulnerables: Vec<(AccountId, AuraId)>,
     endowed_accounts: Vec<AccountId>,
     root: AccountId,
+    council: Vec<AccountId>,
+    oracles: Vec<AccountId>,
     id: ParaId,
 ) -> Value {
     // Define reputation data (same as above)
@@ -39,87 +41,140 @@ fn testnet_genesis(
     let alice_reputation = ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 0,
-        projects_failed: 0,
-        total_earned: 0u128,
-        projects_posted: 10,
-        total_spent: 50000u128,
+        freelancer: FreelancerStats {
+            projects_completed: 0,
+            projects_failed: 0,
+            total_earned: 0u128,
+            avg_rating_received: 4500,
+            total_ratings_received: 8,
+        },
+        client: ClientStats {
+            projects_posted: 10,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 50000 * UNIT,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 0,
         disputes_lost: 0,
-        avg_rating_received: 4500,
-        total_ratings_received: 8,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Bob as Freelancer
     let bob_reputation = ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 15,
-        projects_failed: 1,
-        total_earned: 25000u128,
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: FreelancerStats {
+            projects_completed: 15,
+            projects_failed: 1,
+            total_earned: 25000 * UNIT,
+            avg_rating_received: 4200,
+            total_ratings_received: 12,
+        },
+        client: ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 1,
         disputes_lost: 0,
-        avg_rating_received: 4200,
-        total_ratings_received: 12,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Charlie as Bronze Juror
     let charlie_reputation = ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 5,
-        projects_failed: 0,
-        total_earned: 1500u128,
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: FreelancerStats {
+            projects_completed: 5,
+            projects_failed: 0,
+            total_earned: 1500 * UNIT,
+            avg_rating_received: 4000,
+            total_ratings_received: 5,
+        },
+        client: ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 0,
         disputes_lost: 0,
-        avg_rating_received: 4000,
-        total_ratings_received: 5,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Dave as Silver Juror
     let dave_reputation = ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 25,
-        projects_failed: 1,
-        total_earned: 15000u128,
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: FreelancerStats {
+            projects_completed: 25,
+            projects_failed: 1,
+            total_earned: 15000 * UNIT,
+            avg_rating_received: 4300,
+            total_ratings_received: 20,
+        },
+        client: ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 2,
         disputes_lost: 0,
-        avg_rating_received: 4300,
-        total_ratings_received: 20,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
     // Eve as Gold Juror
     let eve_reputation = ReputationData {
         registration_block: 1u32,
         last_activity_block: 1u32,
-        projects_completed: 60,
-        projects_failed: 2,
-        total_earned: 75000u128,
-        projects_posted: 0,
-        total_spent: 0u128,
+        freelancer: FreelancerStats {
+            projects_completed: 60,
+            projects_failed: 2,
+            total_earned: 75000 * UNIT,
+            avg_rating_received: 4600,
+            total_ratings_received: 50,
+        },
+        client: ClientStats {
+            projects_posted: 0,
+            projects_completed: 0,
+            projects_cancelled: 0,
+            total_spent: 0u128,
+            avg_rating_received: 0,
+            total_ratings_received: 0,
+        },
+        juror: JurorStats {
+            jury_participation: 0,
+            jury_accuracy: Permill::zero(),
+        },
         disputes_initiated: 0,
         disputes_won: 5,
         disputes_lost: 0,
-        avg_rating_received: 4600,
-        total_ratings_received: 50,
-        jury_participation: 0,
-        jury_accuracy: Permill::zero(),
     };
+    // Juror tier earnings in whole units
+    let tier_thresholds = TierThresholds::in_units(UNIT);
+
     build_struct_json_patch!(RuntimeGenesisConfig {
         balances: BalancesConfig {
             balances: endowed_accounts
@@ -153,6 +208,10 @@ fn testnet_genesis(
             safe_xcm_version: Some(SAFE_XCM_VERSION)
         },
         sudo: SudoConfig { key: Some(root) },
+        council: CouncilConfig { members: council },
+        oracle_membership: OracleMembershipConfig {
+            members: oracles.try_into().expect("oracle set fits MaxMembers; qed")
+        },
         reputation: ReputationConfig {
             initial_users: vec![
                 (alice.clone(), alice_reputation),
@@ -162,11 +221,21 @@ fn testnet_genesis(
                 (eve.clone(), eve_reputation),
             ],
             initial_jurors: vec![charlie, dave, eve],
-            juror_stake: 1000000000000u128,
+            juror_stake: UNIT,
+            tier_thresholds,
         }
     })
 }
 
+/// Initial council of the test presets.
+fn council_members() -> Vec<AccountId> {
+    vec![
+        Sr25519Keyring::Alice.to_account_id(),
+        Sr25519Keyring::Bob.to_account_id(),
+        Sr25519Keyring::Charlie.to_account_id(),
+    ]
+}
+
 fn local_testnet_genesis() -> Value {
     testnet_genesis(
         // initial collators.
@@ -184,6 +253,8 @@ fn local_testnet_genesis() -> Value {
             .map(|k| k.to_account_id())
             .collect(),
         Sr25519Keyring::Alice.to_account_id(),
+        council_members(),
+        vec![Sr25519Keyring::Alice.to_account_id()],
         2000.into(),
     )
 }
@@ -205,6 +276,8 @@ fn development_config_genesis() -> Value {
             .map(|k| k.to_account_id())
             .collect(),
         Sr25519Keyring::Alice.public().into(),
+        council_members(),
+        vec![Sr25519Keyring::Alice.to_account_id()],
         2000.into(),
     )
 }
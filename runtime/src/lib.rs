@@ -38,6 +38,9 @@ pub mod configs;
 mod genesis_config_presets;
 mod weights;
 
+#[cfg(test)]
+mod tests;
+
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -120,8 +123,11 @@ pub type TxExtension = StorageWeightReclaim<
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
 
-/// Migrations to apply on runtime upgrade.
-pub type Migrations = pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>;
+/// Single-block migrations to apply on runtime upgrade. Multi-block migrations are configured
+/// on `pallet_migrations` instead.
+pub type Migrations = (
+    pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -295,6 +301,10 @@ mod runtime {
     pub type ParachainInfo = parachain_info::Pallet<Runtime>;
     #[runtime::pallet_index(4)]
     pub type WeightReclaim = cumulus_pallet_weight_reclaim::Pallet<Runtime>;
+    #[runtime::pallet_index(5)]
+    pub type TxPause = pallet_tx_pause;
+    #[runtime::pallet_index(6)]
+    pub type MultiBlockMigrations = pallet_migrations;
 
     // Monetary stuff.
     #[runtime::pallet_index(10)]
@@ -305,6 +315,14 @@ mod runtime {
     // Governance
     #[runtime::pallet_index(15)]
     pub type Sudo = pallet_sudo;
+    #[runtime::pallet_index(16)]
+    pub type Council = pallet_collective<Instance1>;
+    #[runtime::pallet_index(17)]
+    pub type ConvictionVoting = pallet_conviction_voting;
+    #[runtime::pallet_index(18)]
+    pub type Referenda = pallet_referenda;
+    #[runtime::pallet_index(19)]
+    pub type Origins = configs::governance::origins::pallet_custom_origins;
 
     // Collator support. The order of these 4 are important and shall not change.
     #[runtime::pallet_index(20)]
@@ -328,6 +346,14 @@ mod runtime {
     #[runtime::pallet_index(33)]
     pub type MessageQueue = pallet_message_queue::Pallet<Runtime>;
 
+    // Utility
+    #[runtime::pallet_index(35)]
+    pub type Nfts = pallet_nfts::Pallet<Runtime>;
+    #[runtime::pallet_index(36)]
+    pub type Scheduler = pallet_scheduler;
+    #[runtime::pallet_index(37)]
+    pub type Preimage = pallet_preimage;
+
     // custom
     #[runtime::pallet_index(40)]
     pub type Projects = pallet_projects::Pallet<Runtime>;
@@ -335,6 +361,8 @@ mod runtime {
     pub type Reputation = pallet_reputation::Pallet<Runtime>;
     #[runtime::pallet_index(42)]
     pub type Arbitration = pallet_arbitration::Pallet<Runtime>;
+    #[runtime::pallet_index(43)]
+    pub type OracleMembership = pallet_membership<Instance1>;
 }
 
 cumulus_pallet_parachain_system::register_validate_block! {
@@ -0,0 +1,169 @@
+//! Checks of how `TxPause`, as the `BaseCallFilter`, treats the marketplace pallets, and of how
+//! the marketplace pallets report to each other once they are wired together.
+
+use crate::{Arbitration, Projects, Reputation, Runtime, RuntimeCall, RuntimeOrigin, System, TxPause, UNIT};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+use pallet_tx_pause::RuntimeCallNameOf;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{traits::Dispatchable, BuildStorage};
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: vec![
+            (Sr25519Keyring::Alice.to_account_id(), 1_000_000 * UNIT),
+            (Sr25519Keyring::Bob.to_account_id(), 1_000_000 * UNIT),
+            (Sr25519Keyring::Charlie.to_account_id(), 1_000_000 * UNIT),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn full_name(pallet: &[u8], call: &[u8]) -> RuntimeCallNameOf<Runtime> {
+    (pallet.to_vec().try_into().unwrap(), call.to_vec().try_into().unwrap())
+}
+
+/// Whether dispatching `call` from a signed origin is rejected by the call filter, rather than
+/// run and rejected (or accepted) by the pallet.
+fn is_filtered(call: RuntimeCall) -> bool {
+    let origin = RuntimeOrigin::signed(Sr25519Keyring::Alice.to_account_id());
+    call.dispatch(origin)
+        .is_err_and(|e| e.error == frame_system::Error::<Runtime>::CallFiltered.into())
+}
+
+/// `call` can neither be paused nor is filtered while the rest of its pallet is paused.
+fn assert_unpausable(pallet: &[u8], name: &[u8], call: RuntimeCall) {
+    assert_noop!(
+        TxPause::pause(RuntimeOrigin::root(), full_name(pallet, name)),
+        pallet_tx_pause::Error::<Runtime>::Unpausable
+    );
+    assert!(TxPause::contains(&call));
+    assert!(!is_filtered(call));
+}
+
+#[test]
+fn paused_calls_are_filtered_but_exits_still_dispatch() {
+    new_test_ext().execute_with(|| {
+        let create_project = RuntimeCall::Projects(pallet_projects::Call::create_project {
+            budget: 1_000,
+            uri: Default::default(),
+            duration: 100,
+            skills: Default::default(),
+            hiring: pallet_projects::HiringMode::Open,
+        });
+        assert!(!is_filtered(create_project.clone()));
+
+        assert_ok!(TxPause::pause(RuntimeOrigin::root(), full_name(b"Projects", b"create_project")));
+        assert!(!TxPause::contains(&create_project));
+        assert!(is_filtered(create_project));
+
+        assert_unpausable(
+            b"Projects",
+            b"cancel_project",
+            RuntimeCall::Projects(pallet_projects::Call::cancel_project { project_id: 0 }),
+        );
+        assert_unpausable(
+            b"Arbitration",
+            b"finalize_round",
+            RuntimeCall::Arbitration(pallet_arbitration::Call::finalize_round { project_id: 0 }),
+        );
+        assert_unpausable(
+            b"Arbitration",
+            b"set_deadlines_halted",
+            RuntimeCall::Arbitration(pallet_arbitration::Call::set_deadlines_halted { halted: true }),
+        );
+    });
+}
+
+#[test]
+fn withdrawing_an_application_is_unpausable() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(TxPause::pause(RuntimeOrigin::root(), full_name(b"Projects", b"create_project")));
+
+        assert_unpausable(
+            b"Projects",
+            b"withdraw_application",
+            RuntimeCall::Projects(pallet_projects::Call::withdraw_application { project_id: 0 }),
+        );
+    });
+}
+
+#[test]
+fn declining_and_expiring_offers_are_unpausable() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(TxPause::pause(RuntimeOrigin::root(), full_name(b"Projects", b"create_project")));
+
+        assert_unpausable(
+            b"Projects",
+            b"decline_offer",
+            RuntimeCall::Projects(pallet_projects::Call::decline_offer { project_id: 0 }),
+        );
+        assert_unpausable(
+            b"Projects",
+            b"expire_offer",
+            RuntimeCall::Projects(pallet_projects::Call::expire_offer { project_id: 0 }),
+        );
+    });
+}
+
+#[test]
+fn enforced_ruling_is_attested_once() {
+    new_test_ext().execute_with(|| {
+        let client = Sr25519Keyring::Alice.to_account_id();
+        let freelancer = Sr25519Keyring::Bob.to_account_id();
+        let budget = 1_000 * UNIT;
+
+        assert_ok!(Reputation::register_user(RuntimeOrigin::signed(client.clone())));
+        assert_ok!(Reputation::register_user(RuntimeOrigin::signed(freelancer.clone())));
+        assert_ok!(Projects::create_project(
+            RuntimeOrigin::signed(client.clone()),
+            budget,
+            Default::default(),
+            1_000,
+            Default::default(),
+            pallet_projects::HiringMode::Open,
+        ));
+        assert_ok!(Projects::apply_for_project(
+            RuntimeOrigin::signed(freelancer.clone()),
+            0,
+            budget,
+            1_000,
+            b"ipfs://proposal".to_vec().try_into().unwrap(),
+        ));
+        assert_ok!(Projects::start_work(RuntimeOrigin::signed(client.clone()), 0, freelancer.clone(), None));
+        assert_ok!(Projects::submit_work(
+            RuntimeOrigin::signed(freelancer.clone()),
+            0,
+            [1u8; 32],
+            b"ipfs://work".to_vec().try_into().unwrap(),
+            Default::default(),
+        ));
+
+        assert_ok!(Arbitration::create_dispute(RuntimeOrigin::signed(freelancer.clone()), 0));
+        assert_ok!(Arbitration::submit_ruling(
+            RuntimeOrigin::root(),
+            0,
+            pallet_arbitration::Ruling::ClientWins,
+            None,
+        ));
+        System::set_block_number(System::block_number() + 101);
+        assert_ok!(Arbitration::enforce_final_ruling(
+            RuntimeOrigin::signed(Sr25519Keyring::Charlie.to_account_id()),
+            0,
+        ));
+
+        let attested = |account, attestor| {
+            pallet_reputation::ProjectAttestations::<Runtime>::iter_prefix_values((account, 0))
+                .filter(|a| *a == attestor)
+                .count()
+        };
+        assert_eq!(attested(&client, pallet_reputation::AttestorType::ArbitrationWin), 1);
+        assert_eq!(attested(&freelancer, pallet_reputation::AttestorType::ArbitrationLoss), 1);
+        assert_eq!(Reputation::reputation_stats(&freelancer).freelancer.projects_failed, 1);
+    });
+}